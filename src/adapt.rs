@@ -1,3 +1,6 @@
+#[cfg(feature = "adapt-bytemuck")]
+// @TODO pub?
+mod bytemuck;
 #[cfg(feature = "adapt-smartstring")]
 // @TODO pub?
 mod smartstring;
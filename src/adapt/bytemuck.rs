@@ -0,0 +1,29 @@
+//! [bytemuck](https://docs.rs/bytemuck) support. For example, cast a (memory-mapped) `&[f32]` to
+//! `&[F32Cami]` with `bytemuck::cast_slice`, or wrap with [TransparentWrapper::wrap_slice].
+//!
+//! Any (future) numeric wrappers in `cami` (like [F32Total]) are to be added here, too.
+#![allow(unsafe_code)]
+
+use crate::core::F32Total;
+use crate::{Cami, CamiCopy, CamiPartialEq};
+use bytemuck::{Pod, TransparentWrapper, Zeroable};
+
+// SAFETY: `Cami` is `#[repr(transparent)]` over `T`.
+unsafe impl<T: CamiPartialEq + ?Sized> TransparentWrapper<T> for Cami<T> {}
+
+// SAFETY: `Cami` is `#[repr(transparent)]` over `T`, so all-zeros is valid for `Cami<T>` when it's
+// valid for `T`.
+unsafe impl<T: CamiPartialEq + Zeroable> Zeroable for Cami<T> {}
+
+// SAFETY: `Cami` is `#[repr(transparent)]` over `T`: no padding, and any bit pattern valid for `T`
+// is valid for `Cami<T>`. `Cami<T>` is [Copy] only when `T` is [CamiCopy].
+unsafe impl<T: CamiPartialEq + CamiCopy + Pod> Pod for Cami<T> {}
+
+// SAFETY: `F32Total` is `#[repr(transparent)]` over `f32`.
+unsafe impl TransparentWrapper<f32> for F32Total {}
+
+// SAFETY: `F32Total` is `#[repr(transparent)]` over `f32`, for which all-zeros (`0.0`) is valid.
+unsafe impl Zeroable for F32Total {}
+
+// SAFETY: `F32Total` is `#[repr(transparent)]` over `f32`, which is [Pod].
+unsafe impl Pod for F32Total {}
@@ -1,2 +1,16 @@
+pub mod argsort;
+pub mod boxed;
+pub mod btree_map;
+pub mod cami_str;
+pub mod eytzinger;
+pub mod interner;
+pub mod kmerge;
+pub mod rc;
+pub mod select;
+pub mod set_ops;
+pub mod static_tree;
 pub mod string;
+pub mod string_table;
+#[cfg(target_has_atomic = "ptr")]
+pub mod sync;
 pub mod vec;
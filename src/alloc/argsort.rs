@@ -0,0 +1,150 @@
+//! Sorting permutations (indices) rather than items - for example, to reorder several parallel
+//! columns by one key column.
+use crate::{CamiLocalKey, CamiOrd};
+use rust_alloc::vec::Vec;
+
+/// Index type of a permutation.
+trait PermutationIndex: Copy {
+    fn from_usize(index: usize) -> Self;
+    fn to_usize(self) -> usize;
+}
+
+impl PermutationIndex for usize {
+    #[inline]
+    fn from_usize(index: usize) -> Self {
+        index
+    }
+    #[inline]
+    fn to_usize(self) -> usize {
+        self
+    }
+}
+
+impl PermutationIndex for u32 {
+    #[inline]
+    fn from_usize(index: usize) -> Self {
+        u32::try_from(index).expect("the slice is too long for u32 indices")
+    }
+    #[inline]
+    fn to_usize(self) -> usize {
+        self as usize
+    }
+}
+
+/// Two passes, local first:
+/// 1. Gather the local keys (with indices) into a contiguous scratch buffer, and sort it by the
+///    local keys only. That never accesses `slice` again.
+/// 2. Only within runs of equal local keys, sort by the non-local parts (accessing `slice`).
+///
+/// Both passes are stable, so the result is stable, too.
+fn argsort<T, I>(slice: &[T]) -> Vec<I>
+where
+    T: CamiOrd + CamiLocalKey,
+    I: PermutationIndex,
+{
+    let mut scratch: Vec<(T::LocalKey, I)> = slice
+        .iter()
+        .enumerate()
+        .map(|(index, item)| (item.local_key(), I::from_usize(index)))
+        .collect();
+    scratch.sort_by_key(|&(key, _)| key);
+    if T::LOCALITY.has_non_local() {
+        let mut start = 0;
+        while start < scratch.len() {
+            let local_key = scratch[start].0;
+            let end = start
+                + scratch[start..]
+                    .iter()
+                    .take_while(|(key, _)| *key == local_key)
+                    .count();
+            if end - start > 1 {
+                scratch[start..end].sort_by(|this, other| {
+                    slice[this.1.to_usize()].cmp_non_local(&slice[other.1.to_usize()])
+                });
+            }
+            start = end;
+        }
+    }
+    scratch.into_iter().map(|(_, index)| index).collect()
+}
+
+/// Permutation that sorts `slice` (stable) in [crate::Cami] order: the index of the smallest item,
+/// then of the second smallest... Apply it (to `slice` or to other slices of the same length) with
+/// [apply_permutation].
+///
+/// It sorts the local keys ([CamiLocalKey]), gathered into a contiguous buffer, first. Then it
+/// compares non-local parts only within runs of equal local keys.
+#[must_use]
+pub fn argsort_cami<T: CamiOrd + CamiLocalKey>(slice: &[T]) -> Vec<usize> {
+    argsort(slice)
+}
+
+/// Like [argsort_cami], but with [u32] indices (half of the memory on 64-bit targets).
+///
+/// # Panics
+///
+/// If `slice` is longer than [u32::MAX] + 1 items.
+#[must_use]
+pub fn argsort_cami_u32<T: CamiOrd + CamiLocalKey>(slice: &[T]) -> Vec<u32> {
+    argsort(slice)
+}
+
+/// Reorder `slice` in place, so that `slice[i]` becomes (the original) `slice[permutation[i]]`. It
+/// resets `permutation` to the identity. `permutation` must be a valid permutation (it's not
+/// checked here).
+pub(crate) fn permute_in_place<T>(slice: &mut [T], permutation: &mut [usize]) {
+    for start in 0..permutation.len() {
+        let mut current = start;
+        loop {
+            let source = permutation[current];
+            permutation[current] = current;
+            if source == start {
+                break;
+            }
+            slice.swap(current, source);
+            current = source;
+        }
+    }
+}
+
+fn apply<T, I: PermutationIndex>(slice: &mut [T], permutation: &[I]) {
+    assert_eq!(
+        slice.len(),
+        permutation.len(),
+        "the permutation must be as long as the slice"
+    );
+    let mut seen = Vec::new();
+    seen.resize(permutation.len(), false);
+    let mut scratch = Vec::with_capacity(permutation.len());
+    for &index in permutation {
+        let index = index.to_usize();
+        assert!(
+            index < seen.len() && !seen[index],
+            "not a permutation: index {} is out of bounds or repeated",
+            index
+        );
+        seen[index] = true;
+        scratch.push(index);
+    }
+    permute_in_place(slice, &mut scratch);
+}
+
+/// Reorder `slice` in place, so that `slice[i]` becomes (the original) `slice[permutation[i]]` -
+/// for example, with a permutation from [argsort_cami]. `permutation` is not modified, so it can be
+/// applied to several (parallel) slices. It allocates a copy of it.
+///
+/// # Panics
+///
+/// If `permutation` is not a permutation of `0..slice.len()`.
+pub fn apply_permutation<T>(slice: &mut [T], permutation: &[usize]) {
+    apply(slice, permutation);
+}
+
+/// Like [apply_permutation], but for [u32] indices (from [argsort_cami_u32]).
+///
+/// # Panics
+///
+/// If `permutation` is not a permutation of `0..slice.len()`.
+pub fn apply_permutation_u32<T>(slice: &mut [T], permutation: &[u32]) {
+    apply(slice, permutation);
+}
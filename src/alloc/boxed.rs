@@ -0,0 +1,32 @@
+use crate::{Cami, CamiPartialEq};
+use rust_alloc::boxed::Box;
+
+/// NOT a [Cami] of a boxed slice, BUT a boxed slice of [Cami].
+pub type BoxedSliceCami<T> = Box<[Cami<T>]>;
+
+pub trait IntoBoxedSliceCami<T: CamiPartialEq> {
+    #[must_use]
+    fn into_boxed_slice_cami(self) -> Box<[Cami<T>]>;
+}
+/// Zero cost (no reallocation), see [Cami::wrap_boxed_slice].
+impl<T: CamiPartialEq> IntoBoxedSliceCami<T> for Box<[T]> {
+    #[must_use]
+    #[inline]
+    fn into_boxed_slice_cami(self) -> Box<[Cami<T>]> {
+        Cami::wrap_boxed_slice(self)
+    }
+}
+
+/// The reverse of [IntoBoxedSliceCami].
+pub trait FromBoxedSliceCami<T> {
+    #[must_use]
+    fn into_unwrapped_boxed_slice(self) -> Box<[T]>;
+}
+/// Zero cost (no reallocation), see [Cami::unwrap_boxed_slice].
+impl<T: CamiPartialEq> FromBoxedSliceCami<T> for Box<[Cami<T>]> {
+    #[must_use]
+    #[inline]
+    fn into_unwrapped_boxed_slice(self) -> Box<[T]> {
+        Cami::unwrap_boxed_slice(self)
+    }
+}
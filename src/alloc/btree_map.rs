@@ -0,0 +1,892 @@
+//! Ordered map (B-tree) with [Cami] keys, whose nodes keep local keys (as per [CamiLocalKey]) of
+//! their keys in a dense array, next to the keys themselves.
+//!
+//! `BTreeMap<Cami<K>, V>` compares local parts first, too, but it has to dereference each key in a
+//! node to get to its local part. Here a search within a node scans the dense local keys, and it
+//! calls [CamiPartialOrd::partial_cmp_non_local] only for keys whose local keys tie with the
+//! needle's.
+//!
+//! Each node is one allocation: its local keys, keys, values and child pointers are fixed-capacity
+//! arrays inside the node.
+use crate::cami::{eq_cami, partial_cmp_cami};
+use crate::{Cami, CamiLocalKey, CamiOrd, CamiPartialEq, CamiPartialOrd};
+use core::cmp::Ordering;
+use core::iter::FusedIterator;
+use core::mem;
+use core::ops::{Bound, RangeBounds};
+use core::ptr;
+use rust_alloc::boxed::Box;
+use rust_alloc::vec::Vec;
+
+/// Minimum degree: Each node (other than the root) has between `MIN_DEGREE - 1` and
+/// [CAPACITY] keys.
+const MIN_DEGREE: usize = 6;
+/// Maximum number of keys in a node.
+const CAPACITY: usize = 2 * MIN_DEGREE - 1;
+
+struct Node<K: CamiOrd + CamiLocalKey, V> {
+    /// Number of keys.
+    len: usize,
+    /// Local keys of `keys`, in the same order. Slots from `len` on hold stale copies of local
+    /// keys (rather than being uninitialized).
+    locals: [K::LocalKey; CAPACITY],
+    /// The first `len` are [Some].
+    keys: [Option<Cami<K>>; CAPACITY],
+    /// The first `len` are [Some].
+    vals: [Option<V>; CAPACITY],
+    /// All [None] for leaves. Otherwise the first `len + 1` are [Some].
+    children: [Option<Box<Node<K, V>>>; CAPACITY + 1],
+}
+
+/// Insert `item` at index `i` of `slots[..len]`, shifting the rest right. `slots[len]` must be free.
+fn insert_slot<T>(slots: &mut [Option<T>], len: usize, i: usize, item: T) {
+    debug_assert!(slots[len].is_none());
+    slots[len] = Some(item);
+    slots[i..=len].rotate_right(1);
+}
+
+/// Remove the item at index `i` of `slots[..len]`, shifting the rest left.
+fn remove_slot<T>(slots: &mut [Option<T>], len: usize, i: usize) -> T {
+    slots[i..len].rotate_left(1);
+    slots[len - 1].take().unwrap()
+}
+
+impl<K: CamiOrd + CamiLocalKey, V> Node<K, V> {
+    /// An empty node. `local` only fills the unused local key slots.
+    fn new(local: K::LocalKey) -> Self {
+        Self {
+            len: 0,
+            locals: [local; CAPACITY],
+            keys: [(); CAPACITY].map(|_| None),
+            vals: [(); CAPACITY].map(|_| None),
+            children: [(); CAPACITY + 1].map(|_| None),
+        }
+    }
+
+    #[inline]
+    fn is_leaf(&self) -> bool {
+        self.children[0].is_none()
+    }
+
+    #[inline]
+    fn key(&self, i: usize) -> &Cami<K> {
+        self.keys[i].as_ref().unwrap()
+    }
+
+    #[inline]
+    fn val(&self, i: usize) -> &V {
+        self.vals[i].as_ref().unwrap()
+    }
+
+    #[inline]
+    fn val_mut(&mut self, i: usize) -> &mut V {
+        self.vals[i].as_mut().unwrap()
+    }
+
+    #[inline]
+    fn child(&self, i: usize) -> &Node<K, V> {
+        self.children[i].as_deref().unwrap()
+    }
+
+    #[inline]
+    fn child_mut(&mut self, i: usize) -> &mut Node<K, V> {
+        self.children[i].as_deref_mut().unwrap()
+    }
+
+    /// `Ok(index)` of the key equal to `key`, or `Err(index)` of the first greater key (which is
+    /// also the index of the child to descend to).
+    ///
+    /// If a key and `key` are incomparable, the key is treated as being less than `key`.
+    fn search<Q>(&self, local: &K::LocalKey, key: &Q) -> Result<usize, usize>
+    where
+        Q: ?Sized,
+        K: CamiPartialOrd<Q>,
+    {
+        let locals = &self.locals[..self.len];
+        // The local keys are sorted, so this is a lower bound. But counting is branchless.
+        let mut i = locals.iter().filter(|l| *l < local).count();
+        if !<Cami<K>>::LOCALITY.has_non_local() {
+            return if i < locals.len() && locals[i] == *local {
+                Ok(i)
+            } else {
+                Err(i)
+            };
+        }
+        while i < locals.len() && locals[i] == *local {
+            match self
+                .key(i)
+                .in_cami()
+                .partial_cmp_non_local(key)
+                .unwrap_or(Ordering::Less)
+            {
+                Ordering::Less => i += 1,
+                Ordering::Equal => return Ok(i),
+                Ordering::Greater => return Err(i),
+            }
+        }
+        Err(i)
+    }
+
+    fn insert_at(&mut self, i: usize, local: K::LocalKey, key: Cami<K>, value: V) {
+        let len = self.len;
+        self.locals[len] = local;
+        self.locals[i..=len].rotate_right(1);
+        insert_slot(&mut self.keys, len, i, key);
+        insert_slot(&mut self.vals, len, i, value);
+        self.len += 1;
+    }
+
+    fn remove_at(&mut self, i: usize) -> (K::LocalKey, Cami<K>, V) {
+        let len = self.len;
+        let local = self.locals[i];
+        self.locals[i..len].rotate_left(1);
+        let entry = (
+            local,
+            remove_slot(&mut self.keys, len, i),
+            remove_slot(&mut self.vals, len, i),
+        );
+        self.len -= 1;
+        entry
+    }
+
+    #[inline]
+    fn push(&mut self, local: K::LocalKey, key: Cami<K>, value: V) {
+        self.insert_at(self.len, local, key, value);
+    }
+
+    /// Number of children: 0 for leaves, `len + 1` otherwise. But this counts them, so that it
+    /// works while `len` and the children are being updated separately.
+    fn children_len(&self) -> usize {
+        self.children
+            .iter()
+            .take_while(|child| child.is_some())
+            .count()
+    }
+
+    fn insert_child(&mut self, i: usize, child: Box<Node<K, V>>) {
+        let len = self.children_len();
+        insert_slot(&mut self.children, len, i, child);
+    }
+
+    fn remove_child(&mut self, i: usize) -> Box<Node<K, V>> {
+        let len = self.children_len();
+        remove_slot(&mut self.children, len, i)
+    }
+
+    /// Replace entry `i`. Return the old local key, key and value.
+    fn replace_at(
+        &mut self,
+        i: usize,
+        entry: (K::LocalKey, Cami<K>, V),
+    ) -> (K::LocalKey, Cami<K>, V) {
+        (
+            mem::replace(&mut self.locals[i], entry.0),
+            self.keys[i].replace(entry.1).unwrap(),
+            self.vals[i].replace(entry.2).unwrap(),
+        )
+    }
+
+    /// Split full child `i` into two, moving its median key up here.
+    fn split_child(&mut self, i: usize) {
+        let child = self.child_mut(i);
+        debug_assert_eq!(child.len, CAPACITY);
+        let right_len = CAPACITY - MIN_DEGREE;
+        let mut right = Node::new(child.locals[MIN_DEGREE]);
+        right.locals[..right_len].copy_from_slice(&child.locals[MIN_DEGREE..]);
+        right.keys[..right_len].swap_with_slice(&mut child.keys[MIN_DEGREE..]);
+        right.vals[..right_len].swap_with_slice(&mut child.vals[MIN_DEGREE..]);
+        right.children[..=right_len].swap_with_slice(&mut child.children[MIN_DEGREE..]);
+        right.len = right_len;
+        child.len = MIN_DEGREE;
+        let (local, key, value) = child.remove_at(MIN_DEGREE - 1);
+        self.insert_at(i, local, key, value);
+        self.insert_child(i + 1, Box::new(right));
+    }
+
+    /// Merge child `i + 1` and key `i` into child `i`.
+    fn merge_children(&mut self, i: usize) {
+        let (local, key, value) = self.remove_at(i);
+        let mut right = self.remove_child(i + 1);
+        let left = self.child_mut(i);
+        left.push(local, key, value);
+        let (start, end) = (left.len, left.len + right.len);
+        left.locals[start..end].copy_from_slice(&right.locals[..right.len]);
+        left.keys[start..end].swap_with_slice(&mut right.keys[..right.len]);
+        left.vals[start..end].swap_with_slice(&mut right.vals[..right.len]);
+        left.children[start..=end].swap_with_slice(&mut right.children[..=right.len]);
+        left.len = end;
+    }
+
+    /// Make child `i` have at least [MIN_DEGREE] keys (by moving a key from a sibling, or by
+    /// merging it with a sibling), so that we can remove a key from its subtree. Return the index
+    /// of the child that now holds the former subtree of child `i`.
+    fn fill_child(&mut self, i: usize) -> usize {
+        if self.child(i).len >= MIN_DEGREE {
+            i
+        } else if i > 0 && self.child(i - 1).len >= MIN_DEGREE {
+            // Rotate right.
+            let left = self.child_mut(i - 1);
+            let grandchild = left.children[left.len].take();
+            let entry = left.remove_at(left.len - 1);
+            let (local, key, value) = self.replace_at(i - 1, entry);
+            let child = self.child_mut(i);
+            child.insert_at(0, local, key, value);
+            if let Some(grandchild) = grandchild {
+                child.insert_child(0, grandchild);
+            }
+            i
+        } else if i < self.len && self.child(i + 1).len >= MIN_DEGREE {
+            // Rotate left.
+            let right = self.child_mut(i + 1);
+            let grandchild = if right.is_leaf() {
+                None
+            } else {
+                Some(right.remove_child(0))
+            };
+            let entry = right.remove_at(0);
+            let (local, key, value) = self.replace_at(i, entry);
+            let child = self.child_mut(i);
+            child.push(local, key, value);
+            if let Some(grandchild) = grandchild {
+                child.insert_child(child.len, grandchild);
+            }
+            i
+        } else if i < self.len {
+            self.merge_children(i);
+            i
+        } else {
+            self.merge_children(i - 1);
+            i - 1
+        }
+    }
+
+    /// Remove `key` from this subtree. This node must have at least [MIN_DEGREE] keys, unless it's
+    /// the root.
+    fn remove<Q>(&mut self, local: &K::LocalKey, key: &Q) -> Option<(Cami<K>, V)>
+    where
+        Q: ?Sized,
+        K: CamiPartialOrd<Q>,
+    {
+        match self.search(local, key) {
+            Ok(i) if self.is_leaf() => {
+                let (_, key, value) = self.remove_at(i);
+                Some((key, value))
+            }
+            Ok(i) => {
+                let replacement = if self.child(i).len >= MIN_DEGREE {
+                    self.child_mut(i).remove_last()
+                } else if self.child(i + 1).len >= MIN_DEGREE {
+                    self.child_mut(i + 1).remove_first()
+                } else {
+                    self.merge_children(i);
+                    return self.child_mut(i).remove(local, key);
+                };
+                let (_, key, value) = self.replace_at(i, replacement);
+                Some((key, value))
+            }
+            Err(_) if self.is_leaf() => None,
+            Err(i) => {
+                let i = self.fill_child(i);
+                self.child_mut(i).remove(local, key)
+            }
+        }
+    }
+
+    fn remove_first(&mut self) -> (K::LocalKey, Cami<K>, V) {
+        if self.is_leaf() {
+            self.remove_at(0)
+        } else {
+            let i = self.fill_child(0);
+            self.child_mut(i).remove_first()
+        }
+    }
+
+    fn remove_last(&mut self) -> (K::LocalKey, Cami<K>, V) {
+        if self.is_leaf() {
+            self.remove_at(self.len - 1)
+        } else {
+            let i = self.fill_child(self.len);
+            self.child_mut(i).remove_last()
+        }
+    }
+}
+
+impl<K: CamiOrd + CamiLocalKey + Clone, V: Clone> Clone for Node<K, V> {
+    fn clone(&self) -> Self {
+        Self {
+            len: self.len,
+            locals: self.locals,
+            keys: self.keys.clone(),
+            vals: self.vals.clone(),
+            children: self.children.clone(),
+        }
+    }
+}
+
+/// Ordered map with [Cami] keys. Its API follows [alloc::collections::BTreeMap] (for `insert`,
+/// `get`, `remove`, `range`, `entry`...).
+///
+/// Lookups accept any (possibly unsized) `Q` where `K: CamiPartialOrd<Q>`, and where `Q` has the
+/// same [CamiLocalKey::LocalKey] as `K`. For example, look up `CamiBTreeMap<String, V>` by `&str`:
+///
+/// `map.get("abc")`
+///
+/// If a key and the needle are incomparable, the key is treated as being less than the needle.
+///
+/// [alloc::collections::BTreeMap]: https://doc.rust-lang.org/alloc/collections/struct.BTreeMap.html
+pub struct CamiBTreeMap<K: CamiOrd + CamiLocalKey, V> {
+    /// [None] if empty.
+    root: Option<Box<Node<K, V>>>,
+    len: usize,
+}
+
+impl<K: CamiOrd + CamiLocalKey, V> CamiBTreeMap<K, V> {
+    #[must_use]
+    #[inline]
+    pub fn new() -> Self {
+        Self { root: None, len: 0 }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::new();
+    }
+
+    #[must_use]
+    pub fn get_key_value<Q>(&self, key: &Q) -> Option<(&Cami<K>, &V)>
+    where
+        Q: CamiLocalKey<LocalKey = K::LocalKey> + ?Sized,
+        K: CamiPartialOrd<Q>,
+    {
+        let local = key.local_key();
+        let mut node = self.root.as_deref()?;
+        loop {
+            match node.search(&local, key) {
+                Ok(i) => return Some((node.key(i), node.val(i))),
+                Err(_) if node.is_leaf() => return None,
+                Err(i) => node = node.child(i),
+            }
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        Q: CamiLocalKey<LocalKey = K::LocalKey> + ?Sized,
+        K: CamiPartialOrd<Q>,
+    {
+        self.get_key_value(key).map(|(_, value)| value)
+    }
+
+    #[must_use]
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        Q: CamiLocalKey<LocalKey = K::LocalKey> + ?Sized,
+        K: CamiPartialOrd<Q>,
+    {
+        let local = key.local_key();
+        let mut node = self.root.as_deref_mut()?;
+        loop {
+            match node.search(&local, key) {
+                Ok(i) => return Some(node.val_mut(i)),
+                Err(_) if node.is_leaf() => return None,
+                Err(i) => node = node.child_mut(i),
+            }
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        Q: CamiLocalKey<LocalKey = K::LocalKey> + ?Sized,
+        K: CamiPartialOrd<Q>,
+    {
+        self.get_key_value(key).is_some()
+    }
+
+    /// Insert, or replace the value (but keep the existing key). Return the value, and the old
+    /// value (if any).
+    fn insert_or_replace(&mut self, key: Cami<K>, value: V) -> (&mut V, Option<V>) {
+        let local = key.local_key();
+        if self.root.is_none() {
+            self.len = 1;
+            let root = self.root.insert(Box::new(Node::new(local)));
+            root.push(local, key, value);
+            return (root.val_mut(0), None);
+        }
+        let root = self.root.as_mut().unwrap();
+        if root.len == CAPACITY {
+            let old_root = mem::replace(root, Box::new(Node::new(local)));
+            root.children[0] = Some(old_root);
+            root.split_child(0);
+        }
+        let mut node = &mut **root;
+        loop {
+            let mut i = match node.search(&local, key.in_cami()) {
+                Ok(i) => {
+                    let old = mem::replace(node.val_mut(i), value);
+                    return (node.val_mut(i), Some(old));
+                }
+                Err(i) => i,
+            };
+            if node.is_leaf() {
+                node.insert_at(i, local, key, value);
+                self.len += 1;
+                return (node.val_mut(i), None);
+            }
+            if node.child(i).len == CAPACITY {
+                node.split_child(i);
+                // The median moved up to `i`.
+                match node.search(&local, key.in_cami()) {
+                    Ok(i) => {
+                        let old = mem::replace(node.val_mut(i), value);
+                        return (node.val_mut(i), Some(old));
+                    }
+                    Err(new_i) => i = new_i,
+                }
+            }
+            node = node.child_mut(i);
+        }
+    }
+
+    /// Like [alloc::collections::BTreeMap::insert]: If the map already had this key, keep the
+    /// existing key, replace the value and return the old value.
+    ///
+    /// [alloc::collections::BTreeMap::insert]: https://doc.rust-lang.org/alloc/collections/struct.BTreeMap.html#method.insert
+    #[inline]
+    pub fn insert(&mut self, key: Cami<K>, value: V) -> Option<V> {
+        self.insert_or_replace(key, value).1
+    }
+
+    pub fn remove_entry<Q>(&mut self, key: &Q) -> Option<(Cami<K>, V)>
+    where
+        Q: CamiLocalKey<LocalKey = K::LocalKey> + ?Sized,
+        K: CamiPartialOrd<Q>,
+    {
+        let root = self.root.as_mut()?;
+        let result = root.remove(&key.local_key(), key);
+        if result.is_some() {
+            self.len -= 1;
+        }
+        if root.len == 0 {
+            // The only child (if any) becomes the root.
+            self.root = root.children[0].take();
+        }
+        result
+    }
+
+    #[inline]
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        Q: CamiLocalKey<LocalKey = K::LocalKey> + ?Sized,
+        K: CamiPartialOrd<Q>,
+    {
+        self.remove_entry(key).map(|(_, value)| value)
+    }
+
+    /// Like [alloc::collections::BTreeMap::entry].
+    ///
+    /// [alloc::collections::BTreeMap::entry]: https://doc.rust-lang.org/alloc/collections/struct.BTreeMap.html#method.entry
+    pub fn entry(&mut self, key: Cami<K>) -> Entry<'_, K, V> {
+        let local = key.local_key();
+        let mut path = Vec::new();
+        let mut node = match self.root.as_deref() {
+            Some(root) => root,
+            None => return Entry::Vacant(VacantEntry { map: self, key }),
+        };
+        loop {
+            match node.search(&local, key.in_cami()) {
+                Ok(i) => {
+                    path.push(i);
+                    return Entry::Occupied(OccupiedEntry {
+                        map: self,
+                        key,
+                        path,
+                    });
+                }
+                Err(_) if node.is_leaf() => {
+                    return Entry::Vacant(VacantEntry { map: self, key });
+                }
+                Err(i) => {
+                    path.push(i);
+                    node = node.child(i);
+                }
+            }
+        }
+    }
+
+    /// Node at `path` (of child indexes), and the index of the key there (the last item of `path`).
+    fn at_path_mut(&mut self, path: &[usize]) -> (&mut Node<K, V>, usize) {
+        let (&i, children) = path.split_last().unwrap();
+        let mut node = self.root.as_deref_mut().unwrap();
+        for &child in children {
+            node = node.child_mut(child);
+        }
+        (node, i)
+    }
+
+    fn at_path(&self, path: &[usize]) -> (&Node<K, V>, usize) {
+        let (&i, children) = path.split_last().unwrap();
+        let mut node = self.root.as_deref().unwrap();
+        for &child in children {
+            node = node.child(child);
+        }
+        (node, i)
+    }
+
+    /// Position (for [Iter]) of the first key not less than `key` (if `after`: greater than
+    /// `key`).
+    fn seek<'a, Q>(&'a self, key: &Q, after: bool) -> Vec<(&'a Node<K, V>, usize)>
+    where
+        Q: CamiLocalKey<LocalKey = K::LocalKey> + ?Sized,
+        K: CamiPartialOrd<Q>,
+    {
+        let local = key.local_key();
+        let mut stack = Vec::new();
+        let mut node = match self.root.as_deref() {
+            Some(root) => root,
+            None => return stack,
+        };
+        loop {
+            let i = match node.search(&local, key) {
+                Ok(i) if !after => {
+                    stack.push((node, i));
+                    break;
+                }
+                Ok(i) => i + 1,
+                Err(i) => i,
+            };
+            stack.push((node, i));
+            if node.is_leaf() {
+                break;
+            }
+            node = node.child(i);
+        }
+        normalize(&mut stack);
+        stack
+    }
+
+    fn seek_first(&self) -> Vec<(&Node<K, V>, usize)> {
+        let mut stack = Vec::new();
+        if let Some(root) = self.root.as_deref() {
+            descend(&mut stack, root);
+        }
+        normalize(&mut stack);
+        stack
+    }
+
+    /// Iterate over entries with keys within `range`, in [Cami] order.
+    ///
+    /// # Panics
+    ///
+    /// If the start of `range` is greater than its end, or if they are equal and both excluded
+    /// (like [alloc::collections::BTreeMap::range]).
+    ///
+    /// [alloc::collections::BTreeMap::range]: https://doc.rust-lang.org/alloc/collections/struct.BTreeMap.html#method.range
+    pub fn range<Q, R>(&self, range: R) -> Iter<'_, K, V>
+    where
+        Q: CamiLocalKey<LocalKey = K::LocalKey> + CamiPartialOrd + ?Sized,
+        K: CamiPartialOrd<Q>,
+        R: RangeBounds<Q>,
+    {
+        match (range.start_bound(), range.end_bound()) {
+            (Bound::Excluded(start), Bound::Excluded(end)) if eq_cami(start, end) => {
+                panic!("range start and end are equal and excluded in CamiBTreeMap")
+            }
+            (
+                Bound::Included(start) | Bound::Excluded(start),
+                Bound::Included(end) | Bound::Excluded(end),
+            ) if partial_cmp_cami(start, end) == Some(Ordering::Greater) => {
+                panic!("range start is greater than range end in CamiBTreeMap")
+            }
+            _ => {}
+        }
+        let mut stack = match range.start_bound() {
+            Bound::Included(start) => self.seek(start, false),
+            Bound::Excluded(start) => self.seek(start, true),
+            Bound::Unbounded => self.seek_first(),
+        };
+        let end = match range.end_bound() {
+            Bound::Included(end) => self.seek(end, true).last().copied(),
+            Bound::Excluded(end) => self.seek(end, false).last().copied(),
+            Bound::Unbounded => None,
+        };
+        // The start position can be after the end position only if the bounds are incomparable.
+        if let (Some(&(start_node, start)), Some((end_node, end))) = (stack.last(), end) {
+            if start_node.key(start) > end_node.key(end) {
+                stack.clear();
+            }
+        }
+        Iter { stack, end }
+    }
+
+    /// Iterate in [Cami] order.
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter {
+            stack: self.seek_first(),
+            end: None,
+        }
+    }
+
+    #[inline]
+    pub fn keys(&self) -> impl Iterator<Item = &Cami<K>> + '_ {
+        self.iter().map(|(key, _)| key)
+    }
+
+    #[inline]
+    pub fn values(&self) -> impl Iterator<Item = &V> + '_ {
+        self.iter().map(|(_, value)| value)
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn first_key_value(&self) -> Option<(&Cami<K>, &V)> {
+        self.iter().next()
+    }
+
+    #[must_use]
+    pub fn last_key_value(&self) -> Option<(&Cami<K>, &V)> {
+        let mut node = self.root.as_deref()?;
+        while !node.is_leaf() {
+            node = node.child(node.len);
+        }
+        Some((node.key(node.len - 1), node.val(node.len - 1)))
+    }
+}
+
+/// Push `node` and the leftmost path below it.
+fn descend<'a, K: CamiOrd + CamiLocalKey, V>(
+    stack: &mut Vec<(&'a Node<K, V>, usize)>,
+    mut node: &'a Node<K, V>,
+) {
+    loop {
+        stack.push((node, 0));
+        if node.is_leaf() {
+            break;
+        }
+        node = node.child(0);
+    }
+}
+
+/// Pop finished nodes, so that the top of `stack` (if any) points to the next key.
+fn normalize<K: CamiOrd + CamiLocalKey, V>(stack: &mut Vec<(&Node<K, V>, usize)>) {
+    while let Some(&(node, i)) = stack.last() {
+        if i < node.len {
+            break;
+        }
+        stack.pop();
+    }
+}
+
+impl<K: CamiOrd + CamiLocalKey, V> Default for CamiBTreeMap<K, V> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: CamiOrd + CamiLocalKey + Clone, V: Clone> Clone for CamiBTreeMap<K, V> {
+    fn clone(&self) -> Self {
+        Self {
+            root: self.root.clone(),
+            len: self.len,
+        }
+    }
+}
+
+impl<K: CamiOrd + CamiLocalKey, V> Extend<(Cami<K>, V)> for CamiBTreeMap<K, V> {
+    fn extend<I: IntoIterator<Item = (Cami<K>, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+impl<K: CamiOrd + CamiLocalKey, V> FromIterator<(Cami<K>, V)> for CamiBTreeMap<K, V> {
+    fn from_iter<I: IntoIterator<Item = (Cami<K>, V)>>(iter: I) -> Self {
+        let mut map = Self::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<'a, K: CamiOrd + CamiLocalKey, V> IntoIterator for &'a CamiBTreeMap<K, V> {
+    type Item = (&'a Cami<K>, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+    #[inline]
+    fn into_iter(self) -> Iter<'a, K, V> {
+        self.iter()
+    }
+}
+
+/// Iterator over (a range of) [CamiBTreeMap], in [Cami] order.
+pub struct Iter<'a, K: CamiOrd + CamiLocalKey, V> {
+    /// Path to the next entry. Each item is a node and the index of its next key.
+    stack: Vec<(&'a Node<K, V>, usize)>,
+    /// Node and index of the first entry NOT to yield, or [None] to iterate until the end.
+    end: Option<(&'a Node<K, V>, usize)>,
+}
+
+impl<'a, K: CamiOrd + CamiLocalKey, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a Cami<K>, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let &(node, i) = self.stack.last()?;
+        if let Some((end_node, end)) = self.end {
+            if ptr::eq(node, end_node) && i == end {
+                self.stack.clear();
+                return None;
+            }
+        }
+        let item = (node.key(i), node.val(i));
+        self.stack.last_mut().unwrap().1 += 1;
+        if !node.is_leaf() {
+            descend(&mut self.stack, node.child(i + 1));
+        }
+        normalize(&mut self.stack);
+        Some(item)
+    }
+}
+
+impl<'a, K: CamiOrd + CamiLocalKey, V> FusedIterator for Iter<'a, K, V> {}
+
+impl<'a, K: CamiOrd + CamiLocalKey, V> Clone for Iter<'a, K, V> {
+    fn clone(&self) -> Self {
+        Self {
+            stack: self.stack.clone(),
+            end: self.end,
+        }
+    }
+}
+
+/// Like `alloc::collections::btree_map::Entry`.
+pub enum Entry<'a, K: CamiOrd + CamiLocalKey, V> {
+    Occupied(OccupiedEntry<'a, K, V>),
+    Vacant(VacantEntry<'a, K, V>),
+}
+
+impl<'a, K: CamiOrd + CamiLocalKey, V> Entry<'a, K, V> {
+    #[must_use]
+    #[inline]
+    pub fn key(&self) -> &Cami<K> {
+        match self {
+            Entry::Occupied(entry) => entry.key(),
+            Entry::Vacant(entry) => entry.key(),
+        }
+    }
+
+    #[inline]
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        self.or_insert_with(|| default)
+    }
+
+    pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default()),
+        }
+    }
+
+    #[inline]
+    pub fn or_default(self) -> &'a mut V
+    where
+        V: Default,
+    {
+        self.or_insert_with(V::default)
+    }
+
+    #[must_use]
+    pub fn and_modify<F: FnOnce(&mut V)>(mut self, f: F) -> Self {
+        if let Entry::Occupied(entry) = &mut self {
+            f(entry.get_mut());
+        }
+        self
+    }
+}
+
+/// An existing entry. Accessing it follows the path (of child indexes) found by
+/// [CamiBTreeMap::entry], with no more key comparisons - except for removal, which searches again.
+pub struct OccupiedEntry<'a, K: CamiOrd + CamiLocalKey, V> {
+    map: &'a mut CamiBTreeMap<K, V>,
+    /// The key passed to [CamiBTreeMap::entry].
+    key: Cami<K>,
+    path: Vec<usize>,
+}
+
+impl<'a, K: CamiOrd + CamiLocalKey, V> OccupiedEntry<'a, K, V> {
+    /// The key stored in the map (NOT the one passed to [CamiBTreeMap::entry]).
+    #[must_use]
+    pub fn key(&self) -> &Cami<K> {
+        let (node, i) = self.map.at_path(&self.path);
+        node.key(i)
+    }
+
+    #[must_use]
+    pub fn get(&self) -> &V {
+        let (node, i) = self.map.at_path(&self.path);
+        node.val(i)
+    }
+
+    #[must_use]
+    pub fn get_mut(&mut self) -> &mut V {
+        let (node, i) = self.map.at_path_mut(&self.path);
+        node.val_mut(i)
+    }
+
+    #[must_use]
+    pub fn into_mut(self) -> &'a mut V {
+        let (node, i) = self.map.at_path_mut(&self.path);
+        node.val_mut(i)
+    }
+
+    #[inline]
+    pub fn insert(&mut self, value: V) -> V {
+        mem::replace(self.get_mut(), value)
+    }
+
+    pub fn remove_entry(self) -> (Cami<K>, V) {
+        self.map.remove_entry(self.key.in_cami()).unwrap()
+    }
+
+    #[inline]
+    pub fn remove(self) -> V {
+        self.remove_entry().1
+    }
+}
+
+pub struct VacantEntry<'a, K: CamiOrd + CamiLocalKey, V> {
+    map: &'a mut CamiBTreeMap<K, V>,
+    key: Cami<K>,
+}
+
+impl<'a, K: CamiOrd + CamiLocalKey, V> VacantEntry<'a, K, V> {
+    #[must_use]
+    #[inline]
+    pub fn key(&self) -> &Cami<K> {
+        &self.key
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn into_key(self) -> Cami<K> {
+        self.key
+    }
+
+    pub fn insert(self, value: V) -> &'a mut V {
+        self.map.insert_or_replace(self.key, value).0
+    }
+}
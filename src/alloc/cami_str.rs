@@ -0,0 +1,376 @@
+//! Compact (16 bytes), immutable string with its length and first 4 bytes inline - an
+//! Umbra/"German" style string, designed around [Cami](crate::Cami).
+//!
+//! All `unsafe` code of [CamiStr] is here. It relies on `#[repr(C)]` layout with no padding (checked
+//! at compile time): bytes of short strings are stored right after the length, so the first 4
+//! bytes and the rest (of up to 8 bytes) are contiguous.
+#![allow(unsafe_code)]
+
+use crate::{CamiLocalKey, CamiOrd, CamiPartialEq, CamiPartialOrd, Locality};
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+use core::ops::Deref;
+use core::{fmt, mem, ptr, slice, str};
+use rust_alloc::boxed::Box;
+use rust_alloc::string::String;
+
+/// Number of bytes in the inline prefix.
+const PREFIX_LEN: usize = 4;
+/// Strings of up to this many bytes are stored inline (in the prefix and [Rest::inline]).
+const INLINE_LEN: usize = 12;
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+union Rest {
+    /// Bytes after the prefix, padded with zeros. Used if `len <= INLINE_LEN`.
+    inline: [u8; INLINE_LEN - PREFIX_LEN],
+    /// All `len` bytes (including the prefix), owned: from [Box::into_raw] of a `Box<[u8]>`. Used
+    /// if `len > INLINE_LEN`.
+    heap: *mut u8,
+}
+
+/// Immutable UTF-8 string of 16 bytes: a [u32] length, the first 4 bytes inline, and either the
+/// rest inline (for strings of up to 12 bytes), or a pointer to all the bytes (for longer strings).
+///
+/// [Cami](crate::Cami) order is consistent with `Cami<&str>` and `Cami<String>` (by length first),
+/// but the local part includes the first 4 bytes, too. So most comparisons (of strings of the same
+/// length) don't dereference. Only the rest of long strings is non-local.
+///
+/// Its [Ord] (not [Cami](crate::Cami) order) is lexicographic, the same as for [str].
+#[repr(C)]
+pub struct CamiStr {
+    len: u32,
+    prefix: [u8; PREFIX_LEN],
+    rest: Rest,
+}
+
+const _: () = assert!(mem::size_of::<CamiStr>() == 16);
+
+// SAFETY: CamiStr owns its heap bytes exclusively (like `Box<str>`), and it never mutates them.
+unsafe impl Send for CamiStr {}
+// SAFETY: See Send above. Shared access is read-only.
+unsafe impl Sync for CamiStr {}
+
+impl CamiStr {
+    /// Copy `from` (into a new allocation, if it's longer than 12 bytes).
+    ///
+    /// # Panics
+    ///
+    /// If `from` is longer than [u32::MAX] bytes.
+    #[must_use]
+    pub fn new(from: &str) -> Self {
+        let bytes = from.as_bytes();
+        if bytes.len() <= INLINE_LEN {
+            Self::new_inline(bytes)
+        } else {
+            Self::new_heap(bytes.into())
+        }
+    }
+
+    fn checked_len(len: usize) -> u32 {
+        u32::try_from(len).expect("CamiStr can't be longer than u32::MAX bytes")
+    }
+
+    fn prefix_of(bytes: &[u8]) -> [u8; PREFIX_LEN] {
+        let mut prefix = [0; PREFIX_LEN];
+        let len = bytes.len().min(PREFIX_LEN);
+        prefix[..len].copy_from_slice(&bytes[..len]);
+        prefix
+    }
+
+    /// `bytes` must be valid UTF-8, of up to [INLINE_LEN] bytes.
+    fn new_inline(bytes: &[u8]) -> Self {
+        debug_assert!(bytes.len() <= INLINE_LEN);
+        let mut inline = [0; INLINE_LEN - PREFIX_LEN];
+        if bytes.len() > PREFIX_LEN {
+            inline[..bytes.len() - PREFIX_LEN].copy_from_slice(&bytes[PREFIX_LEN..]);
+        }
+        Self {
+            len: bytes.len() as u32,
+            prefix: Self::prefix_of(bytes),
+            rest: Rest { inline },
+        }
+    }
+
+    /// `bytes` must be valid UTF-8, longer than [INLINE_LEN] bytes.
+    fn new_heap(bytes: Box<[u8]>) -> Self {
+        debug_assert!(bytes.len() > INLINE_LEN);
+        let len = Self::checked_len(bytes.len());
+        let prefix = Self::prefix_of(&bytes);
+        Self {
+            len,
+            prefix,
+            rest: Rest {
+                heap: Box::into_raw(bytes) as *mut u8,
+            },
+        }
+    }
+
+    /// Whether the bytes are stored inline (with no heap allocation).
+    #[must_use]
+    #[inline]
+    pub fn is_inline(&self) -> bool {
+        self.len as usize <= INLINE_LEN
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        let len = self.len as usize;
+        if self.is_inline() {
+            // SAFETY: `#[repr(C)]` with no padding (see the size assertion above): `prefix` is at
+            // offset 4 and `rest.inline` directly follows it. Both are initialized for inline
+            // strings, so `len <= INLINE_LEN` bytes from offset 4 are initialized and owned by
+            // `self`. The pointer is derived from the whole `self`, so it may access both fields.
+            unsafe {
+                slice::from_raw_parts(
+                    (self as *const Self as *const u8).add(mem::size_of::<u32>()),
+                    len,
+                )
+            }
+        } else {
+            // SAFETY: For long strings `rest.heap` is initialized, and it points to `len` bytes
+            // owned by `self` (see [Rest::heap]). They live (unchanged) as long as `self`.
+            unsafe { slice::from_raw_parts(self.rest.heap, len) }
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        // SAFETY: Constructed only from `str` (or `String`) bytes, and never mutated.
+        unsafe { str::from_utf8_unchecked(self.as_bytes()) }
+    }
+
+    /// Bytes after the (inline) prefix.
+    #[must_use]
+    #[inline]
+    fn rest_bytes(&self) -> &[u8] {
+        let bytes = self.as_bytes();
+        &bytes[bytes.len().min(PREFIX_LEN)..]
+    }
+}
+
+impl Drop for CamiStr {
+    fn drop(&mut self) {
+        if !self.is_inline() {
+            // SAFETY: `rest.heap` came from [Box::into_raw] of a `Box<[u8]>` of `len` bytes (see
+            // [CamiStr::new_heap]), and nothing else frees it.
+            unsafe {
+                drop(Box::from_raw(ptr::slice_from_raw_parts_mut(
+                    self.rest.heap,
+                    self.len as usize,
+                )));
+            }
+        }
+    }
+}
+
+impl Clone for CamiStr {
+    fn clone(&self) -> Self {
+        if self.is_inline() {
+            Self {
+                len: self.len,
+                prefix: self.prefix,
+                rest: self.rest,
+            }
+        } else {
+            Self::new_heap(self.as_bytes().into())
+        }
+    }
+}
+
+impl Default for CamiStr {
+    #[inline]
+    fn default() -> Self {
+        Self::new_inline(&[])
+    }
+}
+
+impl Deref for CamiStr {
+    type Target = str;
+    #[must_use]
+    #[inline]
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for CamiStr {
+    #[must_use]
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<[u8]> for CamiStr {
+    #[must_use]
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl Borrow<str> for CamiStr {
+    #[must_use]
+    #[inline]
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl From<&str> for CamiStr {
+    #[inline]
+    fn from(from: &str) -> Self {
+        Self::new(from)
+    }
+}
+
+impl From<String> for CamiStr {
+    /// Reuse the allocation of a long `from` (though [String::into_boxed_str] may shrink it).
+    fn from(from: String) -> Self {
+        if from.len() <= INLINE_LEN {
+            Self::new_inline(from.as_bytes())
+        } else {
+            Self::new_heap(from.into_boxed_str().into_boxed_bytes())
+        }
+    }
+}
+
+impl From<&String> for CamiStr {
+    #[inline]
+    fn from(from: &String) -> Self {
+        Self::new(from)
+    }
+}
+
+impl From<CamiStr> for String {
+    #[inline]
+    fn from(from: CamiStr) -> Self {
+        from.as_str().into()
+    }
+}
+
+impl fmt::Debug for CamiStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl fmt::Display for CamiStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), f)
+    }
+}
+
+/// The same as for [str], so that it's consistent with [Borrow].
+impl Hash for CamiStr {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
+
+impl PartialEq for CamiStr {
+    #[must_use]
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len
+            && self.prefix == other.prefix
+            && self.rest_bytes() == other.rest_bytes()
+    }
+}
+impl Eq for CamiStr {}
+
+impl PartialEq<str> for CamiStr {
+    #[must_use]
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for CamiStr {
+    #[must_use]
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl PartialOrd for CamiStr {
+    #[must_use]
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Lexicographic, the same as for [str].
+impl Ord for CamiStr {
+    #[must_use]
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.prefix
+            .cmp(&other.prefix)
+            .then_with(|| self.as_bytes().cmp(other.as_bytes()))
+    }
+}
+
+impl CamiPartialEq for CamiStr {
+    const LOCALITY: Locality = Locality::Both;
+
+    #[must_use]
+    #[inline]
+    fn eq_local(&self, other: &Self) -> bool {
+        self.len == other.len && self.prefix == other.prefix
+    }
+
+    #[must_use]
+    #[inline]
+    fn eq_non_local(&self, other: &Self) -> bool {
+        self.rest_bytes() == other.rest_bytes()
+    }
+}
+
+impl CamiPartialOrd for CamiStr {
+    #[must_use]
+    #[inline]
+    fn partial_cmp_local(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp_local(other))
+    }
+
+    #[must_use]
+    #[inline]
+    fn partial_cmp_non_local(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp_non_local(other))
+    }
+}
+
+impl CamiOrd for CamiStr {
+    /// By length, then by the first 4 bytes.
+    #[must_use]
+    #[inline]
+    fn cmp_local(&self, other: &Self) -> Ordering {
+        self.local_key().cmp(&other.local_key())
+    }
+
+    /// By the rest of the bytes (after the first 4). Called only if both have the same length.
+    #[must_use]
+    #[inline]
+    fn cmp_non_local(&self, other: &Self) -> Ordering {
+        self.rest_bytes().cmp(other.rest_bytes())
+    }
+}
+
+impl CamiLocalKey for CamiStr {
+    /// The length, and the first 4 bytes packed big-endian.
+    type LocalKey = (u32, u32);
+
+    #[must_use]
+    #[inline]
+    fn local_key(&self) -> (u32, u32) {
+        (self.len, u32::from_be_bytes(self.prefix))
+    }
+}
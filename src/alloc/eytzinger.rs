@@ -0,0 +1,251 @@
+//! Static (read-only) search array in Eytzinger (BFS) layout.
+//!
+//! A binary search over a sorted array jumps around the memory. In Eytzinger layout the items
+//! visited at the top levels of the search are next to each other, and the descendants of an item
+//! 4 levels down are close to each other, too - so we can prefetch them. See
+//! [https://algorithmica.org/en/eytzinger](https://algorithmica.org/en/eytzinger).
+use crate::prefetch::prefetch_read;
+use crate::{Cami, CamiOrd};
+use core::iter::FusedIterator;
+use rust_alloc::vec::Vec;
+
+/// How many items (in Eytzinger order) to prefetch ahead: the descendants 4 levels down.
+const PREFETCH_MULTIPLIER: usize = 16;
+
+/// Static search array of [Cami] items, in Eytzinger (BFS) layout. Build it from an iterator (or a
+/// [Vec]) in any order. Duplicates are kept.
+///
+/// The search compares items with [Ord] for [Cami] - that is, by their local parts first, and by
+/// their non-local parts only if the local parts are equal.
+pub struct CamiEytzinger<T: CamiOrd> {
+    /// 1-based node `k` (as in a binary heap) is at index `k - 1`.
+    items: Vec<Cami<T>>,
+}
+
+impl<T: CamiOrd> CamiEytzinger<T> {
+    /// Sort (unstable) `items` in [Cami] order, and lay them out in Eytzinger order.
+    #[must_use]
+    pub fn from_vec(mut items: Vec<Cami<T>>) -> Self {
+        items.sort_unstable();
+        let len = items.len();
+        let mut slots: Vec<Option<Cami<T>>> = Vec::with_capacity(len);
+        slots.resize_with(len, || None);
+        for (k, item) in InOrder::new(len).zip(items) {
+            slots[k - 1] = Some(item);
+        }
+        Self {
+            items: slots
+                .into_iter()
+                .map(|slot| slot.expect("every node is filled exactly once"))
+                .collect(),
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// The items in Eytzinger order (NOT sorted).
+    #[must_use]
+    #[inline]
+    pub fn as_eytzinger_slice(&self) -> &[Cami<T>] {
+        &self.items
+    }
+
+    /// Index (into [CamiEytzinger::as_eytzinger_slice]) of the first item (in [Cami] order) that
+    /// is NOT less than `needle`.
+    #[must_use]
+    pub fn lower_bound_index(&self, needle: &Cami<T>) -> Option<usize> {
+        let items = &self.items[..];
+        let len = items.len();
+        let mut k = 1usize;
+        while k <= len {
+            prefetch_read(
+                items
+                    .as_ptr()
+                    .wrapping_add(k.wrapping_mul(PREFETCH_MULTIPLIER).wrapping_sub(1)),
+            );
+            // Branch-light: the comparison result becomes part of the index.
+            k = 2 * k + usize::from(items[k - 1] < *needle);
+        }
+        // Undo the right turns after the last left turn (plus that left turn itself).
+        k = k.checked_shr(k.trailing_ones() + 1).unwrap_or(0);
+        if k == 0 {
+            None
+        } else {
+            Some(k - 1)
+        }
+    }
+
+    /// The first item (in [Cami] order) that is NOT less than `needle`.
+    #[must_use]
+    #[inline]
+    pub fn lower_bound(&self, needle: &Cami<T>) -> Option<&Cami<T>> {
+        self.lower_bound_index(needle)
+            .map(|index| &self.items[index])
+    }
+
+    /// An item equal to `needle`, if any.
+    #[must_use]
+    #[inline]
+    pub fn get(&self, needle: &Cami<T>) -> Option<&Cami<T>> {
+        self.lower_bound(needle).filter(|item| *item == needle)
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn contains(&self, needle: &Cami<T>) -> bool {
+        self.get(needle).is_some()
+    }
+
+    /// Iterate in [Cami] order, by mapping in-order positions to Eytzinger indexes.
+    #[must_use]
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            items: &self.items,
+            in_order: InOrder::new(self.items.len()),
+        }
+    }
+
+    /// Items sorted in [Cami] order.
+    #[must_use]
+    pub fn into_sorted_vec(self) -> Vec<Cami<T>> {
+        let len = self.items.len();
+        let mut slots: Vec<Option<Cami<T>>> = self.items.into_iter().map(Some).collect();
+        InOrder::new(len)
+            .map(|k| {
+                slots[k - 1]
+                    .take()
+                    .expect("every node is visited exactly once")
+            })
+            .collect()
+    }
+}
+
+impl<T: CamiOrd> From<Vec<Cami<T>>> for CamiEytzinger<T> {
+    #[inline]
+    fn from(items: Vec<Cami<T>>) -> Self {
+        Self::from_vec(items)
+    }
+}
+
+impl<T: CamiOrd> FromIterator<Cami<T>> for CamiEytzinger<T> {
+    fn from_iter<I: IntoIterator<Item = Cami<T>>>(iter: I) -> Self {
+        Self::from_vec(iter.into_iter().collect())
+    }
+}
+
+impl<'a, T: CamiOrd> IntoIterator for &'a CamiEytzinger<T> {
+    type Item = &'a Cami<T>;
+    type IntoIter = Iter<'a, T>;
+    #[inline]
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<T: CamiOrd + Clone> Clone for CamiEytzinger<T> {
+    fn clone(&self) -> Self {
+        Self {
+            items: self.items.clone(),
+        }
+    }
+}
+
+/// In-order traversal of 1-based node indexes of a complete binary tree with `len` nodes laid out
+/// like a binary heap.
+#[derive(Clone)]
+struct InOrder {
+    len: usize,
+    /// Next node to yield, or 0 when done.
+    k: usize,
+    remaining: usize,
+}
+
+impl InOrder {
+    fn new(len: usize) -> Self {
+        let mut k = if len == 0 { 0 } else { 1 };
+        while k != 0 && 2 * k <= len {
+            k *= 2;
+        }
+        Self {
+            len,
+            k,
+            remaining: len,
+        }
+    }
+}
+
+impl Iterator for InOrder {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        if self.k == 0 {
+            return None;
+        }
+        let result = self.k;
+        let mut k = self.k;
+        if 2 * k < self.len {
+            // Leftmost node of the right subtree.
+            k = 2 * k + 1;
+            while 2 * k <= self.len {
+                k *= 2;
+            }
+        } else {
+            // Climb up while we're a right child, then once more.
+            while k & 1 == 1 {
+                k >>= 1;
+            }
+            k >>= 1;
+        }
+        self.k = k;
+        self.remaining -= 1;
+        Some(result)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+/// Iterator over [CamiEytzinger] in [Cami] order.
+pub struct Iter<'a, T: CamiOrd> {
+    items: &'a [Cami<T>],
+    in_order: InOrder,
+}
+
+impl<'a, T: CamiOrd> Iterator for Iter<'a, T> {
+    type Item = &'a Cami<T>;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a Cami<T>> {
+        self.in_order.next().map(|k| &self.items[k - 1])
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.in_order.size_hint()
+    }
+}
+
+impl<'a, T: CamiOrd> ExactSizeIterator for Iter<'a, T> {}
+impl<'a, T: CamiOrd> FusedIterator for Iter<'a, T> {}
+
+impl<'a, T: CamiOrd> Clone for Iter<'a, T> {
+    fn clone(&self) -> Self {
+        Self {
+            items: self.items,
+            in_order: self.in_order.clone(),
+        }
+    }
+}
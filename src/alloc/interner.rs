@@ -0,0 +1,407 @@
+//! String interner whose lookup table groups strings by their local key (length, as per `&str`
+//! [crate::CamiOrd]) before comparing bytes.
+//!
+//! Each slot of its (open addressing) table holds the length and some hash bits of its string, so
+//! a probe compares the dense length first, then the hash bits, and only then the bytes in the
+//! arena.
+use crate::Cami;
+use core::iter::FusedIterator;
+use rust_alloc::string::String;
+use rust_alloc::vec::Vec;
+
+/// Compact ID of an interned string.
+///
+/// Symbols of a [CamiInterner] are in insertion order. Symbols of a [FrozenCamiInterner] are in
+/// [Cami] order of their strings, so their [Ord] is consistent with [Cami] order of `&str`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Symbol(u32);
+
+impl Symbol {
+    #[must_use]
+    #[inline]
+    pub fn to_u32(self) -> u32 {
+        self.0
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn from_u32(from: u32) -> Self {
+        Self(from)
+    }
+
+    #[must_use]
+    #[inline]
+    fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Slot {
+    /// [Symbol] plus one, or 0 if empty.
+    symbol: u32,
+    /// Length of the string (its local key).
+    len: u32,
+    /// High bits of the hash.
+    tag: u32,
+}
+
+const EMPTY: Slot = Slot {
+    symbol: 0,
+    len: 0,
+    tag: 0,
+};
+
+/// FxHash-like. No need for HashDoS resistance or for std (or any other) `Hasher`.
+fn hash_of(bytes: &[u8]) -> u64 {
+    const SEED: u64 = 0x51_7c_c1_b7_27_22_0a_95;
+    let mut hash = bytes.len() as u64;
+    let mut chunks = bytes.chunks_exact(8);
+    for chunk in &mut chunks {
+        let mut buffer = [0; 8];
+        buffer.copy_from_slice(chunk);
+        hash = (hash.rotate_left(5) ^ u64::from_le_bytes(buffer)).wrapping_mul(SEED);
+    }
+    for &byte in chunks.remainder() {
+        hash = (hash.rotate_left(5) ^ u64::from(byte)).wrapping_mul(SEED);
+    }
+    hash ^ (hash >> 32)
+}
+
+/// Strings (in one arena) and their lookup table. Shared by [CamiInterner] and
+/// [FrozenCamiInterner].
+#[derive(Clone, Default)]
+struct Storage {
+    arena: String,
+    /// Start (in `arena`) and length of each string, indexed by [Symbol].
+    spans: Vec<(u32, u32)>,
+    /// Length is 0, or a power of two.
+    slots: Vec<Slot>,
+}
+
+impl Storage {
+    #[inline]
+    fn resolve(&self, symbol: Symbol) -> Option<&str> {
+        let &(start, len) = self.spans.get(symbol.index())?;
+        Some(&self.arena[start as usize..start as usize + len as usize])
+    }
+
+    /// Position in `slots` of `s` (if present), or of the empty slot where it would go.
+    fn find(&self, s: &str, hash: u64) -> Result<usize, usize> {
+        let mask = self.slots.len() - 1;
+        let (len, tag) = (s.len() as u32, (hash >> 32) as u32);
+        let mut position = hash as usize & mask;
+        loop {
+            let slot = self.slots[position];
+            if slot.symbol == 0 {
+                return Err(position);
+            }
+            // The local key (length) first, then the hash bits, and only then the bytes.
+            if slot.len == len
+                && slot.tag == tag
+                && self.resolve(Symbol(slot.symbol - 1)) == Some(s)
+            {
+                return Ok(position);
+            }
+            position = (position + 1) & mask;
+        }
+    }
+
+    fn get(&self, s: &str) -> Option<Symbol> {
+        if self.slots.is_empty() || u32::try_from(s.len()).is_err() {
+            return None;
+        }
+        self.find(s, hash_of(s.as_bytes()))
+            .ok()
+            .map(|position| Symbol(self.slots[position].symbol - 1))
+    }
+
+    /// Grow the table (if needed) so that it has room for one more string, at most 3/4 full.
+    /// Return whether it grew (which moves the slots).
+    fn reserve_one(&mut self) -> bool {
+        if (self.spans.len() + 1) * 4 <= self.slots.len() * 3 {
+            return false;
+        }
+        let new_len = (self.slots.len() * 2).max(16);
+        let mut slots = Vec::new();
+        slots.resize(new_len, EMPTY);
+        let old = core::mem::replace(&mut self.slots, slots);
+        let mask = new_len - 1;
+        for slot in old.into_iter().filter(|slot| slot.symbol != 0) {
+            let (start, len) = self.spans[slot.symbol as usize - 1];
+            let s = &self.arena[start as usize..start as usize + len as usize];
+            let mut position = hash_of(s.as_bytes()) as usize & mask;
+            while self.slots[position].symbol != 0 {
+                position = (position + 1) & mask;
+            }
+            self.slots[position] = slot;
+        }
+        true
+    }
+
+    fn get_or_intern(&mut self, s: &str) -> Symbol {
+        let len =
+            u32::try_from(s.len()).expect("CamiInterner strings are limited to u32::MAX bytes");
+        let hash = hash_of(s.as_bytes());
+        let found = if self.slots.is_empty() {
+            Err(0)
+        } else {
+            self.find(s, hash)
+        };
+        match found {
+            Ok(position) => Symbol(self.slots[position].symbol - 1),
+            Err(mut position) => {
+                // Grow only for a new string. Then the empty slot may have moved.
+                if self.reserve_one() {
+                    position = self.find(s, hash).unwrap_err();
+                }
+                let symbol = u32::try_from(self.spans.len())
+                    .ok()
+                    .filter(|&symbol| symbol < u32::MAX)
+                    .expect("CamiInterner is limited to u32::MAX - 1 strings");
+                let start = u32::try_from(self.arena.len())
+                    .ok()
+                    .filter(|start| start.checked_add(len).is_some())
+                    .expect("CamiInterner arena is limited to u32::MAX bytes");
+                self.arena.push_str(s);
+                self.spans.push((start, len));
+                self.slots[position] = Slot {
+                    symbol: symbol + 1,
+                    len,
+                    tag: (hash >> 32) as u32,
+                };
+                Symbol(symbol)
+            }
+        }
+    }
+}
+
+/// String interner. [CamiInterner::get_or_intern] returns a [Symbol] - the same one for equal
+/// strings. Symbols are assigned in insertion order. To get symbols ordered consistently with
+/// [Cami] order, call [CamiInterner::freeze].
+#[derive(Clone, Default)]
+pub struct CamiInterner {
+    storage: Storage,
+}
+
+impl CamiInterner {
+    #[must_use]
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.storage.spans.len()
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.storage.spans.is_empty()
+    }
+
+    /// # Panics
+    ///
+    /// If there are already `u32::MAX - 1` strings, or if all the strings together would exceed
+    /// [u32::MAX] bytes.
+    #[inline]
+    pub fn get_or_intern(&mut self, s: &str) -> Symbol {
+        self.storage.get_or_intern(s)
+    }
+
+    /// The symbol of `s`, if interned.
+    #[must_use]
+    #[inline]
+    pub fn get(&self, s: &str) -> Option<Symbol> {
+        self.storage.get(s)
+    }
+
+    /// The string of `symbol`, or [None] if `symbol` doesn't come from this interner.
+    #[must_use]
+    #[inline]
+    pub fn resolve(&self, symbol: Symbol) -> Option<&str> {
+        self.storage.resolve(symbol)
+    }
+
+    /// Iterate in insertion order (which is the order of symbols).
+    #[must_use]
+    #[inline]
+    pub fn iter(&self) -> Iter<'_> {
+        Iter {
+            storage: &self.storage,
+            symbols: 0..self.len() as u32,
+        }
+    }
+
+    /// Symbols sorted in [Cami] order of their strings.
+    #[must_use]
+    pub fn cami_order(&self) -> Vec<Symbol> {
+        let mut symbols: Vec<Symbol> = (0..self.len() as u32).map(Symbol).collect();
+        symbols.sort_unstable_by_key(|&symbol| Cami::new(self.storage.resolve(symbol).unwrap()));
+        symbols
+    }
+
+    /// Iterate in [Cami] order of the strings. It sorts (see [CamiInterner::cami_order]) first.
+    #[must_use]
+    pub fn iter_cami(&self) -> impl ExactSizeIterator<Item = (Symbol, &str)> + '_ {
+        self.cami_order()
+            .into_iter()
+            .map(move |symbol| (symbol, self.storage.resolve(symbol).unwrap()))
+    }
+
+    /// Renumber the symbols in [Cami] order of their strings. Use
+    /// [FrozenCamiInterner::remap] to translate symbols issued by this interner.
+    #[must_use]
+    pub fn freeze(self) -> FrozenCamiInterner {
+        let mut storage = self.storage;
+        let order = {
+            let mut order: Vec<u32> = (0..storage.spans.len() as u32).collect();
+            order.sort_unstable_by_key(|&old| Cami::new(storage.resolve(Symbol(old)).unwrap()));
+            order
+        };
+        let mut remap = Vec::new();
+        remap.resize(order.len(), Symbol(0));
+        for (new, &old) in order.iter().enumerate() {
+            remap[old as usize] = Symbol(new as u32);
+        }
+        storage.spans = order
+            .iter()
+            .map(|&old| storage.spans[old as usize])
+            .collect();
+        for slot in storage.slots.iter_mut().filter(|slot| slot.symbol != 0) {
+            slot.symbol = remap[slot.symbol as usize - 1].0 + 1;
+        }
+        FrozenCamiInterner { storage, remap }
+    }
+}
+
+/// Read-only interner, whose symbols are in [Cami] order of their strings: `a < b` if and only if
+/// `Cami::new(resolve(a)) < Cami::new(resolve(b))`. Created by [CamiInterner::freeze].
+#[derive(Clone)]
+pub struct FrozenCamiInterner {
+    storage: Storage,
+    /// New symbol, indexed by the old (insertion order) symbol.
+    remap: Vec<Symbol>,
+}
+
+impl FrozenCamiInterner {
+    #[must_use]
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.storage.spans.len()
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.storage.spans.is_empty()
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn get(&self, s: &str) -> Option<Symbol> {
+        self.storage.get(s)
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn resolve(&self, symbol: Symbol) -> Option<&str> {
+        self.storage.resolve(symbol)
+    }
+
+    /// The (new) symbol for a symbol issued by the [CamiInterner] before freezing, if valid.
+    #[must_use]
+    #[inline]
+    pub fn remap(&self, old: Symbol) -> Option<Symbol> {
+        self.remap.get(old.index()).copied()
+    }
+
+    /// Iterate in [Cami] order (which is the order of symbols).
+    #[must_use]
+    #[inline]
+    pub fn iter(&self) -> Iter<'_> {
+        Iter {
+            storage: &self.storage,
+            symbols: 0..self.len() as u32,
+        }
+    }
+
+    /// Iterate in the original insertion order (with the new symbols).
+    #[must_use]
+    #[inline]
+    pub fn iter_insertion_order(&self) -> impl ExactSizeIterator<Item = (Symbol, &str)> + '_ {
+        self.remap
+            .iter()
+            .map(move |&symbol| (symbol, self.storage.resolve(symbol).unwrap()))
+    }
+}
+
+/// Iterator over symbols and strings, in the order of symbols.
+#[derive(Clone)]
+pub struct Iter<'a> {
+    storage: &'a Storage,
+    symbols: core::ops::Range<u32>,
+}
+
+impl<'a> Iterator for Iter<'a> {
+    type Item = (Symbol, &'a str);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let symbol = Symbol(self.symbols.next()?);
+        Some((symbol, self.storage.resolve(symbol).unwrap()))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.symbols.size_hint()
+    }
+}
+
+impl<'a> DoubleEndedIterator for Iter<'a> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let symbol = Symbol(self.symbols.next_back()?);
+        Some((symbol, self.storage.resolve(symbol).unwrap()))
+    }
+}
+
+impl<'a> ExactSizeIterator for Iter<'a> {}
+impl<'a> FusedIterator for Iter<'a> {}
+
+impl<'a> IntoIterator for &'a CamiInterner {
+    type Item = (Symbol, &'a str);
+    type IntoIter = Iter<'a>;
+    #[inline]
+    fn into_iter(self) -> Iter<'a> {
+        self.iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a FrozenCamiInterner {
+    type Item = (Symbol, &'a str);
+    type IntoIter = Iter<'a>;
+    #[inline]
+    fn into_iter(self) -> Iter<'a> {
+        self.iter()
+    }
+}
+
+impl<'s> Extend<&'s str> for CamiInterner {
+    fn extend<I: IntoIterator<Item = &'s str>>(&mut self, iter: I) {
+        for s in iter {
+            self.get_or_intern(s);
+        }
+    }
+}
+
+impl<'s> FromIterator<&'s str> for CamiInterner {
+    fn from_iter<I: IntoIterator<Item = &'s str>>(iter: I) -> Self {
+        let mut interner = Self::new();
+        interner.extend(iter);
+        interner
+    }
+}
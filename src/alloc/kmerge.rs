@@ -0,0 +1,186 @@
+//! K-way merge of iterators sorted in [crate::Cami] order, with a loser tree (tournament tree).
+//!
+//! After the initial tournament, each item costs only `log2(k)` comparisons: the new head of the
+//! run that won replays its matches against the losers stored on its path to the root. Each match
+//! compares local parts first, and non-local parts only on a tie.
+use crate::cami::cmp_cami;
+use crate::CamiOrd;
+use core::cmp::Ordering;
+use core::fmt;
+use core::iter::FusedIterator;
+use rust_alloc::vec::Vec;
+
+/// Merge any number of iterators (runs), each sorted in [crate::Cami] order. Among fully equal
+/// items (from different runs) the order is unspecified. See [kmerge_cami_stable].
+#[must_use]
+#[inline]
+pub fn kmerge_cami<R>(runs: R) -> KMergeCami<<R::Item as IntoIterator>::IntoIter>
+where
+    R: IntoIterator,
+    R::Item: IntoIterator,
+    <R::Item as IntoIterator>::Item: CamiOrd,
+{
+    KMergeCami::new(runs, false)
+}
+
+/// Like [kmerge_cami], but stable: among fully equal items it yields the ones from earlier runs
+/// first.
+#[must_use]
+#[inline]
+pub fn kmerge_cami_stable<R>(runs: R) -> KMergeCami<<R::Item as IntoIterator>::IntoIter>
+where
+    R: IntoIterator,
+    R::Item: IntoIterator,
+    <R::Item as IntoIterator>::Item: CamiOrd,
+{
+    KMergeCami::new(runs, true)
+}
+
+/// Iterator returned by [kmerge_cami] and [kmerge_cami_stable].
+pub struct KMergeCami<I: Iterator> {
+    runs: Vec<I>,
+    /// Current head of each run, or [None] if the run is exhausted.
+    heads: Vec<Option<I::Item>>,
+    /// `tree[0]` is the index of the winning run. `tree[1..]` are internal nodes (as in a binary
+    /// heap), each holding the index of the run that lost the match there. Leaf of run `i` is
+    /// (virtually) node `heads.len() + i`.
+    tree: Vec<usize>,
+    stable: bool,
+}
+
+impl<I: Iterator + Clone> Clone for KMergeCami<I>
+where
+    I::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            runs: self.runs.clone(),
+            heads: self.heads.clone(),
+            tree: self.tree.clone(),
+            stable: self.stable,
+        }
+    }
+}
+
+impl<I: Iterator + fmt::Debug> fmt::Debug for KMergeCami<I>
+where
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("KMergeCami")
+            .field("runs", &self.runs)
+            .field("heads", &self.heads)
+            .field("stable", &self.stable)
+            .finish()
+    }
+}
+
+impl<I: Iterator> KMergeCami<I>
+where
+    I::Item: CamiOrd,
+{
+    fn new<R>(runs: R, stable: bool) -> Self
+    where
+        R: IntoIterator,
+        R::Item: IntoIterator<IntoIter = I>,
+    {
+        let mut runs: Vec<I> = runs.into_iter().map(IntoIterator::into_iter).collect();
+        let heads: Vec<Option<I::Item>> = runs.iter_mut().map(Iterator::next).collect();
+        let mut merge = Self {
+            runs,
+            heads,
+            tree: Vec::new(),
+            stable,
+        };
+        merge.build();
+        merge
+    }
+
+    /// Whether run `this` beats run `other` (so its head comes first). Exhausted runs lose.
+    #[inline]
+    fn beats(&self, this: usize, other: usize) -> bool {
+        match (&self.heads[this], &self.heads[other]) {
+            (Some(this_head), Some(other_head)) => match cmp_cami(this_head, other_head) {
+                Ordering::Less => true,
+                Ordering::Greater => false,
+                Ordering::Equal => self.stable && this < other,
+            },
+            (Some(_), None) => true,
+            (None, _) => false,
+        }
+    }
+
+    fn build(&mut self) {
+        let k = self.heads.len();
+        if k == 0 {
+            return;
+        }
+        let mut winners = Vec::with_capacity(2 * k);
+        winners.resize(k, 0);
+        winners.extend(0..k);
+        self.tree.resize(k, 0);
+        for node in (1..k).rev() {
+            let (left, right) = (winners[2 * node], winners[2 * node + 1]);
+            // The tie goes to the left (earlier) run, whether stable or not.
+            let (winner, loser) = if self.beats(right, left) {
+                (right, left)
+            } else {
+                (left, right)
+            };
+            winners[node] = winner;
+            self.tree[node] = loser;
+        }
+        self.tree[0] = winners[1];
+    }
+
+    /// Replay the matches of run `run` (whose head has changed) on its path to the root.
+    fn replay(&mut self, run: usize) {
+        let mut winner = run;
+        let mut node = (self.heads.len() + run) / 2;
+        while node >= 1 {
+            let loser = self.tree[node];
+            if self.beats(loser, winner) {
+                self.tree[node] = winner;
+                winner = loser;
+            }
+            node /= 2;
+        }
+        self.tree[0] = winner;
+    }
+}
+
+impl<I: Iterator> Iterator for KMergeCami<I>
+where
+    I::Item: CamiOrd,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        let winner = *self.tree.first()?;
+        // If the winner is exhausted, all runs are.
+        let item = self.heads[winner].take()?;
+        self.heads[winner] = self.runs[winner].next();
+        self.replay(winner);
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let mut lower = 0usize;
+        let mut upper = Some(0usize);
+        for (run, head) in self.runs.iter().zip(&self.heads) {
+            if head.is_some() {
+                let (run_lower, run_upper) = run.size_hint();
+                lower = lower.saturating_add(run_lower).saturating_add(1);
+                upper = match (upper, run_upper) {
+                    (Some(upper), Some(run_upper)) => upper
+                        .checked_add(run_upper)
+                        .and_then(|upper| upper.checked_add(1)),
+                    _ => None,
+                };
+            }
+        }
+        (lower, upper)
+    }
+}
+
+impl<I: FusedIterator> FusedIterator for KMergeCami<I> where I::Item: CamiOrd {}
@@ -0,0 +1,32 @@
+use crate::{Cami, CamiPartialEq};
+use rust_alloc::rc::Rc;
+
+/// NOT a [Cami] of an [Rc] slice, BUT an [Rc] slice of [Cami].
+pub type RcSliceCami<T> = Rc<[Cami<T>]>;
+
+pub trait IntoRcSliceCami<T: CamiPartialEq> {
+    #[must_use]
+    fn into_rc_slice_cami(self) -> Rc<[Cami<T>]>;
+}
+/// Zero cost (no reallocation, no change of reference counts), see [Cami::wrap_rc_slice].
+impl<T: CamiPartialEq> IntoRcSliceCami<T> for Rc<[T]> {
+    #[must_use]
+    #[inline]
+    fn into_rc_slice_cami(self) -> Rc<[Cami<T>]> {
+        Cami::wrap_rc_slice(self)
+    }
+}
+
+/// The reverse of [IntoRcSliceCami].
+pub trait FromRcSliceCami<T> {
+    #[must_use]
+    fn into_unwrapped_rc_slice(self) -> Rc<[T]>;
+}
+/// Zero cost (no reallocation, no change of reference counts), see [Cami::unwrap_rc_slice].
+impl<T: CamiPartialEq> FromRcSliceCami<T> for Rc<[Cami<T>]> {
+    #[must_use]
+    #[inline]
+    fn into_unwrapped_rc_slice(self) -> Rc<[T]> {
+        Cami::unwrap_rc_slice(self)
+    }
+}
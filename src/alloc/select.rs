@@ -0,0 +1,28 @@
+//! Allocating counterpart of [crate::core::top_k_cami_in].
+use crate::core::{sift_up, top_k_rest};
+use crate::{CamiLocalKey, CamiOrd};
+use rust_alloc::vec::Vec;
+
+/// The (up to) `k` smallest items of `iter`, sorted in [crate::Cami] order. Unstable. It keeps at
+/// most `k` items at a time, in a bounded max-heap - see [crate::core::top_k_cami_in].
+#[must_use]
+pub fn top_k_cami<T, I>(iter: I, k: usize) -> Vec<T>
+where
+    T: CamiOrd + CamiLocalKey,
+    I: IntoIterator<Item = T>,
+{
+    let mut iter = iter.into_iter();
+    let mut heap = Vec::with_capacity(k.min(iter.size_hint().0));
+    while heap.len() < k {
+        match iter.next() {
+            Some(item) => {
+                heap.push(item);
+                let last = heap.len() - 1;
+                sift_up(&mut heap, last);
+            }
+            None => break,
+        }
+    }
+    top_k_rest(&mut heap, iter);
+    heap
+}
@@ -0,0 +1,37 @@
+//! Collecting counterparts of the set algebra in [crate::core] - see [crate::core::SetOpCami].
+use crate::core::{
+    difference_cami, intersection_cami, symmetric_difference_cami, union_cami, SetOpCami,
+};
+use crate::CamiOrd;
+use rust_alloc::vec::Vec;
+
+#[inline]
+fn cloned<T: CamiOrd + Clone>(set_op: SetOpCami<'_, T>) -> Vec<T> {
+    let mut result = Vec::with_capacity(set_op.size_hint().0);
+    result.extend(set_op.cloned());
+    result
+}
+
+/// Like [intersection_cami], but collecting (clones of) the items.
+#[must_use]
+pub fn intersection_cami_to_vec<T: CamiOrd + Clone>(left: &[T], right: &[T]) -> Vec<T> {
+    cloned(intersection_cami(left, right))
+}
+
+/// Like [union_cami], but collecting (clones of) the items.
+#[must_use]
+pub fn union_cami_to_vec<T: CamiOrd + Clone>(left: &[T], right: &[T]) -> Vec<T> {
+    cloned(union_cami(left, right))
+}
+
+/// Like [difference_cami], but collecting (clones of) the items.
+#[must_use]
+pub fn difference_cami_to_vec<T: CamiOrd + Clone>(left: &[T], right: &[T]) -> Vec<T> {
+    cloned(difference_cami(left, right))
+}
+
+/// Like [symmetric_difference_cami], but collecting (clones of) the items.
+#[must_use]
+pub fn symmetric_difference_cami_to_vec<T: CamiOrd + Clone>(left: &[T], right: &[T]) -> Vec<T> {
+    cloned(symmetric_difference_cami(left, right))
+}
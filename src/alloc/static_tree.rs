@@ -0,0 +1,276 @@
+//! Static (read-only) B+ tree index (S+ tree) over sorted [Cami] items, whose nodes hold only
+//! local keys (as per [CamiLocalKey]).
+//!
+//! Each node is one 64-byte cache line with [NODE_KEYS] keys, so a search touches one cache line
+//! per level. It dereferences the items (and their non-local parts) only once it narrows down to
+//! the items whose local keys tie with the needle's. See
+//! [https://algorithmica.org/en/b-tree](https://algorithmica.org/en/b-tree).
+use crate::{Cami, CamiLocalKey, CamiOrd, CamiPartialEq};
+use core::cmp::Ordering;
+use core::ops::{Bound, RangeBounds};
+use core::slice;
+use rust_alloc::vec::Vec;
+
+/// Number of keys per node. With `usize` local keys (for example lengths) a node is exactly one
+/// 64-byte cache line. (Smaller keys leave the rest of the line unused.)
+pub const NODE_KEYS: usize = 8;
+
+#[repr(C, align(64))]
+#[derive(Clone, Copy)]
+struct Node<K: Copy> {
+    keys: [K; NODE_KEYS],
+}
+
+impl<K: Ord + Copy> Node<K> {
+    /// Number of keys less than `key` (or, if `inclusive`, less than or equal to `key`). Without
+    /// branches on the comparison results.
+    #[inline]
+    fn count(&self, key: &K, inclusive: bool) -> usize {
+        if inclusive {
+            self.keys.iter().filter(|k| *k <= key).count()
+        } else {
+            self.keys.iter().filter(|k| *k < key).count()
+        }
+    }
+}
+
+/// Static search index of [Cami] items. Build it from sorted items with
+/// [CamiStaticTree::from_sorted_vec], or from an iterator in any order. Duplicates are kept.
+///
+/// The items themselves stay in a sorted [Vec], so [CamiStaticTree::rank] is an index into
+/// [CamiStaticTree::as_slice], and ranges are sub-slices.
+pub struct CamiStaticTree<T: CamiOrd + CamiLocalKey> {
+    items: Vec<Cami<T>>,
+    /// Layers of nodes, leaves first. The leaf layer holds local keys of all items (in the same
+    /// order), padded with the maximum key. Key `i` of an internal node is the minimum key of its
+    /// child `i + 1`.
+    nodes: Vec<Node<T::LocalKey>>,
+    /// Index (into `nodes`) of the first node of each layer, leaves first. The last layer has one
+    /// node: the root.
+    layers: Vec<usize>,
+}
+
+impl<T: CamiOrd + CamiLocalKey> CamiStaticTree<T> {
+    /// Build the index over `items`, which must be sorted in [Cami] order.
+    ///
+    /// # Panics
+    ///
+    /// If `items` are not sorted.
+    #[must_use]
+    pub fn from_sorted_vec(items: Vec<Cami<T>>) -> Self {
+        assert!(
+            items.windows(2).all(|pair| pair[0] <= pair[1]),
+            "items must be sorted in Cami order"
+        );
+        let keys: Vec<T::LocalKey> = items.iter().map(CamiLocalKey::local_key).collect();
+        let (nodes, layers) = match keys.last() {
+            Some(&max) => Self::build(&keys, max),
+            None => (Vec::new(), Vec::new()),
+        };
+        Self {
+            items,
+            nodes,
+            layers,
+        }
+    }
+
+    fn build(keys: &[T::LocalKey], max: T::LocalKey) -> (Vec<Node<T::LocalKey>>, Vec<usize>) {
+        let mut nodes = Vec::new();
+        let mut layers = Vec::new();
+        layers.push(0);
+        let leaf_count = (keys.len() + NODE_KEYS - 1) / NODE_KEYS;
+        for leaf in 0..leaf_count {
+            let mut node = Node {
+                keys: [max; NODE_KEYS],
+            };
+            for (slot, key) in node
+                .keys
+                .iter_mut()
+                .zip(&keys[leaf * NODE_KEYS..keys.len().min((leaf + 1) * NODE_KEYS)])
+            {
+                *slot = *key;
+            }
+            nodes.push(node);
+        }
+        let mut count = leaf_count;
+        while count > 1 {
+            // Layers below this one (in 0..height), counting from the leaves.
+            let height = layers.len();
+            layers.push(nodes.len());
+            let parent_count = (count + NODE_KEYS) / (NODE_KEYS + 1);
+            for parent in 0..parent_count {
+                let mut node = Node {
+                    keys: [max; NODE_KEYS],
+                };
+                for (i, slot) in node.keys.iter_mut().enumerate() {
+                    let child = parent * (NODE_KEYS + 1) + i + 1;
+                    if child < count {
+                        // The leftmost leaf under `child`.
+                        let mut leaf = child;
+                        for _ in 1..height {
+                            leaf *= NODE_KEYS + 1;
+                        }
+                        *slot = keys[leaf * NODE_KEYS];
+                    }
+                }
+                nodes.push(node);
+            }
+            count = parent_count;
+        }
+        (nodes, layers)
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// The items, sorted in [Cami] order.
+    #[must_use]
+    #[inline]
+    pub fn as_slice(&self) -> &[Cami<T>] {
+        &self.items
+    }
+
+    /// Index of the first item whose local key is not less than `key` (or, if `inclusive`, is
+    /// greater than `key`). It doesn't touch the items.
+    fn local_bound(&self, key: &T::LocalKey, inclusive: bool) -> usize {
+        let len = self.items.len();
+        if len == 0 {
+            return 0;
+        }
+        // The maximum key (used for padding) is the last key of the last leaf. Handle it here,
+        // so that the descent below never steps into a non-existing (padding) child.
+        let max = &self.nodes[(len - 1) / NODE_KEYS].keys[(len - 1) % NODE_KEYS];
+        if if inclusive { key >= max } else { key > max } {
+            return len;
+        }
+        let mut k = 0usize;
+        for layer in (1..self.layers.len()).rev() {
+            let i = self.nodes[self.layers[layer] + k].count(key, inclusive);
+            k = k * (NODE_KEYS + 1) + i;
+        }
+        let i = self.nodes[k].count(key, inclusive);
+        (k * NODE_KEYS + i).min(len)
+    }
+
+    /// Index (into [CamiStaticTree::as_slice]) of the first item that is NOT less than `needle`
+    /// (if `inclusive`: that is greater than `needle`). Non-local parts are compared only among
+    /// the items whose local keys tie with `needle`'s.
+    fn bound(&self, needle: &Cami<T>, inclusive: bool) -> usize {
+        let key = needle.local_key();
+        let start = self.local_bound(&key, false);
+        let end = self.local_bound(&key, true);
+        if !<Cami<T>>::LOCALITY.has_non_local() {
+            return if inclusive { end } else { start };
+        }
+        let needle = needle.in_cami();
+        start
+            + self.items[start..end].partition_point(|item| {
+                match item.in_cami().cmp_non_local(needle) {
+                    Ordering::Less => true,
+                    Ordering::Equal => inclusive,
+                    Ordering::Greater => false,
+                }
+            })
+    }
+
+    /// Number of items less than `needle` - that is, the index (into
+    /// [CamiStaticTree::as_slice]) where `needle` would be inserted before any equal items.
+    #[must_use]
+    #[inline]
+    pub fn rank(&self, needle: &Cami<T>) -> usize {
+        self.bound(needle, false)
+    }
+
+    /// The first item (in [Cami] order) that is NOT less than `needle`.
+    #[must_use]
+    #[inline]
+    pub fn lower_bound(&self, needle: &Cami<T>) -> Option<&Cami<T>> {
+        self.items.get(self.rank(needle))
+    }
+
+    /// An item equal to `needle`, if any.
+    #[must_use]
+    #[inline]
+    pub fn get(&self, needle: &Cami<T>) -> Option<&Cami<T>> {
+        self.lower_bound(needle).filter(|item| *item == needle)
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn contains(&self, needle: &Cami<T>) -> bool {
+        self.get(needle).is_some()
+    }
+
+    /// Items within `range` (in [Cami] order), as a sub-slice of [CamiStaticTree::as_slice].
+    #[must_use]
+    pub fn range_slice<R: RangeBounds<Cami<T>>>(&self, range: R) -> &[Cami<T>] {
+        let start = match range.start_bound() {
+            Bound::Included(start) => self.bound(start, false),
+            Bound::Excluded(start) => self.bound(start, true),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(end) => self.bound(end, true),
+            Bound::Excluded(end) => self.bound(end, false),
+            Bound::Unbounded => self.items.len(),
+        };
+        // For example, `b..a` where `a < b`.
+        &self.items[start..end.max(start)]
+    }
+
+    /// Iterate over items within `range`, in [Cami] order.
+    #[inline]
+    pub fn range<R: RangeBounds<Cami<T>>>(&self, range: R) -> slice::Iter<'_, Cami<T>> {
+        self.range_slice(range).iter()
+    }
+
+    /// Iterate in [Cami] order.
+    #[inline]
+    pub fn iter(&self) -> slice::Iter<'_, Cami<T>> {
+        self.items.iter()
+    }
+
+    /// Items sorted in [Cami] order.
+    #[must_use]
+    #[inline]
+    pub fn into_sorted_vec(self) -> Vec<Cami<T>> {
+        self.items
+    }
+}
+
+impl<T: CamiOrd + CamiLocalKey> FromIterator<Cami<T>> for CamiStaticTree<T> {
+    /// Collect and sort (stable) the items.
+    fn from_iter<I: IntoIterator<Item = Cami<T>>>(iter: I) -> Self {
+        let mut items: Vec<Cami<T>> = iter.into_iter().collect();
+        items.sort();
+        Self::from_sorted_vec(items)
+    }
+}
+
+impl<'a, T: CamiOrd + CamiLocalKey> IntoIterator for &'a CamiStaticTree<T> {
+    type Item = &'a Cami<T>;
+    type IntoIter = slice::Iter<'a, Cami<T>>;
+    #[inline]
+    fn into_iter(self) -> slice::Iter<'a, Cami<T>> {
+        self.iter()
+    }
+}
+
+impl<T: CamiOrd + CamiLocalKey + Clone> Clone for CamiStaticTree<T> {
+    fn clone(&self) -> Self {
+        Self {
+            items: self.items.clone(),
+            nodes: self.nodes.clone(),
+            layers: self.layers.clone(),
+        }
+    }
+}
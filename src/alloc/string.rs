@@ -1,7 +1,9 @@
 use crate as cami; // For macros
-use crate::{Cami, CamiOrd, CamiPartialEq, CamiPartialOrd};
+use crate::core::cami_cross_deref;
+use crate::{Cami, CamiLocalKey, CamiOrd, CamiPartialEq, CamiPartialOrd};
 use cami_helpers::{cami_ord, cami_partial_eq, Locality};
 use core::cmp::Ordering;
+use rust_alloc::boxed::Box;
 use rust_alloc::string::String;
 
 // @TODO rename to CamiString, or: remove?
@@ -28,3 +30,126 @@ cami_ord! {
     [{|v: &String| v.len()}]
     [(|this: &String, other: &String| this.cmp(other))]
 }
+
+/// The length (in bytes), consistent with [CamiOrd::cmp_local] for [String].
+impl CamiLocalKey for String {
+    type LocalKey = usize;
+    #[must_use]
+    #[inline]
+    fn local_key(&self) -> usize {
+        self.len()
+    }
+}
+
+// @TODO rename to CamiBoxStr, or: remove?
+pub type BoxStrCami = Cami<Box<str>>;
+
+/// The same as for `&str` and [String].
+impl CamiPartialEq for Box<str> {
+    const LOCALITY: Locality = Locality::Both;
+
+    #[must_use]
+    #[inline]
+    fn eq_local(&self, other: &Self) -> bool {
+        self.len() == other.len()
+    }
+
+    #[must_use]
+    #[inline]
+    fn eq_non_local(&self, other: &Self) -> bool {
+        self == other
+    }
+}
+
+impl CamiPartialOrd for Box<str> {
+    #[must_use]
+    #[inline]
+    fn partial_cmp_local(&self, other: &Self) -> Option<Ordering> {
+        self.len().partial_cmp(&other.len())
+    }
+    #[must_use]
+    #[inline]
+    fn partial_cmp_non_local(&self, other: &Self) -> Option<Ordering> {
+        self.partial_cmp(other)
+    }
+
+    #[must_use]
+    #[inline]
+    fn lt_local(&self, other: &Self) -> bool {
+        self.len() < other.len()
+    }
+    #[must_use]
+    #[inline]
+    fn lt_non_local(&self, other: &Self) -> bool {
+        self < other
+    }
+
+    #[must_use]
+    #[inline]
+    fn le_local(&self, other: &Self) -> bool {
+        self.len() <= other.len()
+    }
+    #[must_use]
+    #[inline]
+    fn le_non_local(&self, other: &Self) -> bool {
+        self <= other
+    }
+
+    #[must_use]
+    #[inline]
+    fn gt_local(&self, other: &Self) -> bool {
+        self.len() > other.len()
+    }
+    #[must_use]
+    #[inline]
+    fn gt_non_local(&self, other: &Self) -> bool {
+        self > other
+    }
+
+    #[must_use]
+    #[inline]
+    fn ge_local(&self, other: &Self) -> bool {
+        self.len() >= other.len()
+    }
+    #[must_use]
+    #[inline]
+    fn ge_non_local(&self, other: &Self) -> bool {
+        self >= other
+    }
+}
+
+impl CamiOrd for Box<str> {
+    #[must_use]
+    #[inline]
+    fn cmp_local(&self, other: &Self) -> Ordering {
+        self.len().cmp(&other.len())
+    }
+
+    #[must_use]
+    #[inline]
+    fn cmp_non_local(&self, other: &Self) -> Ordering {
+        self.cmp(other)
+    }
+}
+
+impl CamiLocalKey for Box<str> {
+    type LocalKey = usize;
+    #[must_use]
+    #[inline]
+    fn local_key(&self) -> usize {
+        self.len()
+    }
+}
+
+// Heterogeneous comparisons, for example `Cami<String>` with `Cami<&str>`, or searching a
+// `[Cami<String>]` for a `&str` needle (with no need to allocate a temporary `String`).
+cami_cross_deref! { ['a] ['a] str, String, &'a str }
+cami_cross_deref! { [] [] str, String, Box<str> }
+cami_cross_deref! { [] [] str, String, str }
+
+cami_cross_deref! { ['a] ['a] str, &'a str, String }
+cami_cross_deref! { ['a] ['a] str, &'a str, Box<str> }
+
+cami_cross_deref! { [] [] str, Box<str>, String }
+cami_cross_deref! { ['a] ['a] str, Box<str>, &'a str }
+cami_cross_deref! { [] [] str, Box<str>, str }
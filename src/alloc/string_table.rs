@@ -0,0 +1,398 @@
+//! Read-only, sorted string table in a serialized format, loadable with no copying (for example,
+//! from a memory-mapped file).
+//!
+//! # Format (version 1)
+//!
+//! All integers are little-endian.
+//!
+//! | Offset                | Size          | Content                                          |
+//! |-----------------------|---------------|--------------------------------------------------|
+//! | 0                     | 8             | magic: `b"CAMISTRT"`                             |
+//! | 8                     | 4             | version: `u32`, currently `1`                    |
+//! | 12                    | 4             | reserved: `u32`, must be `0`                     |
+//! | 16                    | 8             | number of strings `count`: `u64`                 |
+//! | 24                    | 8             | blob length: `u64`                               |
+//! | 32                    | `4 * count`   | lengths (local keys): `u32` each                 |
+//! |                       | 0 or 4        | zero padding, so that offsets are 8-aligned      |
+//! |                       | `8 * count`   | offsets of strings within the blob: `u64` each   |
+//! |                       | blob length   | blob: UTF-8 bytes of the strings                 |
+//!
+//! Strings are stored one after another in the blob (with no gaps or overlaps, so each offset is the
+//! sum of the previous lengths). They are unique, and sorted in [Cami] order of `&str` (by length first, then
+//! lexicographically). Any other version is rejected with
+//! [CamiStringTableError::UnsupportedVersion]. A new version will be introduced for any change of
+//! the format, rather than reinterpreting the reserved field.
+//!
+//! Loading ([CamiStringTable::from_bytes]) validates the whole table - its structure, bounds, UTF-8
+//! and order - so that lookups can't panic or return wrong results. That's `O(total length)`.
+use crate::Cami;
+use core::fmt;
+use core::str;
+use rust_alloc::vec::Vec;
+
+const MAGIC: &[u8; 8] = b"CAMISTRT";
+/// Version of the format written by [CamiStringTable::build].
+pub const CAMI_STRING_TABLE_VERSION: u32 = 1;
+const HEADER_LEN: usize = 32;
+
+/// Why [CamiStringTable::from_bytes] (or [CamiStringTable::build]) failed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CamiStringTableError {
+    /// Shorter than the header, or than the header says.
+    Truncated {
+        needed: u64,
+        actual: usize,
+    },
+    /// Longer than the header says.
+    TrailingBytes {
+        expected: u64,
+        actual: usize,
+    },
+    BadMagic,
+    UnsupportedVersion(u32),
+    /// The reserved field is not zero.
+    BadReserved(u32),
+    /// The padding (between lengths and offsets) is not zero.
+    BadPadding,
+    /// String at `index` reaches beyond the blob.
+    OutOfBounds {
+        index: u64,
+    },
+    /// Offset of string at `index` is not the sum of lengths of the previous strings.
+    BadOffset {
+        index: u64,
+    },
+    /// The blob is longer than the sum of lengths of all strings.
+    UnusedBlobBytes {
+        used: u64,
+        blob_len: u64,
+    },
+    InvalidUtf8 {
+        index: u64,
+    },
+    /// String at `index` is not greater than the previous one (in [Cami] order).
+    NotSorted {
+        index: u64,
+    },
+    /// (When building) a string is longer than [u32::MAX] bytes.
+    StringTooLong {
+        len: usize,
+    },
+}
+
+impl fmt::Display for CamiStringTableError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Truncated { needed, actual } => write!(
+                f,
+                "string table is truncated: needed {} bytes, but got {}",
+                needed, actual
+            ),
+            Self::TrailingBytes { expected, actual } => write!(
+                f,
+                "string table has trailing bytes: expected {} bytes, but got {}",
+                expected, actual
+            ),
+            Self::BadMagic => f.write_str("not a string table (bad magic)"),
+            Self::UnsupportedVersion(version) => {
+                write!(f, "unsupported string table version {}", version)
+            }
+            Self::BadReserved(reserved) => {
+                write!(f, "string table reserved field is {}, not 0", reserved)
+            }
+            Self::BadPadding => f.write_str("string table padding is not zero"),
+            Self::OutOfBounds { index } => {
+                write!(f, "string at index {} is out of the blob's bounds", index)
+            }
+            Self::BadOffset { index } => write!(
+                f,
+                "offset of string at index {} doesn't follow the previous string",
+                index
+            ),
+            Self::UnusedBlobBytes { used, blob_len } => write!(
+                f,
+                "string table blob has {} bytes, but its strings use only {}",
+                blob_len, used
+            ),
+            Self::InvalidUtf8 { index } => write!(f, "string at index {} is not UTF-8", index),
+            Self::NotSorted { index } => write!(
+                f,
+                "string at index {} is not greater than the previous one in Cami order",
+                index
+            ),
+            Self::StringTooLong { len } => {
+                write!(f, "string of {} bytes is too long for a string table", len)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl ::std::error::Error for CamiStringTableError {}
+
+#[inline]
+fn read_u32(bytes: &[u8], at: usize) -> u32 {
+    let mut buffer = [0; 4];
+    buffer.copy_from_slice(&bytes[at..at + 4]);
+    u32::from_le_bytes(buffer)
+}
+
+#[inline]
+fn read_u64(bytes: &[u8], at: usize) -> u64 {
+    let mut buffer = [0; 8];
+    buffer.copy_from_slice(&bytes[at..at + 8]);
+    u64::from_le_bytes(buffer)
+}
+
+/// Byte offsets of the lengths, offsets and blob sections, and the total size - for `count` strings
+/// and `blob_len` bytes. [None] on overflow.
+fn layout(count: u64, blob_len: u64) -> Option<(u64, u64, u64, u64)> {
+    let lengths_at = HEADER_LEN as u64;
+    let lengths_end = lengths_at.checked_add(count.checked_mul(4)?)?;
+    let offsets_at = lengths_end.checked_add(lengths_end % 8)?;
+    let blob_at = offsets_at.checked_add(count.checked_mul(8)?)?;
+    let total = blob_at.checked_add(blob_len)?;
+    Some((lengths_at, offsets_at, blob_at, total))
+}
+
+/// Read-only view of a serialized string table (see the module documentation), borrowing the bytes
+/// with no copying.
+#[derive(Clone, Copy)]
+pub struct CamiStringTable<'a> {
+    count: usize,
+    lengths: &'a [u8],
+    offsets: &'a [u8],
+    blob: &'a [u8],
+}
+
+impl<'a> CamiStringTable<'a> {
+    /// Serialize `strings` (in the current version of the format): sort them in [Cami] order of
+    /// `&str`, and remove duplicates.
+    pub fn build<'s, I: IntoIterator<Item = &'s str>>(
+        strings: I,
+    ) -> Result<Vec<u8>, CamiStringTableError> {
+        let mut strings: Vec<Cami<&str>> = strings.into_iter().map(Cami::new).collect();
+        strings.sort_unstable();
+        strings.dedup();
+        if let Some(long) = strings.iter().find(|s| u32::try_from(s.len()).is_err()) {
+            return Err(CamiStringTableError::StringTooLong { len: long.len() });
+        }
+        let count = strings.len() as u64;
+        let blob_len: u64 = strings.iter().map(|s| s.len() as u64).sum();
+        let (_, offsets_at, blob_at, total) =
+            layout(count, blob_len).expect("in-memory strings fit into u64");
+
+        let mut bytes = Vec::with_capacity(total as usize);
+        bytes.extend_from_slice(MAGIC);
+        bytes.extend_from_slice(&CAMI_STRING_TABLE_VERSION.to_le_bytes());
+        bytes.extend_from_slice(&0u32.to_le_bytes());
+        bytes.extend_from_slice(&count.to_le_bytes());
+        bytes.extend_from_slice(&blob_len.to_le_bytes());
+        for s in &strings {
+            bytes.extend_from_slice(&(s.len() as u32).to_le_bytes());
+        }
+        bytes.resize(offsets_at as usize, 0);
+        let mut offset = 0u64;
+        for s in &strings {
+            bytes.extend_from_slice(&offset.to_le_bytes());
+            offset += s.len() as u64;
+        }
+        debug_assert_eq!(bytes.len() as u64, blob_at);
+        for s in &strings {
+            bytes.extend_from_slice(s.as_bytes());
+        }
+        debug_assert_eq!(bytes.len() as u64, total);
+        Ok(bytes)
+    }
+
+    /// Validate and load `bytes` with no copying. They don't need to be aligned.
+    pub fn from_bytes(bytes: &'a [u8]) -> Result<Self, CamiStringTableError> {
+        if bytes.len() < HEADER_LEN {
+            return Err(CamiStringTableError::Truncated {
+                needed: HEADER_LEN as u64,
+                actual: bytes.len(),
+            });
+        }
+        if &bytes[..8] != MAGIC {
+            return Err(CamiStringTableError::BadMagic);
+        }
+        let version = read_u32(bytes, 8);
+        if version != CAMI_STRING_TABLE_VERSION {
+            return Err(CamiStringTableError::UnsupportedVersion(version));
+        }
+        let reserved = read_u32(bytes, 12);
+        if reserved != 0 {
+            return Err(CamiStringTableError::BadReserved(reserved));
+        }
+        let count = read_u64(bytes, 16);
+        let blob_len = read_u64(bytes, 24);
+        let (lengths_at, offsets_at, blob_at, total) =
+            layout(count, blob_len).ok_or(CamiStringTableError::Truncated {
+                needed: u64::MAX,
+                actual: bytes.len(),
+            })?;
+        if (bytes.len() as u64) < total {
+            return Err(CamiStringTableError::Truncated {
+                needed: total,
+                actual: bytes.len(),
+            });
+        }
+        if (bytes.len() as u64) > total {
+            return Err(CamiStringTableError::TrailingBytes {
+                expected: total,
+                actual: bytes.len(),
+            });
+        }
+        // All fit into `bytes.len()`, so into usize, too.
+        let (count_usize, lengths_at, offsets_at, blob_at) = (
+            count as usize,
+            lengths_at as usize,
+            offsets_at as usize,
+            blob_at as usize,
+        );
+        let lengths_end = lengths_at + 4 * count_usize;
+        if bytes[lengths_end..offsets_at].iter().any(|&b| b != 0) {
+            return Err(CamiStringTableError::BadPadding);
+        }
+        let table = Self {
+            count: count_usize,
+            lengths: &bytes[lengths_at..lengths_end],
+            offsets: &bytes[offsets_at..blob_at],
+            blob: &bytes[blob_at..],
+        };
+        table.validate()?;
+        Ok(table)
+    }
+
+    fn validate(&self) -> Result<(), CamiStringTableError> {
+        let mut previous: Option<&str> = None;
+        // Strings are stored one after another, so this is the expected offset.
+        let mut offset = 0usize;
+        for index in 0..self.count {
+            if read_u64(self.offsets, 8 * index) != offset as u64 {
+                return Err(CamiStringTableError::BadOffset {
+                    index: index as u64,
+                });
+            }
+            let end = offset
+                .checked_add(self.len_at(index))
+                .filter(|&end| end <= self.blob.len())
+                .ok_or(CamiStringTableError::OutOfBounds {
+                    index: index as u64,
+                })?;
+            let s = str::from_utf8(&self.blob[offset..end]).map_err(|_| {
+                CamiStringTableError::InvalidUtf8 {
+                    index: index as u64,
+                }
+            })?;
+            if let Some(previous) = previous {
+                if Cami::new(previous) >= Cami::new(s) {
+                    return Err(CamiStringTableError::NotSorted {
+                        index: index as u64,
+                    });
+                }
+            }
+            previous = Some(s);
+            offset = end;
+        }
+        if offset != self.blob.len() {
+            return Err(CamiStringTableError::UnusedBlobBytes {
+                used: offset as u64,
+                blob_len: self.blob.len() as u64,
+            });
+        }
+        Ok(())
+    }
+
+    /// Number of strings.
+    #[must_use]
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Length of the string at `index` (its local key). It reads only the dense lengths array.
+    #[must_use]
+    #[inline]
+    pub fn len_at(&self, index: usize) -> usize {
+        read_u32(self.lengths, 4 * index) as usize
+    }
+
+    /// Bytes of the string at `index`, if any.
+    #[inline]
+    fn bytes_at(&self, index: usize) -> Option<&'a [u8]> {
+        if index >= self.count {
+            return None;
+        }
+        let offset = read_u64(self.offsets, 8 * index) as usize;
+        self.blob
+            .get(offset..offset.checked_add(self.len_at(index))?)
+    }
+
+    /// String at `index` (in [Cami] order), if any.
+    #[must_use]
+    pub fn get(&self, index: usize) -> Option<&'a str> {
+        // Validated by from_bytes. (Not using from_utf8_unchecked, to keep this module safe.)
+        Some(str::from_utf8(self.bytes_at(index)?).expect("validated UTF-8"))
+    }
+
+    /// Index of `needle`, if present. Local-first: it binary searches the dense lengths array
+    /// first, and then it compares bytes only among strings of the same length as `needle`.
+    #[must_use]
+    pub fn find(&self, needle: &str) -> Option<usize> {
+        let (len, needle) = (needle.len(), needle.as_bytes());
+        let start = self.partition_point(0, self.count, |i| self.len_at(i) < len);
+        let end = self.partition_point(start, self.count, |i| self.len_at(i) <= len);
+        let index = self.partition_point(start, end, |i| self.bytes_at(i).unwrap() < needle);
+        if index < end && self.bytes_at(index) == Some(needle) {
+            Some(index)
+        } else {
+            None
+        }
+    }
+
+    /// [slice::partition_point] over indexes `start..end`: the first index for which `pred`
+    /// returns `false`. `pred` must be `true` for a (possibly empty) prefix of `start..end` only.
+    fn partition_point<P: FnMut(usize) -> bool>(
+        &self,
+        start: usize,
+        end: usize,
+        mut pred: P,
+    ) -> usize {
+        let (mut lo, mut hi) = (start, end);
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            if pred(mid) {
+                lo = mid + 1;
+            } else {
+                hi = mid;
+            }
+        }
+        lo
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn contains(&self, needle: &str) -> bool {
+        self.find(needle).is_some()
+    }
+
+    /// Iterate in [Cami] order.
+    #[inline]
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = &'a str> + DoubleEndedIterator + 'a {
+        let table = *self;
+        (0..self.count).map(move |index| table.get(index).unwrap())
+    }
+}
+
+impl<'a> fmt::Debug for CamiStringTable<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
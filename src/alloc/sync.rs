@@ -0,0 +1,32 @@
+use crate::{Cami, CamiPartialEq};
+use rust_alloc::sync::Arc;
+
+/// NOT a [Cami] of an [Arc] slice, BUT an [Arc] slice of [Cami].
+pub type ArcSliceCami<T> = Arc<[Cami<T>]>;
+
+pub trait IntoArcSliceCami<T: CamiPartialEq> {
+    #[must_use]
+    fn into_arc_slice_cami(self) -> Arc<[Cami<T>]>;
+}
+/// Zero cost (no reallocation, no change of reference counts), see [Cami::wrap_arc_slice].
+impl<T: CamiPartialEq> IntoArcSliceCami<T> for Arc<[T]> {
+    #[must_use]
+    #[inline]
+    fn into_arc_slice_cami(self) -> Arc<[Cami<T>]> {
+        Cami::wrap_arc_slice(self)
+    }
+}
+
+/// The reverse of [IntoArcSliceCami].
+pub trait FromArcSliceCami<T> {
+    #[must_use]
+    fn into_unwrapped_arc_slice(self) -> Arc<[T]>;
+}
+/// Zero cost (no reallocation, no change of reference counts), see [Cami::unwrap_arc_slice].
+impl<T: CamiPartialEq> FromArcSliceCami<T> for Arc<[Cami<T>]> {
+    #[must_use]
+    #[inline]
+    fn into_unwrapped_arc_slice(self) -> Arc<[T]> {
+        Cami::unwrap_arc_slice(self)
+    }
+}
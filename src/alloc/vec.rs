@@ -1,10 +1,17 @@
-use crate::{Cami, CamiPartialEq};
+use crate::core::cami_cross_deref;
+use crate::{Cami, CamiLocalKey, CamiOrd, CamiPartialEq, CamiPartialOrd, Locality};
+use core::cmp::Ordering;
 use rust_alloc::vec::Vec;
 
 /// NOT a [Cami] of [Vec], BUT a [Vec] of [Cami]. If you really need [Cami] of [Vec], use
 /// `Cami<Vec<T>>`.
 pub type VecCami<T> = Vec<Cami<T>>;
 
+/// For `into_vec_cami` alone, prefer [Cami::wrap_vec], which is always available.
+/// [IntoVecCami::into_ref_vec_cami] and [IntoVecCami::into_mut_vec_cami] need `transmute` feature,
+/// because the layout of `Vec<T>` is not guaranteed to be the same as of `Vec<Cami<T>>` (even
+/// though the layout of their items is). Use [Cami::wrap_slice] and [Cami::wrap_slice_mut] instead,
+/// where possible.
 pub trait IntoVecCami<T>
 where
     T: CamiPartialEq,
@@ -21,7 +28,7 @@ impl<T: CamiPartialEq> IntoVecCami<T> for Vec<T> {
     #[must_use]
     #[inline]
     fn into_vec_cami(self) -> Vec<Cami<T>> {
-        unsafe { core::mem::transmute(self) }
+        Cami::wrap_vec(self)
     }
     #[must_use]
     #[inline]
@@ -34,3 +41,161 @@ impl<T: CamiPartialEq> IntoVecCami<T> for Vec<T> {
         unsafe { core::mem::transmute(self) }
     }
 }
+
+/// The reverse of [IntoVecCami::into_vec_cami]: from Cami order back to the "classic" order of the
+/// items.
+pub trait FromVecCami<T> {
+    #[must_use]
+    fn into_unwrapped_vec(self) -> Vec<T>;
+}
+/// Zero cost (no reallocation), see [Cami::unwrap_vec]. No need for `transmute` feature.
+impl<T: CamiPartialEq> FromVecCami<T> for Vec<Cami<T>> {
+    #[must_use]
+    #[inline]
+    fn into_unwrapped_vec(self) -> Vec<T> {
+        Cami::unwrap_vec(self)
+    }
+}
+
+/// Remove consecutive duplicates from a [VecCami] - typically sorted in [Cami] order, so that it
+/// removes all duplicates. Both keep the first item of each run. See also
+/// [crate::core::GroupCami].
+pub trait DedupCami {
+    /// Like [Vec::dedup], but with [Cami] equality: local parts first, and then the non-local parts
+    /// only for neighbours whose local parts are equal.
+    fn dedup_cami(&mut self);
+
+    /// Keep only one item per run of items with equal local parts ([CamiPartialEq::eq_local]). It
+    /// doesn't access non-local parts at all.
+    fn dedup_by_local(&mut self);
+}
+impl<T: CamiPartialEq> DedupCami for Vec<Cami<T>> {
+    #[inline]
+    fn dedup_cami(&mut self) {
+        self.dedup_by(|next, kept| next == kept);
+    }
+
+    #[inline]
+    fn dedup_by_local(&mut self) {
+        if T::LOCALITY.has_local() {
+            self.dedup_by(|next, kept| next.in_cami().eq_local(kept.in_cami()));
+        } else {
+            self.truncate(1);
+        }
+    }
+}
+
+/// The same as for `&[T]`. This is for [Cami] of [Vec] (`Cami<Vec<T>>`), which is NOT the same as
+/// [VecCami].
+impl<T> CamiPartialEq for Vec<T>
+where
+    T: PartialEq,
+{
+    const LOCALITY: Locality = Locality::Both;
+
+    #[must_use]
+    #[inline]
+    fn eq_local(&self, other: &Self) -> bool {
+        self.len() == other.len()
+    }
+
+    #[must_use]
+    #[inline]
+    fn eq_non_local(&self, other: &Self) -> bool {
+        self == other
+    }
+}
+
+impl<T> CamiPartialOrd for Vec<T>
+where
+    T: PartialOrd,
+{
+    #[must_use]
+    #[inline]
+    fn partial_cmp_local(&self, other: &Self) -> Option<Ordering> {
+        self.len().partial_cmp(&other.len())
+    }
+    #[must_use]
+    #[inline]
+    fn partial_cmp_non_local(&self, other: &Self) -> Option<Ordering> {
+        self.partial_cmp(other)
+    }
+
+    #[must_use]
+    #[inline]
+    fn lt_local(&self, other: &Self) -> bool {
+        self.len() < other.len()
+    }
+    #[must_use]
+    #[inline]
+    fn lt_non_local(&self, other: &Self) -> bool {
+        self < other
+    }
+
+    #[must_use]
+    #[inline]
+    fn le_local(&self, other: &Self) -> bool {
+        self.len() <= other.len()
+    }
+    #[must_use]
+    #[inline]
+    fn le_non_local(&self, other: &Self) -> bool {
+        self <= other
+    }
+
+    #[must_use]
+    #[inline]
+    fn gt_local(&self, other: &Self) -> bool {
+        self.len() > other.len()
+    }
+    #[must_use]
+    #[inline]
+    fn gt_non_local(&self, other: &Self) -> bool {
+        self > other
+    }
+
+    #[must_use]
+    #[inline]
+    fn ge_local(&self, other: &Self) -> bool {
+        self.len() >= other.len()
+    }
+    #[must_use]
+    #[inline]
+    fn ge_non_local(&self, other: &Self) -> bool {
+        self >= other
+    }
+}
+
+impl<T> CamiOrd for Vec<T>
+where
+    T: Ord,
+{
+    #[must_use]
+    #[inline]
+    fn cmp_local(&self, other: &Self) -> Ordering {
+        self.len().cmp(&other.len())
+    }
+
+    /// See [CamiOrd::cmp_non_local] for `&[T]`.
+    #[must_use]
+    #[inline]
+    fn cmp_non_local(&self, other: &Self) -> Ordering {
+        self.cmp(other)
+    }
+}
+
+/// The length, consistent with [CamiOrd::cmp_local] for [Vec].
+impl<T> CamiLocalKey for Vec<T> {
+    type LocalKey = usize;
+    #[must_use]
+    #[inline]
+    fn local_key(&self) -> usize {
+        self.len()
+    }
+}
+
+// Heterogeneous comparisons, for example `Cami<Vec<T>>` with `Cami<&[T]>`, or searching a
+// `[Cami<Vec<T>>]` for a `&[T]` needle.
+cami_cross_deref! { ['a, T: PartialEq] ['a, T: PartialOrd] [T], Vec<T>, &'a [T] }
+cami_cross_deref! { [T: PartialEq] [T: PartialOrd] [T], Vec<T>, [T] }
+cami_cross_deref! { ['a, T: PartialEq] ['a, T: PartialOrd] [T], &'a [T], Vec<T> }
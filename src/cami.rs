@@ -1,17 +1,17 @@
-use crate::{CamiOrd, CamiPartialEq, CamiPartialOrd, Locality};
+use crate::{CamiLocalKey, CamiOrd, CamiPartialEq, CamiPartialOrd, Locality};
 use core::cmp::Ordering;
 #[cfg(feature = "debug")]
 use core::fmt::{self, Debug};
 #[cfg(feature = "hash")]
 use core::hash::{Hash, Hasher};
-#[cfg(feature = "transmute")]
-use core::mem;
 #[cfg(feature = "deref_pure")]
 use core::ops::DerefPure;
 use core::ops::{Deref, DerefMut};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+mod wrap;
+
 // @TODO once agreed & futureproofed, remove `#[deprecated...]` on field `pub T`.
 //
 // Having an `Rhs` generic (for `CamiPartialEq`) would need a phantom data field, so we couldn't
@@ -20,6 +20,15 @@ use serde::{Deserialize, Serialize};
 // pub struct Cami<T: CamiPartialEq<Rhs>, Rhs: ?Sized = Self>(pub T);
 #[repr(transparent)]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[cfg_attr(
+    feature = "adapt-zerocopy",
+    derive(
+        zerocopy::FromBytes,
+        zerocopy::IntoBytes,
+        zerocopy::KnownLayout,
+        zerocopy::Immutable
+    )
+)]
 pub struct Cami<T: CamiPartialEq + ?Sized>(#[deprecated = "unstable"] pub T);
 //----------
 
@@ -133,18 +142,18 @@ pub trait IntoRefCami {
     #[must_use]
     fn into_mut_cami(&mut self) -> &mut Cami<Self::Wrapped>;
 }
-#[cfg(feature = "transmute")]
+/// Zero cost, see [Cami::wrap_ref]. No need for `transmute` feature.
 impl<T: CamiPartialEq + ?Sized> IntoRefCami for T {
     type Wrapped = Self;
     #[must_use]
     #[inline]
     fn into_ref_cami(&self) -> &Cami<Self> {
-        unsafe { mem::transmute(self) }
+        Cami::wrap_ref(self)
     }
     #[must_use]
     #[inline]
     fn into_mut_cami(&mut self) -> &mut Cami<Self> {
-        unsafe { mem::transmute(self) }
+        Cami::wrap_mut(self)
     }
 }
 
@@ -156,18 +165,86 @@ pub trait IntoSliceCami {
     #[must_use]
     fn into_slice_mut_cami(&mut self) -> &mut [Cami<Self::Wrapped>];
 }
-#[cfg(feature = "transmute")]
+/// Zero cost, see [Cami::wrap_slice]. No need for `transmute` feature.
 impl<T: CamiPartialEq> IntoSliceCami for [T] {
     type Wrapped = T;
     #[must_use]
     #[inline]
     fn into_slice_cami(&self) -> &[Cami<T>] {
-        unsafe { mem::transmute(self) }
+        Cami::wrap_slice(self)
     }
     #[must_use]
     #[inline]
     fn into_slice_mut_cami(&mut self) -> &mut [Cami<T>] {
-        unsafe { mem::transmute(self) }
+        Cami::wrap_slice_mut(self)
+    }
+}
+
+/// The reverse of [IntoSliceCami]: from Cami order back to the "classic" order of the items.
+pub trait FromSliceCami {
+    type Unwrapped;
+    #[must_use]
+    fn as_unwrapped_slice(&self) -> &[Self::Unwrapped];
+    #[must_use]
+    fn as_unwrapped_slice_mut(&mut self) -> &mut [Self::Unwrapped];
+}
+/// Zero cost, see [Cami::unwrap_slice].
+impl<T: CamiPartialEq> FromSliceCami for [Cami<T>] {
+    type Unwrapped = T;
+    #[must_use]
+    #[inline]
+    fn as_unwrapped_slice(&self) -> &[T] {
+        Cami::unwrap_slice(self)
+    }
+    #[must_use]
+    #[inline]
+    fn as_unwrapped_slice_mut(&mut self) -> &mut [T] {
+        Cami::unwrap_slice_mut(self)
+    }
+}
+
+pub trait IntoArrayCami<const N: usize> {
+    type Wrapped: CamiPartialEq;
+    #[must_use]
+    fn into_array_cami(self) -> [Cami<Self::Wrapped>; N];
+    #[must_use]
+    fn into_ref_array_cami(&self) -> &[Cami<Self::Wrapped>; N];
+}
+/// Zero cost, see [Cami::wrap_array].
+impl<T: CamiPartialEq, const N: usize> IntoArrayCami<N> for [T; N] {
+    type Wrapped = T;
+    #[must_use]
+    #[inline]
+    fn into_array_cami(self) -> [Cami<T>; N] {
+        Cami::wrap_array(self)
+    }
+    #[must_use]
+    #[inline]
+    fn into_ref_array_cami(&self) -> &[Cami<T>; N] {
+        Cami::wrap_array_ref(self)
+    }
+}
+
+/// The reverse of [IntoArrayCami].
+pub trait FromArrayCami<const N: usize> {
+    type Unwrapped;
+    #[must_use]
+    fn into_unwrapped_array(self) -> [Self::Unwrapped; N];
+    #[must_use]
+    fn as_unwrapped_array(&self) -> &[Self::Unwrapped; N];
+}
+/// Zero cost, see [Cami::unwrap_array].
+impl<T: CamiPartialEq, const N: usize> FromArrayCami<N> for [Cami<T>; N] {
+    type Unwrapped = T;
+    #[must_use]
+    #[inline]
+    fn into_unwrapped_array(self) -> [T; N] {
+        Cami::unwrap_array(self)
+    }
+    #[must_use]
+    #[inline]
+    fn as_unwrapped_array(&self) -> &[T; N] {
+        Cami::unwrap_array_ref(self)
     }
 }
 //----------
@@ -214,115 +291,176 @@ impl<T: Hash + CamiPartialEq> Hash for Cami<T> {
 }
 //-----
 
-/// Simple forwarding.
-///
-/// NO "Rhs" (right hand side) generic parameter, because then [Cami] would have to contain phantom
-/// data, which would make pattern matching etc. difficult.
-impl<T: CamiPartialEq> PartialEq for Cami<T> {
-    #[must_use]
-    #[inline]
-    fn eq(&self, other: &Self) -> bool {
-        let this = self.in_cami();
-        let other = other.in_cami();
-        // @TODO write a test that the following would return the same
-        //
-        // Write them not in this crate, but in `Cami`` crate - for example, next to the
-        // implementation for `bool`.
-        if false {
-            return (!Self::LOCALITY.has_local() || this.eq_local(&other))
-                && (!Self::LOCALITY.has_non_local() || this.eq_non_local(&other));
+/// Equality as per [CamiPartialEq], respecting [CamiPartialEq::LOCALITY]. Used by `impl PartialEq
+/// for Cami<T>`, and by helpers that compare against needles of a different type (`Rhs`).
+#[must_use]
+#[inline]
+pub(crate) fn eq_cami<T, Rhs>(this: &T, other: &Rhs) -> bool
+where
+    T: CamiPartialEq<Rhs> + ?Sized,
+    Rhs: ?Sized,
+{
+    let locality = <T as CamiPartialEq<Rhs>>::LOCALITY;
+    // @TODO write a test that the following would return the same
+    //
+    // Write them not in this crate, but in `Cami`` crate - for example, next to the
+    // implementation for `bool`.
+    if false {
+        return (!locality.has_local() || this.eq_local(other))
+            && (!locality.has_non_local() || this.eq_non_local(other));
+    }
+    if locality.has_local() {
+        let local = this.eq_local(other);
+        if local {
+            !locality.has_non_local() || this.eq_non_local(other)
+        } else {
+            false
+        }
+    } else {
+        // Any Locality instance has at least one of `has_local` & `has_non_local` return
+        // `true`.
+        debug_assert!(locality.has_non_local());
+        this.eq_non_local(other)
+    }
+}
+
+/// Partial comparison as per [CamiPartialOrd], respecting [CamiPartialEq::LOCALITY]. Used by `impl
+/// PartialOrd for Cami<T>`, and by helpers that compare against needles of a different type
+/// (`Rhs`).
+#[must_use]
+#[inline]
+pub(crate) fn partial_cmp_cami<T, Rhs>(this: &T, other: &Rhs) -> Option<Ordering>
+where
+    T: CamiPartialOrd<Rhs> + CamiPartialEq<Rhs> + ?Sized,
+    Rhs: ?Sized,
+{
+    let locality = <T as CamiPartialEq<Rhs>>::LOCALITY;
+    if locality.has_local() {
+        let local = this.partial_cmp_local(other);
+        if local == None {
+            return None;
         }
-        if Self::LOCALITY.has_local() {
-            let local = this.eq_local(other);
-            if local {
-                !Self::LOCALITY.has_non_local() || this.eq_non_local(other)
+        if local == Some(Ordering::Equal) {
+            if locality.has_non_local() {
+                this.partial_cmp_non_local(other)
             } else {
-                false
+                Some(Ordering::Equal)
             }
         } else {
-            // Any Locality instance has at least one of `has_local` & `has_non_local` return
-            // `true`.
-            debug_assert!(Self::LOCALITY.has_non_local());
-            this.eq_non_local(other)
+            local
         }
+    } else {
+        debug_assert!(locality.has_non_local());
+        this.partial_cmp_non_local(other)
+    }
+}
+
+/// Less than, as per [CamiPartialOrd], respecting [CamiPartialEq::LOCALITY]. The non-local part
+/// applies only if the local parts are equal. Used by `impl PartialOrd for Cami<T>`.
+#[must_use]
+#[inline]
+pub(crate) fn lt_cami<T, Rhs>(this: &T, other: &Rhs) -> bool
+where
+    T: CamiPartialOrd<Rhs> + CamiPartialEq<Rhs> + ?Sized,
+    Rhs: ?Sized,
+{
+    let locality = <T as CamiPartialEq<Rhs>>::LOCALITY;
+    if locality.has_local() {
+        this.lt_local(other)
+            || locality.has_non_local()
+                && CamiPartialEq::<Rhs>::eq_local(this, other)
+                && this.lt_non_local(other)
+    } else {
+        debug_assert!(locality.has_non_local());
+        this.lt_non_local(other)
+    }
+}
+
+/// Simple forwarding.
+///
+/// The "Rhs" (right hand side) generic parameter is on this `impl`, and NOT on [Cami] itself -
+/// because then [Cami] would have to contain phantom data, which would make pattern matching etc.
+/// difficult. So `Cami<T>` can be compared to `Cami<U>` whenever `T: CamiPartialEq<U>` - for example
+/// `Cami<String>` to `Cami<&str>`.
+///
+/// As with [PartialEq] of `String` itself (which is implemented for several right hand sides), the
+/// right hand side has to have a known type. For example, `owned == Cami::new("abc".into())` needs
+/// a type annotation, while `owned == Cami::new("abc")` and `owned == Cami::new(text.to_owned())`
+/// don't.
+impl<T, U> PartialEq<Cami<U>> for Cami<T>
+where
+    T: CamiPartialEq + CamiPartialEq<U>,
+    U: CamiPartialEq,
+{
+    #[must_use]
+    #[inline]
+    fn eq(&self, other: &Cami<U>) -> bool {
+        eq_cami(self.in_cami(), other.in_cami())
     }
 }
 
 impl<T: Eq + CamiPartialEq> Eq for Cami<T> {}
 
-impl<T: CamiPartialOrd> PartialOrd for Cami<T> {
+impl<T, U> PartialOrd<Cami<U>> for Cami<T>
+where
+    T: CamiPartialOrd<U> + CamiPartialEq<U>,
+    U: CamiPartialEq,
+{
     /// This returns [Some] only if BOTH of [CamiPartialOrd::partial_cmp_local] and
     /// [CamiPartialOrd::partial_cmp_local] (as applicable - depending on [CamiPartialEq::LOCALITY])
     /// return [Some].
     #[must_use]
     #[inline]
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        let this = self.in_cami();
-        let other = other.in_cami();
-        if Self::LOCALITY.has_local() {
-            let local = this.partial_cmp_local(other);
-            if local == None {
-                return None;
-            }
-            if local == Some(Ordering::Equal) {
-                if Self::LOCALITY.has_non_local() {
-                    debug_assert!(Self::LOCALITY.has_non_local());
-                    this.partial_cmp_non_local(other)
-                } else {
-                    Some(Ordering::Equal)
-                }
-            } else {
-                local
-            }
-        } else {
-            debug_assert!(Self::LOCALITY.has_non_local());
-            this.partial_cmp_non_local(other)
-        }
+    fn partial_cmp(&self, other: &Cami<U>) -> Option<Ordering> {
+        partial_cmp_cami(self.in_cami(), other.in_cami())
     }
 
-    // Provided methods
+    // Provided methods. The non-local part applies only if the local parts are equal.
     #[must_use]
     #[inline]
-    fn lt(&self, other: &Self) -> bool {
-        let this = self.in_cami();
-        let other = other.in_cami();
-        if Self::LOCALITY.has_local() {
-            this.lt_local(other) || Self::LOCALITY.has_non_local() && this.lt_non_local(other)
-        } else {
-            debug_assert!(Self::LOCALITY.has_non_local());
-            this.lt_non_local(other)
-        }
+    fn lt(&self, other: &Cami<U>) -> bool {
+        lt_cami(self.in_cami(), other.in_cami())
     }
     #[must_use]
     #[inline]
-    fn le(&self, other: &Self) -> bool {
+    fn le(&self, other: &Cami<U>) -> bool {
         let this = self.in_cami();
         let other = other.in_cami();
-        if Self::LOCALITY.has_local() {
-            this.le_local(other) || Self::LOCALITY.has_non_local() && this.le_non_local(other)
+        let locality = <T as CamiPartialEq<U>>::LOCALITY;
+        if locality.has_local() {
+            this.lt_local(other)
+                || CamiPartialEq::<U>::eq_local(this, other)
+                    && (!locality.has_non_local() || this.le_non_local(other))
         } else {
-            debug_assert!(Self::LOCALITY.has_non_local());
+            debug_assert!(locality.has_non_local());
             this.le_non_local(other)
         }
     }
     #[must_use]
     #[inline]
-    fn gt(&self, other: &Self) -> bool {
+    fn gt(&self, other: &Cami<U>) -> bool {
         let this = self.in_cami();
         let other = other.in_cami();
-        if Self::LOCALITY.has_local() {
-            this.gt_local(other) || Self::LOCALITY.has_non_local() && this.gt_non_local(other)
+        let locality = <T as CamiPartialEq<U>>::LOCALITY;
+        if locality.has_local() {
+            this.gt_local(other)
+                || locality.has_non_local()
+                    && CamiPartialEq::<U>::eq_local(this, other)
+                    && this.gt_non_local(other)
         } else {
             this.gt_non_local(other)
         }
     }
     #[must_use]
     #[inline]
-    fn ge(&self, other: &Self) -> bool {
+    fn ge(&self, other: &Cami<U>) -> bool {
         let this = self.in_cami();
         let other = other.in_cami();
-        if Self::LOCALITY.has_local() {
-            this.ge_local(other) || Self::LOCALITY.has_non_local() && this.ge_non_local(other)
+        let locality = <T as CamiPartialEq<U>>::LOCALITY;
+        if locality.has_local() {
+            this.gt_local(other)
+                || CamiPartialEq::<U>::eq_local(this, other)
+                    && (!locality.has_non_local() || this.ge_non_local(other))
         } else {
             this.ge_non_local(other)
         }
@@ -337,24 +475,38 @@ impl<T: CamiOrd> Ord for Cami<T> {
     #[must_use]
     #[inline]
     fn cmp(&self, other: &Self) -> Ordering {
-        let this = self.in_cami();
-        let other = other.in_cami();
+        cmp_cami(self.in_cami(), other.in_cami())
+    }
+}
 
-        if Self::LOCALITY.has_local() {
-            let local = this.cmp_local(other);
-            if local == Ordering::Equal {
-                if Self::LOCALITY.has_non_local() {
-                    this.cmp_non_local(other)
-                } else {
-                    Ordering::Equal
-                }
-            } else {
-                local
-            }
+/// Full comparison as per [CamiOrd], respecting [CamiPartialEq::LOCALITY]. Used by `impl Ord for
+/// Cami<T>`, and by helpers that work with items that are not wrapped in [Cami].
+#[must_use]
+#[inline]
+pub(crate) fn cmp_cami<T: CamiOrd + ?Sized>(this: &T, other: &T) -> Ordering {
+    let (local, non_local) = cmp_cami_tiers(this, other);
+    non_local.or(local).unwrap_or(Ordering::Equal)
+}
+
+/// Results of [CamiOrd::cmp_local] and [CamiOrd::cmp_non_local] - each [None] if it's not
+/// applicable (as per [CamiPartialEq::LOCALITY]), or if it's not needed (the local part decided).
+/// [cmp_cami] is based on this, and so is [crate::explain_cmp].
+#[must_use]
+#[inline]
+pub(crate) fn cmp_cami_tiers<T: CamiOrd + ?Sized>(
+    this: &T,
+    other: &T,
+) -> (Option<Ordering>, Option<Ordering>) {
+    if T::LOCALITY.has_local() {
+        let local = this.cmp_local(other);
+        if local == Ordering::Equal && T::LOCALITY.has_non_local() {
+            (Some(local), Some(this.cmp_non_local(other)))
         } else {
-            debug_assert!(Self::LOCALITY.has_non_local());
-            this.cmp_non_local(other)
+            (Some(local), None)
         }
+    } else {
+        debug_assert!(T::LOCALITY.has_non_local());
+        (None, Some(this.cmp_non_local(other)))
     }
 }
 //-----
@@ -396,7 +548,7 @@ impl<T: CamiPartialEq> CamiPartialEq for Cami<T> {
     #[inline]
     fn eq_non_local(&self, other: &Self) -> bool {
         #![allow(deprecated)]
-        !self.0.eq_non_local(&other.0)
+        self.0.eq_non_local(&other.0)
     }
 }
 
@@ -482,3 +634,13 @@ impl<T: Ord + PartialOrd + PartialEq + CamiPartialEq + CamiOrd> CamiOrd for Cami
         self.0.cmp_non_local(&other.0)
     }
 }
+
+// Simple forwarding.
+impl<T: CamiLocalKey + CamiPartialEq + ?Sized> CamiLocalKey for Cami<T> {
+    type LocalKey = T::LocalKey;
+    #[must_use]
+    #[inline]
+    fn local_key(&self) -> T::LocalKey {
+        self.in_cami().local_key()
+    }
+}
@@ -0,0 +1,277 @@
+//! Zero cost conversions between `T` and [Cami], and between containers of them. Always available
+//! - with no need for `transmute` (or `unsafe`) feature.
+//!
+//! All `unsafe` code of these conversions is here. It is sound because [Cami] is
+//! `#[repr(transparent)]` over its only field `T`, and [F32Total] is `#[repr(transparent)]` over
+//! [f32]. So they have the same size, alignment and ABI (and, for unsized `T`, the same pointer
+//! metadata) as what they wrap. [SameLayout] double checks size & alignment at compile time
+//! (post-monomorphization).
+#![allow(unsafe_code)]
+
+use crate::core::{F32Cami, F32Total};
+use crate::{Cami, CamiPartialEq};
+use core::marker::PhantomData;
+use core::mem::{self, ManuallyDrop};
+use core::{ptr, slice};
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+use rust_alloc::sync::Arc;
+#[cfg(feature = "alloc")]
+use rust_alloc::{boxed::Box, rc::Rc, vec::Vec};
+
+/// Compile-time (post-monomorphization) check that `T` and `U` have the same size & alignment.
+/// Use as `let () = SameLayout::<T, U>::ASSERT;`.
+pub(crate) struct SameLayout<T, U>(PhantomData<(T, U)>);
+
+impl<T, U> SameLayout<T, U> {
+    pub(crate) const ASSERT: () = assert!(
+        mem::size_of::<T>() == mem::size_of::<U>() && mem::align_of::<T>() == mem::align_of::<U>()
+    );
+}
+
+const _: () = SameLayout::<f32, F32Total>::ASSERT;
+const _: () = SameLayout::<f32, F32Cami>::ASSERT;
+
+impl<T: CamiPartialEq + ?Sized> Cami<T> {
+    /// Zero cost. Like [crate::IntoRefCami::into_ref_cami], but always available.
+    #[must_use]
+    #[inline]
+    pub fn wrap_ref(from: &T) -> &Self {
+        // SAFETY: `Cami<T>` is `#[repr(transparent)]` over `T`, so both pointers have the same
+        // layout & metadata. The lifetime is preserved.
+        unsafe { &*(from as *const T as *const Self) }
+    }
+
+    /// Zero cost. Like [crate::IntoRefCami::into_mut_cami], but always available.
+    #[must_use]
+    #[inline]
+    pub fn wrap_mut(from: &mut T) -> &mut Self {
+        // SAFETY: See [Cami::wrap_ref]. Exclusivity is preserved.
+        unsafe { &mut *(from as *mut T as *mut Self) }
+    }
+}
+
+impl<T: CamiPartialEq> Cami<T> {
+    /// Zero cost. Like [crate::IntoSliceCami::into_slice_cami], but always available.
+    #[must_use]
+    #[inline]
+    pub fn wrap_slice(from: &[T]) -> &[Self] {
+        let () = SameLayout::<T, Self>::ASSERT;
+        // SAFETY: Items have the same layout, so the slices do, too. Same length & lifetime.
+        unsafe { slice::from_raw_parts(from.as_ptr() as *const Self, from.len()) }
+    }
+
+    /// Zero cost. Like [crate::IntoSliceCami::into_slice_mut_cami], but always available.
+    #[must_use]
+    #[inline]
+    pub fn wrap_slice_mut(from: &mut [T]) -> &mut [Self] {
+        let () = SameLayout::<T, Self>::ASSERT;
+        // SAFETY: See [Cami::wrap_slice]. Exclusivity is preserved.
+        unsafe { slice::from_raw_parts_mut(from.as_mut_ptr() as *mut Self, from.len()) }
+    }
+
+    /// Zero cost. The reverse of [Cami::wrap_slice].
+    #[must_use]
+    #[inline]
+    pub fn unwrap_slice(from: &[Self]) -> &[T] {
+        let () = SameLayout::<T, Self>::ASSERT;
+        // SAFETY: See [Cami::wrap_slice].
+        unsafe { slice::from_raw_parts(from.as_ptr() as *const T, from.len()) }
+    }
+
+    /// Zero cost. The reverse of [Cami::wrap_slice_mut].
+    #[must_use]
+    #[inline]
+    pub fn unwrap_slice_mut(from: &mut [Self]) -> &mut [T] {
+        let () = SameLayout::<T, Self>::ASSERT;
+        // SAFETY: See [Cami::wrap_slice_mut].
+        unsafe { slice::from_raw_parts_mut(from.as_mut_ptr() as *mut T, from.len()) }
+    }
+
+    /// Zero cost (no reallocation). Like [crate::alloc::vec::IntoVecCami::into_vec_cami], but
+    /// always available.
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    #[inline]
+    pub fn wrap_vec(from: Vec<T>) -> Vec<Self> {
+        let () = SameLayout::<T, Self>::ASSERT;
+        let mut from = ManuallyDrop::new(from);
+        // SAFETY: The buffer was allocated by `Vec` for items with the same size & alignment, so
+        // it's deallocated with the same layout. `from` is not dropped, so the buffer has a single
+        // owner.
+        unsafe { Vec::from_raw_parts(from.as_mut_ptr() as *mut Self, from.len(), from.capacity()) }
+    }
+
+    /// Zero cost (no reallocation). The reverse of [Cami::wrap_vec].
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    #[inline]
+    pub fn unwrap_vec(from: Vec<Self>) -> Vec<T> {
+        let () = SameLayout::<T, Self>::ASSERT;
+        let mut from = ManuallyDrop::new(from);
+        // SAFETY: See [Cami::wrap_vec].
+        unsafe { Vec::from_raw_parts(from.as_mut_ptr() as *mut T, from.len(), from.capacity()) }
+    }
+
+    /// Zero cost (no copy of the items).
+    #[must_use]
+    #[inline]
+    pub fn wrap_array<const N: usize>(from: [T; N]) -> [Self; N] {
+        let () = SameLayout::<[T; N], [Self; N]>::ASSERT;
+        let from = ManuallyDrop::new(from);
+        // SAFETY: Arrays of items with the same layout have the same layout, too. `from` is not
+        // dropped, so the items are moved (rather than duplicated).
+        unsafe { ptr::read(&*from as *const [T; N] as *const [Self; N]) }
+    }
+
+    /// Zero cost (no copy of the items). The reverse of [Cami::wrap_array].
+    #[must_use]
+    #[inline]
+    pub fn unwrap_array<const N: usize>(from: [Self; N]) -> [T; N] {
+        let () = SameLayout::<[T; N], [Self; N]>::ASSERT;
+        let from = ManuallyDrop::new(from);
+        // SAFETY: See [Cami::wrap_array].
+        unsafe { ptr::read(&*from as *const [Self; N] as *const [T; N]) }
+    }
+
+    /// Zero cost.
+    #[must_use]
+    #[inline]
+    pub fn wrap_array_ref<const N: usize>(from: &[T; N]) -> &[Self; N] {
+        let () = SameLayout::<[T; N], [Self; N]>::ASSERT;
+        // SAFETY: See [Cami::wrap_array]. The lifetime is preserved.
+        unsafe { &*(from as *const [T; N] as *const [Self; N]) }
+    }
+
+    /// Zero cost. The reverse of [Cami::wrap_array_ref].
+    #[must_use]
+    #[inline]
+    pub fn unwrap_array_ref<const N: usize>(from: &[Self; N]) -> &[T; N] {
+        let () = SameLayout::<[T; N], [Self; N]>::ASSERT;
+        // SAFETY: See [Cami::wrap_array_ref].
+        unsafe { &*(from as *const [Self; N] as *const [T; N]) }
+    }
+
+    /// Zero cost (no reallocation).
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    #[inline]
+    pub fn wrap_boxed_slice(from: Box<[T]>) -> Box<[Self]> {
+        let () = SameLayout::<T, Self>::ASSERT;
+        // SAFETY: The allocation holds items with the same layout, so it's deallocated with the
+        // same layout. The slice pointer cast keeps the length.
+        unsafe { Box::from_raw(Box::into_raw(from) as *mut [Self]) }
+    }
+
+    /// Zero cost (no reallocation). The reverse of [Cami::wrap_boxed_slice].
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    #[inline]
+    pub fn unwrap_boxed_slice(from: Box<[Self]>) -> Box<[T]> {
+        let () = SameLayout::<T, Self>::ASSERT;
+        // SAFETY: See [Cami::wrap_boxed_slice].
+        unsafe { Box::from_raw(Box::into_raw(from) as *mut [T]) }
+    }
+
+    /// Zero cost (no reallocation, no change of reference counts).
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    #[inline]
+    pub fn wrap_rc_slice(from: Rc<[T]>) -> Rc<[Self]> {
+        let () = SameLayout::<T, Self>::ASSERT;
+        // SAFETY: As per [Rc::from_raw], the pointer comes from [Rc::into_raw], and its pointee has
+        // the same layout. The slice pointer cast keeps the length.
+        unsafe { Rc::from_raw(Rc::into_raw(from) as *const [Self]) }
+    }
+
+    /// Zero cost (no reallocation, no change of reference counts). The reverse of
+    /// [Cami::wrap_rc_slice].
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    #[inline]
+    pub fn unwrap_rc_slice(from: Rc<[Self]>) -> Rc<[T]> {
+        let () = SameLayout::<T, Self>::ASSERT;
+        // SAFETY: See [Cami::wrap_rc_slice].
+        unsafe { Rc::from_raw(Rc::into_raw(from) as *const [T]) }
+    }
+
+    /// Zero cost (no reallocation, no change of reference counts).
+    #[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+    #[must_use]
+    #[inline]
+    pub fn wrap_arc_slice(from: Arc<[T]>) -> Arc<[Self]> {
+        let () = SameLayout::<T, Self>::ASSERT;
+        // SAFETY: As per [Arc::from_raw], the pointer comes from [Arc::into_raw], and its pointee
+        // has the same layout. The slice pointer cast keeps the length.
+        unsafe { Arc::from_raw(Arc::into_raw(from) as *const [Self]) }
+    }
+
+    /// Zero cost (no reallocation, no change of reference counts). The reverse of
+    /// [Cami::wrap_arc_slice].
+    #[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+    #[must_use]
+    #[inline]
+    pub fn unwrap_arc_slice(from: Arc<[Self]>) -> Arc<[T]> {
+        let () = SameLayout::<T, Self>::ASSERT;
+        // SAFETY: See [Cami::wrap_arc_slice].
+        unsafe { Arc::from_raw(Arc::into_raw(from) as *const [T]) }
+    }
+}
+
+impl<T: CamiPartialEq + ?Sized> Cami<T> {
+    /// Zero cost. No `unsafe` needed here - it's based on [Cami::wrap_ref].
+    #[must_use]
+    #[inline]
+    pub fn wrap_option_ref(from: Option<&T>) -> Option<&Self> {
+        from.map(Self::wrap_ref)
+    }
+
+    /// Zero cost. The reverse of [Cami::wrap_option_ref].
+    #[must_use]
+    #[inline]
+    pub fn unwrap_option_ref(from: Option<&Self>) -> Option<&T> {
+        from.map(Self::in_cami)
+    }
+}
+
+impl F32Cami {
+    /// Zero cost. The same as [crate::IntoRefCami::into_ref_cami] for [f32].
+    #[must_use]
+    #[inline]
+    pub fn wrap_f32_ref(from: &f32) -> &Self {
+        // SAFETY: `Cami<F32Total>` is `#[repr(transparent)]` over `F32Total`, which is
+        // `#[repr(transparent)]` over `f32`.
+        unsafe { &*(from as *const f32 as *const Self) }
+    }
+
+    /// Zero cost. The same as [crate::IntoRefCami::into_mut_cami] for [f32].
+    #[must_use]
+    #[inline]
+    pub fn wrap_f32_mut(from: &mut f32) -> &mut Self {
+        // SAFETY: See [F32Cami::wrap_f32_ref].
+        unsafe { &mut *(from as *mut f32 as *mut Self) }
+    }
+
+    /// Zero cost. The same as [crate::IntoSliceCami::into_slice_cami] for `[f32]`.
+    #[must_use]
+    #[inline]
+    pub fn wrap_f32_slice(from: &[f32]) -> &[Self] {
+        // SAFETY: See [F32Cami::wrap_f32_ref].
+        unsafe { slice::from_raw_parts(from.as_ptr() as *const Self, from.len()) }
+    }
+
+    /// Zero cost. The same as [crate::IntoSliceCami::into_slice_mut_cami] for `[f32]`.
+    #[must_use]
+    #[inline]
+    pub fn wrap_f32_slice_mut(from: &mut [f32]) -> &mut [Self] {
+        // SAFETY: See [F32Cami::wrap_f32_ref].
+        unsafe { slice::from_raw_parts_mut(from.as_mut_ptr() as *mut Self, from.len()) }
+    }
+
+    /// Zero cost. The reverse of [F32Cami::wrap_f32_slice].
+    #[must_use]
+    #[inline]
+    pub fn unwrap_f32_slice(from: &[Self]) -> &[f32] {
+        // SAFETY: See [F32Cami::wrap_f32_ref].
+        unsafe { slice::from_raw_parts(from.as_ptr() as *const f32, from.len()) }
+    }
+}
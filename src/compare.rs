@@ -0,0 +1,87 @@
+//! Free comparison functions in [crate::Cami] order, for items that are NOT wrapped in [crate::Cami] - for
+//! example, fields of other structs. They can be passed to [slice::sort_by],
+//! [slice::binary_search_by], [Iterator::max_by]...
+use crate::cami::{cmp_cami, eq_cami, lt_cami, partial_cmp_cami};
+use crate::{CamiOrd, CamiPartialEq, CamiPartialOrd};
+use core::cmp::Ordering;
+
+/// Full comparison, the same as `Ord for Cami<T>`: by the local parts first, and by the non-local
+/// parts only if the local parts are equal. For example `names.sort_by(cami::cmp)`.
+#[must_use]
+#[inline]
+pub fn cmp<T: CamiOrd + ?Sized>(this: &T, other: &T) -> Ordering {
+    cmp_cami(this, other)
+}
+
+/// Partial comparison, the same as `PartialOrd for Cami<T>`. `other` may be of a different type.
+#[must_use]
+#[inline]
+pub fn partial_cmp<T, Rhs>(this: &T, other: &Rhs) -> Option<Ordering>
+where
+    T: CamiPartialOrd<Rhs> + CamiPartialEq<Rhs> + ?Sized,
+    Rhs: ?Sized,
+{
+    partial_cmp_cami(this, other)
+}
+
+/// Equality, the same as `PartialEq for Cami<T>`. `other` may be of a different type.
+#[must_use]
+#[inline]
+pub fn eq<T, Rhs>(this: &T, other: &Rhs) -> bool
+where
+    T: CamiPartialEq<Rhs> + ?Sized,
+    Rhs: ?Sized,
+{
+    eq_cami(this, other)
+}
+
+/// Less than, the same as `PartialOrd::lt for Cami<T>`. `other` may be of a different type.
+#[must_use]
+#[inline]
+pub fn lt<T, Rhs>(this: &T, other: &Rhs) -> bool
+where
+    T: CamiPartialOrd<Rhs> + CamiPartialEq<Rhs> + ?Sized,
+    Rhs: ?Sized,
+{
+    lt_cami(this, other)
+}
+
+/// Comparator of records by a key (projected by reference), in [crate::Cami] order of the key.
+///
+/// The type of the records has to be given, either on the closure parameter, or by turbofish:
+/// `by_key` is type checked (with its closure) before `sort_by` could fix `R`. So the bare
+/// `records.sort_by(cami::by_key(|record| record.name.as_str()))` fails with "type annotations
+/// needed".
+/// ```
+/// struct Record {
+///     name: &'static str,
+/// }
+/// let mut records = [Record { name: "ab" }, Record { name: "b" }];
+///
+/// records.sort_by(cami::by_key(|record: &Record| record.name));
+/// records.sort_by(cami::by_key::<Record, _, _>(|record| record.name));
+/// // Shorter first.
+/// assert_eq!(records[0].name, "b");
+/// ```
+/// ```compile_fail,E0282
+/// # struct Record {
+/// #     name: &'static str,
+/// # }
+/// # let mut records = [Record { name: "ab" }, Record { name: "b" }];
+/// records.sort_by(cami::by_key(|record| record.name));
+/// ```
+/// To sort a slice with no annotation, use [crate::core::ByCamiKey::sort_by_cami_key_uncached]
+/// (or, for keys with [crate::CamiLocalKey], [crate::core::ByCamiKey::sort_by_cami_key]), which
+/// fix `R` to the slice's item type.
+///
+/// The key is returned by reference - so that a `&String` key is compared as a `String` (in
+/// [crate::Cami] order), rather than by `impl CamiOrd for &T` (which is by [Ord] of `T`).
+#[inline]
+pub fn by_key<R, K, F>(key: F) -> impl Fn(&R, &R) -> Ordering
+where
+    R: ?Sized,
+    K: CamiOrd + ?Sized,
+    F: Fn(&R) -> &K,
+{
+    move |this, other| cmp_cami(key(this), key(other))
+}
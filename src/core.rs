@@ -1,5 +1,25 @@
+pub use by_key::*;
+pub use group::*;
+pub use iter::*;
+pub use lex::*;
+pub use merge::*;
 pub use primitives::*;
+pub use radix::*;
+pub use search::*;
+pub use select::*;
+pub use set_ops::*;
 pub use slice::*;
+pub use string_sort::*;
 
+mod by_key;
+mod group;
+mod iter;
+mod lex;
+mod merge;
 mod primitives;
+mod radix;
+mod search;
+mod select;
+mod set_ops;
 mod slice;
+mod string_sort;
@@ -0,0 +1,155 @@
+#[cfg(feature = "alloc")]
+use crate::alloc::argsort::permute_in_place;
+use crate::cami::{cmp_cami, partial_cmp_cami};
+#[cfg(feature = "alloc")]
+use crate::CamiLocalKey;
+use crate::{CamiOrd, CamiPartialEq, CamiPartialOrd};
+use core::cmp::Ordering;
+#[cfg(feature = "alloc")]
+use rust_alloc::vec::Vec;
+
+/// Sort and search slices of records (of any type `R`) by a key, in [crate::Cami] order of the
+/// key - with no need to wrap the records (or the keys) in [crate::Cami].
+///
+/// The key is projected by reference (`Fn(&R) -> &K`), for example `|record| record.name.as_str()`
+/// or `|record| &record.name`. So a `String` key is compared as a `String` (in [crate::Cami]
+/// order), rather than by `impl CamiOrd for &T` (which is by [Ord] of `T`). See also
+/// [crate::by_key].
+pub trait ByCamiKey<R> {
+    /// Like [slice::sort_by_key], but in [crate::Cami] order of the key. Stable.
+    ///
+    /// Decorate-sort-undecorate: it collects the local key ([CamiLocalKey]) of each record once,
+    /// into a dense array (with record indices). It sorts that array - so the local comparisons
+    /// don't access the records at all. Only for equal local keys it projects the keys again and
+    /// compares their non-local parts. Finally, it permutes the records in place. It allocates
+    /// `O(n)` for the array.
+    ///
+    /// So `K` has to implement [CamiLocalKey] (as `str`, `[T]`, `String`, `Vec<T>` and primitives
+    /// do). For other keys, use [ByCamiKey::sort_by_cami_key_uncached], which sorts in the same
+    /// order with no decoration.
+    #[cfg(feature = "alloc")]
+    fn sort_by_cami_key<K, F>(&mut self, key: F)
+    where
+        K: CamiOrd + CamiLocalKey + ?Sized,
+        F: Fn(&R) -> &K;
+
+    /// Like [ByCamiKey::sort_by_cami_key], but unstable.
+    #[cfg(feature = "alloc")]
+    fn sort_unstable_by_cami_key<K, F>(&mut self, key: F)
+    where
+        K: CamiOrd + CamiLocalKey + ?Sized,
+        F: Fn(&R) -> &K;
+
+    /// Like [ByCamiKey::sort_by_cami_key], but with no decoration: it projects both keys on every
+    /// comparison, the same as `slice::sort_by(crate::by_key(key))`. So `K` doesn't need
+    /// [CamiLocalKey]. Unlike with [crate::by_key], the type of the closure parameter is known
+    /// (it's `&R`), so it doesn't need to be given. Stable.
+    #[cfg(feature = "alloc")]
+    fn sort_by_cami_key_uncached<K, F>(&mut self, key: F)
+    where
+        K: CamiOrd + ?Sized,
+        F: Fn(&R) -> &K;
+
+    /// Like [ByCamiKey::sort_by_cami_key_uncached], but unstable. With no allocation.
+    fn sort_unstable_by_cami_key_uncached<K, F>(&mut self, key: F)
+    where
+        K: CamiOrd + ?Sized,
+        F: Fn(&R) -> &K;
+
+    /// Like [slice::binary_search_by_key], but in [crate::Cami] order of the key. The slice has to
+    /// be sorted by the key (for example, with [ByCamiKey::sort_by_cami_key]). The needle may be of
+    /// a different type (`Q`), as with [crate::core::SearchCami]. As there, if a key and the needle
+    /// are incomparable, the key is treated as being less than the needle.
+    fn binary_search_by_cami_key<'a, K, Q, F>(&'a self, needle: &Q, key: F) -> Result<usize, usize>
+    where
+        K: CamiPartialOrd<Q> + CamiPartialEq<Q> + ?Sized + 'a,
+        Q: ?Sized,
+        F: FnMut(&'a R) -> &'a K,
+        R: 'a;
+}
+
+#[cfg(feature = "alloc")]
+fn sort_indices_by_cami_key<R, K, F>(records: &[R], key: F, stable: bool) -> Vec<usize>
+where
+    K: CamiOrd + CamiLocalKey + ?Sized,
+    F: Fn(&R) -> &K,
+{
+    let mut decorated: Vec<(K::LocalKey, usize)> = records
+        .iter()
+        .enumerate()
+        .map(|(index, record)| (key(record).local_key(), index))
+        .collect();
+    let compare = |this: &(K::LocalKey, usize), other: &(K::LocalKey, usize)| {
+        this.0.cmp(&other.0).then_with(|| {
+            if K::LOCALITY.has_non_local() {
+                key(&records[this.1]).cmp_non_local(key(&records[other.1]))
+            } else {
+                Ordering::Equal
+            }
+        })
+    };
+    if stable {
+        decorated.sort_by(compare);
+    } else {
+        decorated.sort_unstable_by(compare);
+    }
+    decorated.into_iter().map(|(_, index)| index).collect()
+}
+
+impl<R> ByCamiKey<R> for [R] {
+    #[cfg(feature = "alloc")]
+    fn sort_by_cami_key<K, F>(&mut self, key: F)
+    where
+        K: CamiOrd + CamiLocalKey + ?Sized,
+        F: Fn(&R) -> &K,
+    {
+        let mut permutation = sort_indices_by_cami_key(self, key, true);
+        permute_in_place(self, &mut permutation);
+    }
+
+    #[cfg(feature = "alloc")]
+    fn sort_unstable_by_cami_key<K, F>(&mut self, key: F)
+    where
+        K: CamiOrd + CamiLocalKey + ?Sized,
+        F: Fn(&R) -> &K,
+    {
+        let mut permutation = sort_indices_by_cami_key(self, key, false);
+        permute_in_place(self, &mut permutation);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn sort_by_cami_key_uncached<K, F>(&mut self, key: F)
+    where
+        K: CamiOrd + ?Sized,
+        F: Fn(&R) -> &K,
+    {
+        self.sort_by(|this, other| cmp_cami(key(this), key(other)));
+    }
+
+    #[inline]
+    fn sort_unstable_by_cami_key_uncached<K, F>(&mut self, key: F)
+    where
+        K: CamiOrd + ?Sized,
+        F: Fn(&R) -> &K,
+    {
+        self.sort_unstable_by(|this, other| cmp_cami(key(this), key(other)));
+    }
+
+    #[inline]
+    fn binary_search_by_cami_key<'a, K, Q, F>(
+        &'a self,
+        needle: &Q,
+        mut key: F,
+    ) -> Result<usize, usize>
+    where
+        K: CamiPartialOrd<Q> + CamiPartialEq<Q> + ?Sized + 'a,
+        Q: ?Sized,
+        F: FnMut(&'a R) -> &'a K,
+        R: 'a,
+    {
+        self.binary_search_by(|record| {
+            partial_cmp_cami(key(record), needle).unwrap_or(Ordering::Less)
+        })
+    }
+}
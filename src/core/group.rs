@@ -0,0 +1,132 @@
+use crate::cami::eq_cami;
+use crate::{Cami, CamiPartialEq};
+use core::iter::FusedIterator;
+
+/// Runs (subslices) of consecutive items of a slice of [Cami] - typically sorted in [Cami] order,
+/// so that the runs are the groups of all equal items. For example, for `[Cami<String>]` sorted in
+/// [Cami] order, [GroupCami::group_by_local] yields all strings of the same length at once.
+///
+/// Call [GroupCami::group_by_cami] on a run from [GroupCami::group_by_local] to split it further.
+pub trait GroupCami<T: CamiPartialEq> {
+    /// Runs of items with equal local parts ([CamiPartialEq::eq_local]). It doesn't access
+    /// non-local parts at all. If `T` has no local part ([CamiPartialEq::LOCALITY]), the whole
+    /// slice is one run.
+    #[must_use]
+    fn group_by_local(&self) -> GroupByLocal<'_, T>;
+
+    /// Runs of fully equal items (in [Cami] equality): local parts first, and then the non-local
+    /// parts only for neighbours whose local parts are equal.
+    #[must_use]
+    fn group_by_cami(&self) -> GroupByCami<'_, T>;
+}
+
+impl<T: CamiPartialEq> GroupCami<T> for [Cami<T>] {
+    #[inline]
+    fn group_by_local(&self) -> GroupByLocal<'_, T> {
+        GroupByLocal { slice: self }
+    }
+
+    #[inline]
+    fn group_by_cami(&self) -> GroupByCami<'_, T> {
+        GroupByCami { slice: self }
+    }
+}
+
+#[inline]
+fn eq_local<T: CamiPartialEq>(this: &Cami<T>, other: &Cami<T>) -> bool {
+    !T::LOCALITY.has_local() || this.in_cami().eq_local(other.in_cami())
+}
+
+#[inline]
+fn eq_full<T: CamiPartialEq>(this: &Cami<T>, other: &Cami<T>) -> bool {
+    eq_cami(this.in_cami(), other.in_cami())
+}
+
+/// Split off the leading run of `slice` (if any), where `same` holds for all neighbours.
+#[inline]
+fn split_run_front<'a, T>(slice: &mut &'a [T], same: impl Fn(&T, &T) -> bool) -> Option<&'a [T]> {
+    if slice.is_empty() {
+        return None;
+    }
+    let len = 1 + slice
+        .windows(2)
+        .take_while(|pair| same(&pair[0], &pair[1]))
+        .count();
+    let (run, rest) = slice.split_at(len);
+    *slice = rest;
+    Some(run)
+}
+
+/// Split off the trailing run of `slice` (if any), where `same` holds for all neighbours.
+#[inline]
+fn split_run_back<'a, T>(slice: &mut &'a [T], same: impl Fn(&T, &T) -> bool) -> Option<&'a [T]> {
+    if slice.is_empty() {
+        return None;
+    }
+    let len = 1 + slice
+        .windows(2)
+        .rev()
+        .take_while(|pair| same(&pair[0], &pair[1]))
+        .count();
+    let (rest, run) = slice.split_at(slice.len() - len);
+    *slice = rest;
+    Some(run)
+}
+
+/// Iterator returned by [GroupCami::group_by_local].
+#[derive(Clone)]
+pub struct GroupByLocal<'a, T: CamiPartialEq> {
+    slice: &'a [Cami<T>],
+}
+
+impl<'a, T: CamiPartialEq> Iterator for GroupByLocal<'a, T> {
+    type Item = &'a [Cami<T>];
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a [Cami<T>]> {
+        split_run_front(&mut self.slice, eq_local)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::from(!self.slice.is_empty()), Some(self.slice.len()))
+    }
+}
+
+impl<'a, T: CamiPartialEq> DoubleEndedIterator for GroupByLocal<'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a [Cami<T>]> {
+        split_run_back(&mut self.slice, eq_local)
+    }
+}
+
+impl<'a, T: CamiPartialEq> FusedIterator for GroupByLocal<'a, T> {}
+
+/// Iterator returned by [GroupCami::group_by_cami].
+#[derive(Clone)]
+pub struct GroupByCami<'a, T: CamiPartialEq> {
+    slice: &'a [Cami<T>],
+}
+
+impl<'a, T: CamiPartialEq> Iterator for GroupByCami<'a, T> {
+    type Item = &'a [Cami<T>];
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a [Cami<T>]> {
+        split_run_front(&mut self.slice, eq_full)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::from(!self.slice.is_empty()), Some(self.slice.len()))
+    }
+}
+
+impl<'a, T: CamiPartialEq> DoubleEndedIterator for GroupByCami<'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a [Cami<T>]> {
+        split_run_back(&mut self.slice, eq_full)
+    }
+}
+
+impl<'a, T: CamiPartialEq> FusedIterator for GroupByCami<'a, T> {}
@@ -0,0 +1,163 @@
+//! Extension of [Iterator] with minimum, maximum, sortedness and search in [crate::Cami] order -
+//! for items that are NOT wrapped in [crate::Cami]. See [CamiIteratorExt].
+use super::merge::lt;
+use crate::cami::eq_cami;
+use crate::{CamiOrd, CamiPartialEq};
+#[cfg(feature = "alloc")]
+use rust_alloc::vec::Vec;
+
+/// Result of [CamiIteratorExt::minmax_cami].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MinMaxCami<T> {
+    NoElements,
+    OneElement(T),
+    /// The minimum and the maximum.
+    MinMax(T, T),
+}
+
+impl<T: Clone> MinMaxCami<T> {
+    /// The minimum and the maximum (a clone of the only item, if one item).
+    #[must_use]
+    #[inline]
+    pub fn into_option(self) -> Option<(T, T)> {
+        match self {
+            Self::NoElements => None,
+            Self::OneElement(item) => Some((item.clone(), item)),
+            Self::MinMax(min, max) => Some((min, max)),
+        }
+    }
+}
+
+/// Extension of [Iterator], comparing in [crate::Cami] order (local parts first, and non-local
+/// parts only if the local parts are equal) - with items that are NOT wrapped in [crate::Cami].
+///
+/// Items have to implement [CamiOrd] (or [CamiPartialEq]). Beware that references to sized types
+/// (like `&String`) implement them by the [Ord] of the referenced type - which is NOT [crate::Cami]
+/// order. For those, use a key, for example `names.iter().min_by_cami_key(|name| name.as_str())`.
+/// (References to [crate::Cami], `&str` and `&[T]` are in [crate::Cami] order.)
+pub trait CamiIteratorExt: Iterator {
+    /// Like [Iterator::min]. If several items are equally minimum, it returns the first one.
+    #[must_use]
+    fn min_cami(self) -> Option<Self::Item>
+    where
+        Self: Sized,
+        Self::Item: CamiOrd,
+    {
+        self.reduce(|min, item| if lt(&item, &min) { item } else { min })
+    }
+
+    /// Like [Iterator::max]. If several items are equally maximum, it returns the last one.
+    #[must_use]
+    fn max_cami(self) -> Option<Self::Item>
+    where
+        Self: Sized,
+        Self::Item: CamiOrd,
+    {
+        self.reduce(|max, item| if lt(&item, &max) { max } else { item })
+    }
+
+    /// Both [CamiIteratorExt::min_cami] and [CamiIteratorExt::max_cami], in one pass.
+    #[must_use]
+    fn minmax_cami(mut self) -> MinMaxCami<Self::Item>
+    where
+        Self: Sized,
+        Self::Item: CamiOrd,
+    {
+        let first = match self.next() {
+            Some(first) => first,
+            None => return MinMaxCami::NoElements,
+        };
+        let second = match self.next() {
+            Some(second) => second,
+            None => return MinMaxCami::OneElement(first),
+        };
+        let (mut min, mut max) = if lt(&second, &first) {
+            (second, first)
+        } else {
+            (first, second)
+        };
+        for item in self {
+            if lt(&item, &min) {
+                min = item;
+            } else if !lt(&item, &max) {
+                max = item;
+            }
+        }
+        MinMaxCami::MinMax(min, max)
+    }
+
+    /// Whether the items are in (non-descending) [crate::Cami] order. Like [Iterator::position],
+    /// it stops at the first item that is out of order, and it leaves the rest of the iterator.
+    #[must_use]
+    fn is_sorted_cami(&mut self) -> bool
+    where
+        Self: Sized,
+        Self::Item: CamiOrd,
+    {
+        let mut previous = match self.next() {
+            Some(first) => first,
+            None => return true,
+        };
+        for item in self {
+            if lt(&item, &previous) {
+                return false;
+            }
+            previous = item;
+        }
+        true
+    }
+
+    /// Collect, and sort (stable) in [crate::Cami] order.
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    fn sorted_cami(self) -> Vec<Self::Item>
+    where
+        Self: Sized,
+        Self::Item: CamiOrd,
+    {
+        let mut items: Vec<Self::Item> = self.collect();
+        items.sort_by(crate::cami::cmp_cami);
+        items
+    }
+
+    /// Like [Iterator::min_by_key], with the key in [crate::Cami] order. It calls `key` once per
+    /// item.
+    #[must_use]
+    fn min_by_cami_key<K, F>(self, mut key: F) -> Option<Self::Item>
+    where
+        Self: Sized,
+        K: CamiOrd,
+        F: FnMut(&Self::Item) -> K,
+    {
+        self.map(|item| (key(&item), item))
+            .reduce(|min, next| if lt(&next.0, &min.0) { next } else { min })
+            .map(|(_, item)| item)
+    }
+
+    /// Like [Iterator::max_by_key], with the key in [crate::Cami] order. It calls `key` once per
+    /// item.
+    #[must_use]
+    fn max_by_cami_key<K, F>(self, mut key: F) -> Option<Self::Item>
+    where
+        Self: Sized,
+        K: CamiOrd,
+        F: FnMut(&Self::Item) -> K,
+    {
+        self.map(|item| (key(&item), item))
+            .reduce(|max, next| if lt(&next.0, &max.0) { max } else { next })
+            .map(|(_, item)| item)
+    }
+
+    /// Like [Iterator::position], for the first item equal to `needle` (in [crate::Cami]
+    /// equality). The needle may be of a different type (`Q`), as with
+    /// [crate::core::SearchCami].
+    fn position_cami<Q: ?Sized>(&mut self, needle: &Q) -> Option<usize>
+    where
+        Self: Sized,
+        Self::Item: CamiPartialEq<Q>,
+    {
+        self.position(|item| eq_cami(&item, needle))
+    }
+}
+
+impl<I: Iterator> CamiIteratorExt for I {}
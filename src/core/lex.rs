@@ -0,0 +1,176 @@
+use crate::{Cami, CamiLocalKey, CamiOrd, CamiPartialEq, CamiPartialOrd, Locality};
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+
+/// Number of leading bytes cached in [Lex].
+const PREFIX_LEN: usize = 8;
+
+/// First [PREFIX_LEN] bytes, packed big-endian (and padded with zeros), so that comparing the
+/// results as integers compares the bytes lexicographically.
+#[inline]
+fn prefix_of(bytes: &[u8]) -> u64 {
+    let mut buffer = [0u8; PREFIX_LEN];
+    let len = bytes.len().min(PREFIX_LEN);
+    buffer[..len].copy_from_slice(&bytes[..len]);
+    u64::from_be_bytes(buffer)
+}
+
+/// Byte string (like `&str`, `String`, `&[u8]` or `Vec<u8>`) ordered lexicographically - the same
+/// as their std [Ord] - rather than length-first (like `Cami<&str>`, `Cami<String>`...).
+///
+/// It caches the first 8 bytes (packed big-endian into a [u64]) inline, at construction. The local
+/// part is that prefix, plus the length capped at 8 as a tiebreak (for shorter values, including
+/// ones with trailing zero bytes). Only if both of those are equal, the non-local part compares the
+/// remaining bytes. So most comparisons don't dereference the bytes.
+///
+/// It doesn't give mutable access to the value, because that could invalidate the cached prefix.
+#[derive(Clone, Debug)]
+pub struct Lex<T: AsRef<[u8]>> {
+    prefix: u64,
+    value: T,
+}
+
+impl<T: AsRef<[u8]>> Lex<T> {
+    #[must_use]
+    #[inline]
+    pub fn new(value: T) -> Self {
+        Self {
+            prefix: prefix_of(value.as_ref()),
+            value,
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn get(&self) -> &T {
+        &self.value
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+
+    #[must_use]
+    #[inline]
+    fn capped_len(&self) -> u8 {
+        self.value.as_ref().len().min(PREFIX_LEN) as u8
+    }
+
+    /// Bytes beyond the cached prefix.
+    #[must_use]
+    #[inline]
+    fn rest(&self) -> &[u8] {
+        let bytes = self.value.as_ref();
+        &bytes[bytes.len().min(PREFIX_LEN)..]
+    }
+}
+
+impl<T: AsRef<[u8]>> PartialEq for Lex<T> {
+    #[must_use]
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.prefix == other.prefix && self.value.as_ref() == other.value.as_ref()
+    }
+}
+impl<T: AsRef<[u8]>> Eq for Lex<T> {}
+
+impl<T: AsRef<[u8]>> PartialOrd for Lex<T> {
+    #[must_use]
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// The same as std [Ord] for the bytes.
+impl<T: AsRef<[u8]>> Ord for Lex<T> {
+    #[must_use]
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value.as_ref().cmp(other.value.as_ref())
+    }
+}
+
+/// Hashes the bytes only (the cached prefix is derived from them).
+impl<T: AsRef<[u8]>> Hash for Lex<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.as_ref().hash(state);
+    }
+}
+
+impl<T: AsRef<[u8]>> CamiPartialEq for Lex<T> {
+    const LOCALITY: Locality = Locality::Both;
+
+    #[must_use]
+    #[inline]
+    fn eq_local(&self, other: &Self) -> bool {
+        self.prefix == other.prefix && self.capped_len() == other.capped_len()
+    }
+
+    #[must_use]
+    #[inline]
+    fn eq_non_local(&self, other: &Self) -> bool {
+        self.rest() == other.rest()
+    }
+}
+
+impl<T: AsRef<[u8]>> CamiPartialOrd for Lex<T> {
+    #[must_use]
+    #[inline]
+    fn partial_cmp_local(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp_local(other))
+    }
+
+    #[must_use]
+    #[inline]
+    fn partial_cmp_non_local(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp_non_local(other))
+    }
+}
+
+impl<T: AsRef<[u8]>> CamiOrd for Lex<T> {
+    #[must_use]
+    #[inline]
+    fn cmp_local(&self, other: &Self) -> Ordering {
+        self.local_key().cmp(&other.local_key())
+    }
+
+    /// Called only if [CamiOrd::cmp_local] returned [Ordering::Equal] - then both have the same
+    /// first 8 bytes, and both are either of the same length (of at most 8 bytes), or longer than 8.
+    #[must_use]
+    #[inline]
+    fn cmp_non_local(&self, other: &Self) -> Ordering {
+        self.rest().cmp(other.rest())
+    }
+}
+
+impl<T: AsRef<[u8]>> CamiLocalKey for Lex<T> {
+    /// The cached prefix, and the length capped at 8.
+    type LocalKey = (u64, u8);
+
+    #[must_use]
+    #[inline]
+    fn local_key(&self) -> (u64, u8) {
+        (self.prefix, self.capped_len())
+    }
+}
+
+/// [Cami] of [Lex]: ordered the same as std [Ord] of `T`, for example `LexCami<&str>`,
+/// `LexCami<String>`, `LexCami<&[u8]>` or `LexCami<Vec<u8>>`.
+pub type LexCami<T> = Cami<Lex<T>>;
+
+impl<T: AsRef<[u8]>> LexCami<T> {
+    #[must_use]
+    #[inline]
+    pub fn new_lex(value: T) -> Self {
+        Cami::new(Lex::new(value))
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn into_lex_inner(self) -> T {
+        self.from_cami().into_inner()
+    }
+}
@@ -0,0 +1,307 @@
+//! Merging of runs (iterators or slices) sorted in [crate::Cami] order.
+//!
+//! Items are any [CamiOrd] - for example `Cami<String>`, or `&Cami<String>` (which compares by
+//! `Ord for Cami`), or `String` itself (with no need to wrap it). Every comparison is local first,
+//! and it compares the non-local parts only if the local parts are equal.
+use crate::cami::cmp_cami;
+use crate::CamiOrd;
+use core::cmp::Ordering;
+use core::fmt;
+use core::iter::{FusedIterator, Peekable};
+
+#[inline]
+pub(super) fn lt<T: CamiOrd + ?Sized>(this: &T, other: &T) -> bool {
+    cmp_cami(this, other) == Ordering::Less
+}
+
+/// Merge two iterators, each sorted in [crate::Cami] order. Stable: on full equality it yields the
+/// item from `left` first.
+#[must_use]
+#[inline]
+pub fn merge_cami<I, J>(left: I, right: J) -> MergeCami<I::IntoIter, J::IntoIter>
+where
+    I: IntoIterator,
+    J: IntoIterator<Item = I::Item>,
+    I::Item: CamiOrd,
+{
+    MergeCami {
+        left: left.into_iter().peekable(),
+        right: right.into_iter().peekable(),
+    }
+}
+
+/// Iterator returned by [merge_cami].
+pub struct MergeCami<I: Iterator, J: Iterator<Item = I::Item>> {
+    left: Peekable<I>,
+    right: Peekable<J>,
+}
+
+impl<I, J> Clone for MergeCami<I, J>
+where
+    I: Iterator + Clone,
+    J: Iterator<Item = I::Item> + Clone,
+    I::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            left: self.left.clone(),
+            right: self.right.clone(),
+        }
+    }
+}
+
+impl<I, J> fmt::Debug for MergeCami<I, J>
+where
+    I: Iterator + fmt::Debug,
+    J: Iterator<Item = I::Item> + fmt::Debug,
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MergeCami")
+            .field("left", &self.left)
+            .field("right", &self.right)
+            .finish()
+    }
+}
+
+impl<I, J> Iterator for MergeCami<I, J>
+where
+    I: Iterator,
+    J: Iterator<Item = I::Item>,
+    I::Item: CamiOrd,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<I::Item> {
+        match (self.left.peek(), self.right.peek()) {
+            (Some(left), Some(right)) => {
+                if lt(right, left) {
+                    self.right.next()
+                } else {
+                    self.left.next()
+                }
+            }
+            (Some(_), None) => self.left.next(),
+            (None, _) => self.right.next(),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (left_lower, left_upper) = self.left.size_hint();
+        let (right_lower, right_upper) = self.right.size_hint();
+        let upper = match (left_upper, right_upper) {
+            (Some(left), Some(right)) => left.checked_add(right),
+            _ => None,
+        };
+        (left_lower.saturating_add(right_lower), upper)
+    }
+}
+
+impl<I, J> FusedIterator for MergeCami<I, J>
+where
+    I: FusedIterator,
+    J: FusedIterator<Item = I::Item>,
+    I::Item: CamiOrd,
+{
+}
+
+/// Merge two slices, each sorted in [crate::Cami] order, yielding references. Stable: on full
+/// equality it yields the item from `left` first.
+///
+/// See [MergeCamiSlices::galloping] for runs of very different lengths.
+#[must_use]
+#[inline]
+pub fn merge_cami_slices<'a, T: CamiOrd>(left: &'a [T], right: &'a [T]) -> MergeCamiSlices<'a, T> {
+    MergeCamiSlices {
+        left,
+        right,
+        from_left: true,
+        run: 0,
+        galloping: false,
+    }
+}
+
+/// Iterator returned by [merge_cami_slices].
+#[derive(Clone, Debug)]
+pub struct MergeCamiSlices<'a, T: CamiOrd> {
+    left: &'a [T],
+    right: &'a [T],
+    /// Which side the current `run` comes from.
+    from_left: bool,
+    /// How many more items to yield (with no comparisons) from the side given by `from_left`.
+    run: usize,
+    galloping: bool,
+}
+
+impl<'a, T: CamiOrd> MergeCamiSlices<'a, T> {
+    /// Whenever the head of one side wins, find (with exponential search) how many of its items
+    /// win in a row, and then yield them with no more comparisons. That needs `O(log(n))`
+    /// comparisons per such run, rather than one per item - good if one side is much longer, or
+    /// if the sides don't interleave much. But it's wasteful if they interleave item by item.
+    #[must_use]
+    #[inline]
+    pub fn galloping(mut self) -> Self {
+        self.galloping = true;
+        self
+    }
+}
+
+/// Number of leading items of `run` for which `pred` returns `true`. `pred` must return `true` for
+/// the first item, and it must be monotonous (`true`, then `false`).
+pub(super) fn gallop<T>(run: &[T], mut pred: impl FnMut(&T) -> bool) -> usize {
+    let mut bound = 1;
+    while bound < run.len() && pred(&run[bound]) {
+        bound *= 2;
+    }
+    let low = bound / 2 + 1;
+    let high = bound.min(run.len());
+    low + run[low..high].partition_point(pred)
+}
+
+impl<'a, T: CamiOrd> Iterator for MergeCamiSlices<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        if self.run == 0 {
+            match (self.left.first(), self.right.first()) {
+                (Some(left), Some(right)) => {
+                    if lt(right, left) {
+                        self.from_left = false;
+                        self.run = if self.galloping {
+                            gallop(self.right, |item| lt(item, left))
+                        } else {
+                            1
+                        };
+                    } else {
+                        self.from_left = true;
+                        self.run = if self.galloping {
+                            gallop(self.left, |item| !lt(right, item))
+                        } else {
+                            1
+                        };
+                    }
+                }
+                (Some(_), None) => {
+                    self.from_left = true;
+                    self.run = self.left.len();
+                }
+                (None, Some(_)) => {
+                    self.from_left = false;
+                    self.run = self.right.len();
+                }
+                (None, None) => return None,
+            }
+        }
+        self.run -= 1;
+        let side = if self.from_left {
+            &mut self.left
+        } else {
+            &mut self.right
+        };
+        let slice: &'a [T] = side;
+        let (first, rest) = slice.split_first()?;
+        *side = rest;
+        Some(first)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.left.len() + self.right.len();
+        (len, Some(len))
+    }
+}
+
+impl<'a, T: CamiOrd> ExactSizeIterator for MergeCamiSlices<'a, T> {}
+impl<'a, T: CamiOrd> FusedIterator for MergeCamiSlices<'a, T> {}
+
+/// Merge (in place) `slice[..mid]` and `slice[mid..]`, each sorted in [crate::Cami] order. Stable,
+/// with no allocation (so it works with `no_std`).
+///
+/// It uses SymMerge (Kim & Kutzner), which locates the split points with binary search - so it
+/// needs few comparisons even for runs of very different lengths. It moves items with rotations,
+/// `O(n log(n))` moves.
+///
+/// # Panics
+///
+/// If `mid > slice.len()`.
+pub fn merge_cami_in_place<T: CamiOrd>(slice: &mut [T], mid: usize) {
+    assert!(mid <= slice.len(), "mid is out of bounds");
+    if mid == 0 || mid == slice.len() || !lt(&slice[mid], &slice[mid - 1]) {
+        return;
+    }
+    sym_merge(slice, 0, mid, slice.len());
+}
+
+fn sym_merge<T: CamiOrd>(slice: &mut [T], a: usize, m: usize, b: usize) {
+    if m - a == 1 {
+        // Insert slice[a] before the first item (of the right run) that is not less.
+        let i = m + slice[m..b].partition_point(|item| lt(item, &slice[a]));
+        slice[a..i].rotate_left(1);
+        return;
+    }
+    if b - m == 1 {
+        // Insert slice[m] after the last item (of the left run) that is not greater.
+        let i = a + slice[a..m].partition_point(|item| !lt(&slice[m], item));
+        slice[i..=m].rotate_right(1);
+        return;
+    }
+    let mid = a + (b - a) / 2;
+    let n = mid + m;
+    let (mut start, mut r) = if m > mid { (n - b, mid) } else { (a, m) };
+    let p = n - 1;
+    while start < r {
+        let c = start + (r - start) / 2;
+        if !lt(&slice[p - c], &slice[c]) {
+            start = c + 1;
+        } else {
+            r = c;
+        }
+    }
+    let end = n - start;
+    if start < m && m < end {
+        slice[start..end].rotate_left(m - start);
+    }
+    if a < start && start < mid {
+        sym_merge(slice, a, start, mid);
+    }
+    if mid < end && end < b {
+        sym_merge(slice, mid, end, b);
+    }
+}
+
+/// Merge (in place) consecutive runs of `slice`, each sorted in [crate::Cami] order. `run_ends`
+/// are the (exclusive) ends of the runs, ascending - so the last one is `slice.len()`. Stable,
+/// with no allocation: it merges neighbouring pairs of runs (with [merge_cami_in_place]), then
+/// pairs of pairs...
+///
+/// # Panics
+///
+/// If `run_ends` is not ascending, or if its last item is not `slice.len()` (unless both `slice`
+/// and `run_ends` are empty).
+pub fn kmerge_cami_in_place<T: CamiOrd>(slice: &mut [T], run_ends: &[usize]) {
+    assert!(
+        run_ends.windows(2).all(|pair| pair[0] <= pair[1]),
+        "run_ends must be ascending"
+    );
+    assert_eq!(
+        run_ends.last().copied().unwrap_or(0),
+        slice.len(),
+        "the last of run_ends must be the slice length"
+    );
+    let runs = run_ends.len();
+    let start_of = |run: usize| if run == 0 { 0 } else { run_ends[run - 1] };
+    let mut width = 1;
+    while width < runs {
+        let mut first = 0;
+        while first + width < runs {
+            let start = start_of(first);
+            let mid = start_of(first + width);
+            let end = start_of((first + 2 * width).min(runs));
+            merge_cami_in_place(&mut slice[start..end], mid - start);
+            first += 2 * width;
+        }
+        width *= 2;
+    }
+}
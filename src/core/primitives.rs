@@ -4,8 +4,6 @@ pub use crate as cami; // for macros
 use crate::prelude::*;
 use cami_helpers::{pure_local_c_ord, pure_local_c_partial_eq};
 use core::cmp::Ordering;
-#[cfg(feature = "transmute")]
-use core::mem;
 
 impl CamiPartialEq for () {
     const LOCALITY: Locality = Locality::PureLocal;
@@ -46,11 +44,28 @@ impl CamiOrd for () {
 }
 //--------
 
+impl CamiLocalKey for () {
+    type LocalKey = ();
+    #[inline]
+    fn local_key(&self) {}
+}
+impl CamiCopy for () {}
+//--------
+
 /// This exists, so that it has consistent [CamiPartialEq], [CamiPartialOrd], [CamiOrd] and
 /// [PartialEq] based on [pub fn total_cmp(&self, other: &Self) ->
 /// Ordering](https://doc.rust-lang.org/nightly/core/primitive.f32.html#method.total_cmp). Those
 /// implementations do NOT always agree with [PartialEq] (and [PartialOrd]) of [f32].
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(
+    feature = "adapt-zerocopy",
+    derive(
+        zerocopy::FromBytes,
+        zerocopy::IntoBytes,
+        zerocopy::KnownLayout,
+        zerocopy::Immutable
+    )
+)]
 #[repr(transparent)]
 pub struct F32Total(f32);
 
@@ -121,6 +136,44 @@ impl CamiOrd for F32Total {
         Ordering::Equal
     }
 }
+impl F32Total {
+    /// Bits of the wrapped [f32], transformed so that their (unsigned integer) order is the same as
+    /// [f32::total_cmp]: Flip all bits of negatives, and only the sign bit of positives.
+    #[must_use]
+    #[inline]
+    pub fn to_total_bits(&self) -> u32 {
+        let bits = self.0.to_bits();
+        if bits & 0x8000_0000 != 0 {
+            !bits
+        } else {
+            bits | 0x8000_0000
+        }
+    }
+
+    /// The inverse of [F32Total::to_total_bits].
+    #[must_use]
+    #[inline]
+    pub fn from_total_bits(bits: u32) -> Self {
+        if bits & 0x8000_0000 != 0 {
+            Self(f32::from_bits(bits & 0x7FFF_FFFF))
+        } else {
+            Self(f32::from_bits(!bits))
+        }
+    }
+}
+
+/// Consistent with [f32::total_cmp].
+impl CamiLocalKey for F32Total {
+    type LocalKey = u32;
+    #[must_use]
+    #[inline]
+    fn local_key(&self) -> u32 {
+        self.to_total_bits()
+    }
+}
+
+/// So that [F32Cami] is [Copy], too.
+impl CamiCopy for F32Total {}
 pub type F32Cami = Cami<F32Total>;
 
 impl F32Cami {
@@ -156,43 +209,86 @@ impl IntoCamiClone for f32 {
     }
 }
 //--------
-#[cfg(feature = "transmute")]
 impl IntoRefCami for f32 {
     type Wrapped = F32Total;
     #[must_use]
     #[inline]
     fn into_ref_cami(&self) -> &F32Cami {
-        unsafe { mem::transmute(self) }
+        F32Cami::wrap_f32_ref(self)
     }
     #[must_use]
     #[inline]
     fn into_mut_cami(&mut self) -> &mut F32Cami {
-        unsafe { mem::transmute(self) }
+        F32Cami::wrap_f32_mut(self)
     }
 }
-#[cfg(feature = "transmute")]
 impl IntoSliceCami for [f32] {
     type Wrapped = F32Total;
     #[must_use]
     #[inline]
     fn into_slice_cami(&self) -> &[F32Cami] {
-        unsafe { mem::transmute(self) }
+        F32Cami::wrap_f32_slice(self)
     }
     #[must_use]
     #[inline]
     fn into_slice_mut_cami(&mut self) -> &mut [F32Cami] {
-        unsafe { mem::transmute(self) }
+        F32Cami::wrap_f32_slice_mut(self)
     }
 }
 //--------
 
 pure_local_c_partial_eq! { bool }
 pure_local_c_ord! { bool }
+impl CamiCopy for bool {}
+impl CamiLocalKey for bool {
+    type LocalKey = bool;
+    #[must_use]
+    #[inline]
+    fn local_key(&self) -> bool {
+        *self
+    }
+}
 pub type BoolCami = Cami<bool>;
 
 pure_local_c_partial_eq! { u8 }
 pure_local_c_ord! { u8 }
+/// So that [U8Cami] is [Copy], too (and, with `adapt-bytemuck` feature, `bytemuck::Pod`).
+impl CamiCopy for u8 {}
+impl CamiLocalKey for u8 {
+    type LocalKey = u8;
+    #[must_use]
+    #[inline]
+    fn local_key(&self) -> u8 {
+        *self
+    }
+}
 pub type U8Cami = Cami<u8>;
+
+/// Pure local integers: the value is its own local key.
+macro_rules! pure_local_integer {
+    ($t:ty, $alias:ident) => {
+        pure_local_c_partial_eq! { $t }
+        pure_local_c_ord! { $t }
+        impl CamiCopy for $t {}
+        impl CamiLocalKey for $t {
+            type LocalKey = $t;
+            #[must_use]
+            #[inline]
+            fn local_key(&self) -> $t {
+                *self
+            }
+        }
+        pub type $alias = Cami<$t>;
+    };
+}
+
+pure_local_integer! { i8, I8Cami }
+pure_local_integer! { u16, U16Cami }
+pure_local_integer! { i16, I16Cami }
+pure_local_integer! { u32, U32Cami }
+pure_local_integer! { i32, I32Cami }
+pure_local_integer! { u64, U64Cami }
+pure_local_integer! { i64, I64Cami }
 // TODO other types
 
 //--------
@@ -0,0 +1,205 @@
+//! Non-comparison sorts of slices of pure local ([crate::Locality::PureLocal]) [Cami] - whose
+//! values are fully described by (unsigned integer) radix keys, in [Cami] order.
+//!
+//! [RadixSortCami::sort_cami] uses a counting sort (`O(n)`, with no allocation) for types with
+//! small domains (`()`, [bool], [u8] and [i8]). [RadixSortCami::radix_sort_cami] uses an LSD radix
+//! sort (one pass per byte of the key) for wider integers and [crate::core::F32Total], with a
+//! caller-provided buffer, so it works with `no_std`.
+//!
+//! As the radix key is a bijection, equal keys mean equal (indistinguishable) values. So stability
+//! doesn't matter, and the counting sort rebuilds the values from the keys.
+use super::primitives::F32Total;
+use crate::{Cami, CamiCopy, CamiOrd};
+
+/// A pure local type whose values map one-to-one to unsigned integer keys (of up to
+/// [CamiRadixKey::RADIX_BYTES] bytes), in the same order as [CamiOrd::cmp_local]. For signed
+/// integers that's flipping the sign bit, and for [F32Total] it's [F32Total::to_total_bits].
+pub trait CamiRadixKey: CamiOrd + CamiCopy {
+    /// Number of (least significant) bytes of [CamiRadixKey::radix_key] that may be non-zero.
+    const RADIX_BYTES: usize;
+
+    #[must_use]
+    fn radix_key(&self) -> u64;
+
+    /// The inverse of [CamiRadixKey::radix_key]. Called only with keys of existing values.
+    #[must_use]
+    fn from_radix_key(key: u64) -> Self;
+}
+
+impl CamiRadixKey for () {
+    const RADIX_BYTES: usize = 0;
+    #[inline]
+    fn radix_key(&self) -> u64 {
+        0
+    }
+    #[inline]
+    fn from_radix_key(_key: u64) -> Self {}
+}
+
+impl CamiRadixKey for bool {
+    const RADIX_BYTES: usize = 1;
+    #[inline]
+    fn radix_key(&self) -> u64 {
+        *self as u64
+    }
+    #[inline]
+    fn from_radix_key(key: u64) -> Self {
+        key != 0
+    }
+}
+
+impl CamiRadixKey for F32Total {
+    const RADIX_BYTES: usize = 4;
+    #[inline]
+    fn radix_key(&self) -> u64 {
+        self.to_total_bits() as u64
+    }
+    #[inline]
+    fn from_radix_key(key: u64) -> Self {
+        F32Total::from_total_bits(key as u32)
+    }
+}
+
+macro_rules! unsigned_radix_key {
+    ($t:ty) => {
+        impl CamiRadixKey for $t {
+            const RADIX_BYTES: usize = core::mem::size_of::<$t>();
+            #[inline]
+            fn radix_key(&self) -> u64 {
+                *self as u64
+            }
+            #[inline]
+            fn from_radix_key(key: u64) -> Self {
+                key as $t
+            }
+        }
+    };
+}
+
+/// Flip the sign bit, so that negatives come first.
+macro_rules! signed_radix_key {
+    ($t:ty, $unsigned:ty) => {
+        impl CamiRadixKey for $t {
+            const RADIX_BYTES: usize = core::mem::size_of::<$t>();
+            #[inline]
+            fn radix_key(&self) -> u64 {
+                (*self as $unsigned ^ !(<$unsigned>::MAX >> 1)) as u64
+            }
+            #[inline]
+            fn from_radix_key(key: u64) -> Self {
+                (key as $unsigned ^ !(<$unsigned>::MAX >> 1)) as $t
+            }
+        }
+    };
+}
+
+unsigned_radix_key! { u8 }
+unsigned_radix_key! { u16 }
+unsigned_radix_key! { u32 }
+unsigned_radix_key! { u64 }
+signed_radix_key! { i8, u8 }
+signed_radix_key! { i16, u16 }
+signed_radix_key! { i32, u32 }
+signed_radix_key! { i64, u64 }
+
+/// Sort slices of [Cami] of [CamiRadixKey] types in [Cami] order, without comparisons.
+pub trait RadixSortCami<T: CamiRadixKey> {
+    /// Sort in [Cami] order. For keys of at most one byte (`()`, [bool], [u8] and [i8]), it's a
+    /// counting sort: `O(n)`, with no allocation. For wider keys, it falls back to
+    /// [slice::sort_unstable] (use [RadixSortCami::radix_sort_cami] to sort those in `O(n)`).
+    fn sort_cami(&mut self);
+
+    /// Sort in [Cami] order with an LSD radix sort: one pass per byte of the key, with `buffer` for
+    /// the scatter. It skips passes (bytes) that are the same for all items. For keys of at most one
+    /// byte, it's the same as [RadixSortCami::sort_cami] (and `buffer` is not used).
+    ///
+    /// # Panics
+    ///
+    /// If `buffer` is shorter than the slice (and the keys are wider than one byte).
+    fn radix_sort_cami(&mut self, buffer: &mut [Cami<T>]);
+}
+
+impl<T: CamiRadixKey> RadixSortCami<T> for [Cami<T>] {
+    fn sort_cami(&mut self) {
+        if T::RADIX_BYTES <= 1 {
+            counting_sort(self);
+        } else {
+            self.sort_unstable();
+        }
+    }
+
+    fn radix_sort_cami(&mut self, buffer: &mut [Cami<T>]) {
+        if T::RADIX_BYTES <= 1 {
+            counting_sort(self);
+            return;
+        }
+        assert!(
+            buffer.len() >= self.len(),
+            "the buffer must be at least as long as the slice"
+        );
+        let buffer = &mut buffer[..self.len()];
+        let mut in_buffer = false;
+        for byte in 0..T::RADIX_BYTES {
+            let shift = 8 * byte as u32;
+            let scattered = if in_buffer {
+                scatter(buffer, self, shift)
+            } else {
+                scatter(self, buffer, shift)
+            };
+            if scattered {
+                in_buffer = !in_buffer;
+            }
+        }
+        if in_buffer {
+            self.copy_from_slice(buffer);
+        }
+    }
+}
+
+#[inline]
+fn digit<T: CamiRadixKey>(item: &Cami<T>, shift: u32) -> usize {
+    (item.in_cami().radix_key() >> shift) as u8 as usize
+}
+
+/// Count the keys, and then rebuild the items from them.
+fn counting_sort<T: CamiRadixKey>(slice: &mut [Cami<T>]) {
+    debug_assert!(!T::LOCALITY.has_non_local());
+    if T::RADIX_BYTES == 0 {
+        return;
+    }
+    let mut counts = [0usize; 256];
+    for item in slice.iter() {
+        counts[digit(item, 0)] += 1;
+    }
+    let mut start = 0;
+    for (key, &count) in counts.iter().enumerate() {
+        if count > 0 {
+            slice[start..start + count].fill(Cami::new(T::from_radix_key(key as u64)));
+            start += count;
+        }
+    }
+}
+
+/// One (stable) LSD pass: scatter `source` to `target` by the byte at `shift`. Return `false` (and
+/// leave `target` untouched) if all items have the same byte there.
+fn scatter<T: CamiRadixKey>(source: &[Cami<T>], target: &mut [Cami<T>], shift: u32) -> bool {
+    let mut offsets = [0usize; 256];
+    for item in source {
+        offsets[digit(item, shift)] += 1;
+    }
+    if offsets.contains(&source.len()) {
+        return false;
+    }
+    let mut total = 0;
+    for offset in offsets.iter_mut() {
+        let count = *offset;
+        *offset = total;
+        total += count;
+    }
+    for item in source {
+        let digit = digit(item, shift);
+        target[offsets[digit]] = *item;
+        offsets[digit] += 1;
+    }
+    true
+}
@@ -0,0 +1,143 @@
+use crate::cami::{eq_cami, partial_cmp_cami};
+use crate::{Cami, CamiLocalKey, CamiPartialEq, CamiPartialOrd};
+use core::cmp::Ordering;
+use core::ops::{Bound, RangeBounds};
+
+/// Search helpers for slices of [Cami], with needles of (possibly) a different type than the
+/// items. For example, search `[Cami<String>]` for a `&str` (or `str`) needle - with no need to
+/// allocate a temporary `String` for every lookup:
+///
+/// `names.binary_search_cami("abc")`
+///
+/// The needle is NOT wrapped in [Cami]. Any `Q` works where `T: CamiPartialEq<Q>` (and
+/// `T: CamiPartialOrd<Q>` for binary search).
+pub trait SearchCami<T: CamiPartialEq> {
+    /// Like [slice::binary_search], but in [Cami] order. The slice has to be sorted in [Cami] order.
+    ///
+    /// If an item and the needle are incomparable (their [CamiPartialOrd::partial_cmp_local] or
+    /// [CamiPartialOrd::partial_cmp_non_local] return [None]), the item is treated as being less
+    /// than the needle.
+    fn binary_search_cami<Q: ?Sized>(&self, needle: &Q) -> Result<usize, usize>
+    where
+        T: CamiPartialOrd<Q> + CamiPartialEq<Q>;
+
+    /// Like [slice::contains], but with [Cami] equality. Sequential.
+    #[must_use]
+    fn contains_cami<Q: ?Sized>(&self, needle: &Q) -> bool
+    where
+        T: CamiPartialEq<Q>;
+
+    /// Index of the first item equal to the needle (with [Cami] equality). Sequential.
+    #[must_use]
+    fn position_cami<Q: ?Sized>(&self, needle: &Q) -> Option<usize>
+    where
+        T: CamiPartialEq<Q>;
+
+    /// Subslice of items whose local keys ([CamiLocalKey::local_key]) are in `local_keys`. The slice
+    /// has to be sorted in [Cami] order. For example, all strings of length `5..=10` of a
+    /// `[Cami<String>]`, with no needle strings.
+    ///
+    /// It uses binary search over the local keys only, so it never accesses non-local parts.
+    #[must_use]
+    fn local_range<R: RangeBounds<T::LocalKey>>(&self, local_keys: R) -> &[Cami<T>]
+    where
+        T: CamiLocalKey;
+
+    /// Subslice of items equal to the needle (with [Cami] equality) - like C++ `equal_range`. The
+    /// slice has to be sorted in [Cami] order.
+    ///
+    /// It first narrows down (with binary search) to items whose local parts equal the needle's,
+    /// comparing only the local parts. Only within that range it compares non-local parts. As with
+    /// [SearchCami::binary_search_cami], incomparable items are treated as being less than the
+    /// needle.
+    #[must_use]
+    fn equal_range_cami<Q: ?Sized>(&self, needle: &Q) -> &[Cami<T>]
+    where
+        T: CamiPartialOrd<Q> + CamiPartialEq<Q>;
+}
+
+impl<T: CamiPartialEq> SearchCami<T> for [Cami<T>] {
+    #[inline]
+    fn binary_search_cami<Q: ?Sized>(&self, needle: &Q) -> Result<usize, usize>
+    where
+        T: CamiPartialOrd<Q> + CamiPartialEq<Q>,
+    {
+        self.binary_search_by(|item| {
+            partial_cmp_cami(item.in_cami(), needle).unwrap_or(Ordering::Less)
+        })
+    }
+
+    #[must_use]
+    #[inline]
+    fn contains_cami<Q: ?Sized>(&self, needle: &Q) -> bool
+    where
+        T: CamiPartialEq<Q>,
+    {
+        self.position_cami(needle).is_some()
+    }
+
+    #[must_use]
+    #[inline]
+    fn position_cami<Q: ?Sized>(&self, needle: &Q) -> Option<usize>
+    where
+        T: CamiPartialEq<Q>,
+    {
+        self.iter().position(|item| eq_cami(item.in_cami(), needle))
+    }
+
+    #[must_use]
+    fn local_range<R: RangeBounds<T::LocalKey>>(&self, local_keys: R) -> &[Cami<T>]
+    where
+        T: CamiLocalKey,
+    {
+        let start = match local_keys.start_bound() {
+            Bound::Included(key) => self.partition_point(|item| item.local_key() < *key),
+            Bound::Excluded(key) => self.partition_point(|item| item.local_key() <= *key),
+            Bound::Unbounded => 0,
+        };
+        let end = match local_keys.end_bound() {
+            Bound::Included(key) => self.partition_point(|item| item.local_key() <= *key),
+            Bound::Excluded(key) => self.partition_point(|item| item.local_key() < *key),
+            Bound::Unbounded => self.len(),
+        };
+        &self[start..end.max(start)]
+    }
+
+    #[must_use]
+    fn equal_range_cami<Q: ?Sized>(&self, needle: &Q) -> &[Cami<T>]
+    where
+        T: CamiPartialOrd<Q> + CamiPartialEq<Q>,
+    {
+        let locality = <T as CamiPartialEq<Q>>::LOCALITY;
+        let local_equal = if locality.has_local() {
+            let start = self.partition_point(|item| {
+                !matches!(
+                    item.in_cami().partial_cmp_local(needle),
+                    Some(Ordering::Equal | Ordering::Greater)
+                )
+            });
+            let end = start
+                + self[start..].partition_point(|item| {
+                    item.in_cami().partial_cmp_local(needle) != Some(Ordering::Greater)
+                });
+            &self[start..end]
+        } else {
+            self
+        };
+        if locality.has_non_local() {
+            let start = local_equal.partition_point(|item| {
+                !matches!(
+                    item.in_cami().partial_cmp_non_local(needle),
+                    Some(Ordering::Equal | Ordering::Greater)
+                )
+            });
+            let end = start
+                + local_equal[start..].partition_point(|item| {
+                    item.in_cami().partial_cmp_non_local(needle) != Some(Ordering::Greater)
+                });
+            &local_equal[start..end]
+        } else {
+            local_equal
+        }
+    }
+}
@@ -0,0 +1,149 @@
+//! Selection of the smallest items in [crate::Cami] order: [select_nth_unstable_cami],
+//! [partial_sort_cami] and [top_k_cami_in] (with a caller-provided buffer, so it works with
+//! `no_std`).
+//!
+//! [partial_sort_cami] and [top_k_cami_in] keep a bounded max-heap of the smallest items seen so
+//! far. Once the heap is full, they cache the local key ([CamiLocalKey]) of its root - the
+//! threshold. A candidate whose local key is greater is rejected without accessing its non-local
+//! part (or the root's). Only a candidate with an equal local key compares the non-local parts.
+use super::merge::lt;
+use crate::cami::cmp_cami;
+use crate::{CamiLocalKey, CamiOrd};
+use core::cmp::Ordering;
+
+/// Like [slice::select_nth_unstable], but in [crate::Cami] order (local parts first, and the
+/// non-local parts only if the local parts are equal).
+///
+/// # Panics
+///
+/// If `index >= slice.len()`.
+#[inline]
+pub fn select_nth_unstable_cami<T: CamiOrd>(
+    slice: &mut [T],
+    index: usize,
+) -> (&mut [T], &mut T, &mut [T]) {
+    slice.select_nth_unstable_by(index, cmp_cami)
+}
+
+/// Sift up in a max-heap.
+pub(crate) fn sift_up<T: CamiOrd>(heap: &mut [T], mut node: usize) {
+    while node > 0 {
+        let parent = (node - 1) / 2;
+        if !lt(&heap[parent], &heap[node]) {
+            break;
+        }
+        heap.swap(parent, node);
+        node = parent;
+    }
+}
+
+fn sift_down<T: CamiOrd>(heap: &mut [T], mut node: usize) {
+    loop {
+        let mut child = 2 * node + 1;
+        if child >= heap.len() {
+            break;
+        }
+        if child + 1 < heap.len() && lt(&heap[child], &heap[child + 1]) {
+            child += 1;
+        }
+        if !lt(&heap[node], &heap[child]) {
+            break;
+        }
+        heap.swap(node, child);
+        node = child;
+    }
+}
+
+/// Sort a max-heap in ascending order.
+fn sort_heap<T: CamiOrd>(heap: &mut [T]) {
+    for end in (1..heap.len()).rev() {
+        heap.swap(0, end);
+        sift_down(&mut heap[..end], 0);
+    }
+}
+
+/// Push `item` to a max-heap `heap[..len]`, where `heap.len() > len`. Used while the heap is not
+/// full yet.
+#[inline]
+fn heap_push<T: CamiOrd>(heap: &mut [T], len: usize, item: T) {
+    heap[len] = item;
+    sift_up(&mut heap[..=len], len);
+}
+
+/// Whether `candidate` is less than the `root` (whose local key is `threshold`).
+#[inline]
+fn admits<T: CamiOrd + CamiLocalKey>(candidate: &T, root: &T, threshold: &T::LocalKey) -> bool {
+    match candidate.local_key().cmp(threshold) {
+        Ordering::Less => true,
+        Ordering::Greater => false,
+        Ordering::Equal => {
+            T::LOCALITY.has_non_local() && candidate.cmp_non_local(root) == Ordering::Less
+        }
+    }
+}
+
+/// Offer the rest of the candidates to a full max-heap, and then sort it (ascending).
+pub(crate) fn top_k_rest<T, I>(heap: &mut [T], rest: I)
+where
+    T: CamiOrd + CamiLocalKey,
+    I: Iterator<Item = T>,
+{
+    if let Some(root) = heap.first() {
+        let mut threshold = root.local_key();
+        for candidate in rest {
+            if admits(&candidate, &heap[0], &threshold) {
+                heap[0] = candidate;
+                sift_down(heap, 0);
+                threshold = heap[0].local_key();
+            }
+        }
+    }
+    sort_heap(heap);
+}
+
+/// Rearrange `slice` so that `slice[..k]` are its `k` smallest items, sorted in [crate::Cami]
+/// order. The order of the rest is unspecified. Unstable. With no allocation.
+///
+/// If `k >= slice.len()`, it sorts the whole slice.
+pub fn partial_sort_cami<T: CamiOrd + CamiLocalKey>(slice: &mut [T], k: usize) {
+    let k = k.min(slice.len());
+    let (heap, rest) = slice.split_at_mut(k);
+    for node in (0..heap.len() / 2).rev() {
+        sift_down(heap, node);
+    }
+    if let Some(root) = heap.first() {
+        let mut threshold = root.local_key();
+        for candidate in rest {
+            if admits(candidate, &heap[0], &threshold) {
+                core::mem::swap(&mut heap[0], candidate);
+                sift_down(heap, 0);
+                threshold = heap[0].local_key();
+            }
+        }
+    }
+    sort_heap(heap);
+}
+
+/// The (up to) `buffer.len()` smallest items of `iter`, sorted in [crate::Cami] order. Unstable.
+///
+/// The result is stored in (a prefix of) `buffer`, overwriting (and dropping) its existing items -
+/// so the caller provides any (for example [Default]) values. That's for `no_std` with no
+/// allocation. The returned prefix is shorter than `buffer` only if `iter` has fewer items.
+pub fn top_k_cami_in<T, I>(iter: I, buffer: &mut [T]) -> &mut [T]
+where
+    T: CamiOrd + CamiLocalKey,
+    I: IntoIterator<Item = T>,
+{
+    let mut iter = iter.into_iter();
+    let mut len = 0;
+    while len < buffer.len() {
+        match iter.next() {
+            Some(item) => heap_push(buffer, len, item),
+            None => break,
+        }
+        len += 1;
+    }
+    let heap = &mut buffer[..len];
+    top_k_rest(heap, iter);
+    heap
+}
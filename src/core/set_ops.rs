@@ -0,0 +1,279 @@
+//! Set algebra over two inputs sorted in [crate::Cami] order.
+//!
+//! Inputs may contain duplicates - then they're treated as multisets (like C++ `std::set_*`):
+//! items are paired one to one, so an item present `m` times on the left and `n` times on the
+//! right is in the intersection `min(m, n)` times, in the union `max(m, n)` times, in the
+//! difference `m - n` times (if positive) and in the symmetric difference `|m - n|` times.
+use super::merge::{gallop, lt};
+use crate::cami::cmp_cami;
+use crate::CamiOrd;
+use core::cmp::Ordering;
+use core::iter::{FusedIterator, Peekable};
+
+/// Item of [MergeJoinByCami].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum EitherOrBoth<T> {
+    /// Only on the left.
+    Left(T),
+    /// Only on the right.
+    Right(T),
+    /// Equal (in [crate::Cami] order) items, one from each side.
+    Both(T, T),
+}
+
+impl<T> EitherOrBoth<T> {
+    /// The left item, if any.
+    #[must_use]
+    #[inline]
+    pub fn left(self) -> Option<T> {
+        match self {
+            Self::Left(left) | Self::Both(left, _) => Some(left),
+            Self::Right(_) => None,
+        }
+    }
+
+    /// The right item, if any.
+    #[must_use]
+    #[inline]
+    pub fn right(self) -> Option<T> {
+        match self {
+            Self::Right(right) | Self::Both(_, right) => Some(right),
+            Self::Left(_) => None,
+        }
+    }
+}
+
+/// Merge join of two iterators, each sorted in [crate::Cami] order. It pairs equal items (one
+/// from each side, in order) as [EitherOrBoth::Both].
+#[must_use]
+#[inline]
+pub fn merge_join_by_cami<I, J>(left: I, right: J) -> MergeJoinByCami<I::IntoIter, J::IntoIter>
+where
+    I: IntoIterator,
+    J: IntoIterator<Item = I::Item>,
+    I::Item: CamiOrd,
+{
+    MergeJoinByCami {
+        left: left.into_iter().peekable(),
+        right: right.into_iter().peekable(),
+    }
+}
+
+/// Iterator returned by [merge_join_by_cami].
+pub struct MergeJoinByCami<I: Iterator, J: Iterator<Item = I::Item>> {
+    left: Peekable<I>,
+    right: Peekable<J>,
+}
+
+impl<I, J> Clone for MergeJoinByCami<I, J>
+where
+    I: Iterator + Clone,
+    J: Iterator<Item = I::Item> + Clone,
+    I::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            left: self.left.clone(),
+            right: self.right.clone(),
+        }
+    }
+}
+
+impl<I, J> Iterator for MergeJoinByCami<I, J>
+where
+    I: Iterator,
+    J: Iterator<Item = I::Item>,
+    I::Item: CamiOrd,
+{
+    type Item = EitherOrBoth<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let ordering = match (self.left.peek(), self.right.peek()) {
+            (Some(left), Some(right)) => cmp_cami(left, right),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => return None,
+        };
+        match ordering {
+            Ordering::Less => self.left.next().map(EitherOrBoth::Left),
+            Ordering::Greater => self.right.next().map(EitherOrBoth::Right),
+            Ordering::Equal => match (self.left.next(), self.right.next()) {
+                (Some(left), Some(right)) => Some(EitherOrBoth::Both(left, right)),
+                _ => None,
+            },
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (left_lower, left_upper) = self.left.size_hint();
+        let (right_lower, right_upper) = self.right.size_hint();
+        let upper = match (left_upper, right_upper) {
+            (Some(left), Some(right)) => left.checked_add(right),
+            _ => None,
+        };
+        (left_lower.max(right_lower), upper)
+    }
+}
+
+impl<I, J> FusedIterator for MergeJoinByCami<I, J>
+where
+    I: FusedIterator,
+    J: FusedIterator<Item = I::Item>,
+    I::Item: CamiOrd,
+{
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum SetOp {
+    Intersection,
+    Union,
+    Difference,
+    SymmetricDifference,
+}
+
+impl SetOp {
+    #[inline]
+    fn keeps_left(self) -> bool {
+        matches!(
+            self,
+            Self::Union | Self::Difference | Self::SymmetricDifference
+        )
+    }
+
+    #[inline]
+    fn keeps_right(self) -> bool {
+        matches!(self, Self::Union | Self::SymmetricDifference)
+    }
+
+    #[inline]
+    fn keeps_both(self) -> bool {
+        matches!(self, Self::Intersection | Self::Union)
+    }
+}
+
+/// Iterator over references to items of two slices, each sorted in [crate::Cami] order. Returned
+/// by [intersection_cami], [union_cami], [difference_cami] and [symmetric_difference_cami]. It
+/// yields in [crate::Cami] order. For items present on both sides it yields the left one.
+///
+/// Whenever the head of one side is less than the head of the other side, it finds (with
+/// exponential search) the whole run of such items, and then it yields (or skips) that run with no
+/// more comparisons.
+#[derive(Clone, Debug)]
+pub struct SetOpCami<'a, T: CamiOrd> {
+    left: &'a [T],
+    right: &'a [T],
+    /// Run (found by exponential search) to yield before comparing any more items.
+    pending: &'a [T],
+    op: SetOp,
+}
+
+impl<'a, T: CamiOrd> SetOpCami<'a, T> {
+    #[inline]
+    fn new(left: &'a [T], right: &'a [T], op: SetOp) -> Self {
+        Self {
+            left,
+            right,
+            pending: &[],
+            op,
+        }
+    }
+}
+
+/// Items present in both `left` and `right`.
+#[must_use]
+#[inline]
+pub fn intersection_cami<'a, T: CamiOrd>(left: &'a [T], right: &'a [T]) -> SetOpCami<'a, T> {
+    SetOpCami::new(left, right, SetOp::Intersection)
+}
+
+/// Items present in `left` or `right`, or both.
+#[must_use]
+#[inline]
+pub fn union_cami<'a, T: CamiOrd>(left: &'a [T], right: &'a [T]) -> SetOpCami<'a, T> {
+    SetOpCami::new(left, right, SetOp::Union)
+}
+
+/// Items present in `left`, but not in `right`.
+#[must_use]
+#[inline]
+pub fn difference_cami<'a, T: CamiOrd>(left: &'a [T], right: &'a [T]) -> SetOpCami<'a, T> {
+    SetOpCami::new(left, right, SetOp::Difference)
+}
+
+/// Items present in `left` or `right`, but not in both.
+#[must_use]
+#[inline]
+pub fn symmetric_difference_cami<'a, T: CamiOrd>(
+    left: &'a [T],
+    right: &'a [T],
+) -> SetOpCami<'a, T> {
+    SetOpCami::new(left, right, SetOp::SymmetricDifference)
+}
+
+impl<'a, T: CamiOrd> Iterator for SetOpCami<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        loop {
+            if let Some((first, rest)) = self.pending.split_first() {
+                self.pending = rest;
+                return Some(first);
+            }
+            match (self.left.first(), self.right.first()) {
+                (Some(left), Some(right)) => match cmp_cami(left, right) {
+                    Ordering::Less => {
+                        let run = gallop(self.left, |item| lt(item, right));
+                        let (run, rest) = self.left.split_at(run);
+                        self.left = rest;
+                        if self.op.keeps_left() {
+                            self.pending = run;
+                        }
+                    }
+                    Ordering::Greater => {
+                        let run = gallop(self.right, |item| lt(item, left));
+                        let (run, rest) = self.right.split_at(run);
+                        self.right = rest;
+                        if self.op.keeps_right() {
+                            self.pending = run;
+                        }
+                    }
+                    Ordering::Equal => {
+                        let left: &'a [T] = self.left;
+                        self.left = &left[1..];
+                        self.right = &self.right[1..];
+                        if self.op.keeps_both() {
+                            return Some(&left[0]);
+                        }
+                    }
+                },
+                (Some(_), None) => {
+                    if self.op.keeps_left() {
+                        self.pending = self.left;
+                    }
+                    self.left = &[];
+                }
+                (None, Some(_)) => {
+                    if self.op.keeps_right() {
+                        self.pending = self.right;
+                    }
+                    self.right = &[];
+                }
+                (None, None) => return None,
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (left, right, pending) = (self.left.len(), self.right.len(), self.pending.len());
+        let (lower, upper) = match self.op {
+            SetOp::Intersection => (0, left.min(right)),
+            SetOp::Union => (left.max(right), left + right),
+            SetOp::Difference => (left.saturating_sub(right), left),
+            SetOp::SymmetricDifference => (left.max(right) - left.min(right), left + right),
+        };
+        (pending + lower, Some(pending + upper))
+    }
+}
+
+impl<'a, T: CamiOrd> FusedIterator for SetOpCami<'a, T> {}
@@ -1,4 +1,4 @@
-use crate::{Cami, CamiOrd, CamiPartialEq, CamiPartialOrd, Locality};
+use crate::{Cami, CamiLocalKey, CamiOrd, CamiPartialEq, CamiPartialOrd, Locality};
 use core::cmp::Ordering;
 
 /// Used, for example, for multi-dimensional slices (or arrays/vectors). We also have a similar
@@ -113,6 +113,25 @@ where
     }
 }
 
+/// The length, consistent with [CamiOrd::cmp_local] for `&[T]`.
+impl<T> CamiLocalKey for [T] {
+    type LocalKey = usize;
+    #[must_use]
+    #[inline]
+    fn local_key(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T> CamiLocalKey for &[T] {
+    type LocalKey = usize;
+    #[must_use]
+    #[inline]
+    fn local_key(&self) -> usize {
+        self.len()
+    }
+}
+
 // @TODO search for SliceCami (traits containing this in their name), and update them to use `SliceCami`
 //
 // @TODO rename to CamiSlice? Or: remove?
@@ -208,3 +227,109 @@ impl CamiOrd for &str {
         self.cmp(&other)
     }
 }
+
+/// The length (in bytes), consistent with [CamiOrd::cmp_local] for `&str`.
+impl CamiLocalKey for str {
+    type LocalKey = usize;
+    #[must_use]
+    #[inline]
+    fn local_key(&self) -> usize {
+        self.len()
+    }
+}
+
+impl CamiLocalKey for &str {
+    type LocalKey = usize;
+    #[must_use]
+    #[inline]
+    fn local_key(&self) -> usize {
+        self.len()
+    }
+}
+
+/// Implement [CamiPartialEq] & [CamiPartialOrd] between two (different) types that both deref to
+/// the same `str` or `[T]`. Their [Locality] is [Locality::Both]: the local part is the length, and
+/// the non-local part is the (lexicographic) content. That is consistent with the impls for `&str`,
+/// `String`, `&[T]`, `Vec<T>`... themselves.
+///
+/// Parameters: `[generics for CamiPartialEq impl] [generics for CamiPartialOrd impl] Deref target,
+/// Self, Rhs`.
+macro_rules! cami_cross_deref {
+    ([$($eq_gen:tt)*] [$($ord_gen:tt)*] $target:ty, $this:ty, $other:ty) => {
+        impl<$($eq_gen)*> CamiPartialEq<$other> for $this {
+            const LOCALITY: Locality = Locality::Both;
+
+            #[must_use]
+            #[inline]
+            fn eq_local(&self, other: &$other) -> bool {
+                let this: &$target = self;
+                let other: &$target = other;
+                this.len() == other.len()
+            }
+
+            #[must_use]
+            #[inline]
+            fn eq_non_local(&self, other: &$other) -> bool {
+                let this: &$target = self;
+                let other: &$target = other;
+                this == other
+            }
+        }
+
+        impl<$($ord_gen)*> CamiPartialOrd<$other> for $this {
+            #[must_use]
+            #[inline]
+            fn partial_cmp_local(&self, other: &$other) -> Option<Ordering> {
+                let this: &$target = self;
+                let other: &$target = other;
+                this.len().partial_cmp(&other.len())
+            }
+
+            #[must_use]
+            #[inline]
+            fn partial_cmp_non_local(&self, other: &$other) -> Option<Ordering> {
+                let this: &$target = self;
+                let other: &$target = other;
+                this.partial_cmp(other)
+            }
+        }
+    };
+}
+pub(crate) use cami_cross_deref;
+
+// Bare `str` and `[T]` are for needles (for example, in [crate::core::SearchCami]), and for keys
+// projected by reference (for example, in [crate::by_key]).
+cami_cross_deref! { ['a] ['a] str, &'a str, str }
+cami_cross_deref! { ['a, T: PartialEq] ['a, T: PartialOrd] [T], &'a [T], [T] }
+cami_cross_deref! { [] [] str, str, str }
+cami_cross_deref! { [T: PartialEq] [T: PartialOrd] [T], [T], [T] }
+
+/// The same as for `&str`.
+impl CamiOrd for str {
+    #[must_use]
+    #[inline]
+    fn cmp_local(&self, other: &Self) -> Ordering {
+        self.len().cmp(&other.len())
+    }
+
+    #[must_use]
+    #[inline]
+    fn cmp_non_local(&self, other: &Self) -> Ordering {
+        self.cmp(other)
+    }
+}
+
+/// The same as for `&[T]`.
+impl<T: Ord> CamiOrd for [T] {
+    #[must_use]
+    #[inline]
+    fn cmp_local(&self, other: &Self) -> Ordering {
+        self.len().cmp(&other.len())
+    }
+
+    #[must_use]
+    #[inline]
+    fn cmp_non_local(&self, other: &Self) -> Ordering {
+        self.cmp(other)
+    }
+}
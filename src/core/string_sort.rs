@@ -0,0 +1,156 @@
+//! Sorting slices of [Cami] byte strings (`&str`, `&[u8]` and `String`) in [Cami] order, with no
+//! full `memcmp` comparisons in the non-local phase.
+//!
+//! [Cami] order of these is by length (the local part) first, and then lexicographic by bytes (the
+//! non-local part). So, first, it sorts by length, which groups the items into equal-length
+//! buckets. Then it sorts each bucket with a multikey quicksort (three-way radix quicksort): it
+//! partitions by one byte at a time, and only the middle (equal byte) part moves on to the next
+//! byte. As all strings in a bucket have the same length, every byte position exists in all of
+//! them, so there is no end-of-string case to handle.
+use crate::{Cami, CamiOrd};
+#[cfg(feature = "alloc")]
+use rust_alloc::string::String;
+
+/// A [Cami] byte string, ordered by length (the local part), and then lexicographically by its
+/// bytes (the non-local part). That's what [StringSortCami::sort_strings_cami] relies on.
+pub trait CamiBytes: CamiOrd {
+    #[must_use]
+    fn cami_bytes(&self) -> &[u8];
+}
+
+impl CamiBytes for &str {
+    #[must_use]
+    #[inline]
+    fn cami_bytes(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl CamiBytes for &[u8] {
+    #[must_use]
+    #[inline]
+    fn cami_bytes(&self) -> &[u8] {
+        self
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl CamiBytes for String {
+    #[must_use]
+    #[inline]
+    fn cami_bytes(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+/// Sort slices of [Cami] byte strings ([CamiBytes]).
+pub trait StringSortCami<T: CamiBytes> {
+    /// Sort in [Cami] order (the same as [slice::sort_unstable]): by length, and then by a multikey
+    /// quicksort within each equal-length bucket. Unstable. With no allocation.
+    fn sort_strings_cami(&mut self);
+}
+
+impl<T: CamiBytes> StringSortCami<T> for [Cami<T>] {
+    fn sort_strings_cami(&mut self) {
+        self.sort_unstable_by_key(|item| item.in_cami().cami_bytes().len());
+        let mut start = 0;
+        while start < self.len() {
+            let width = self[start].in_cami().cami_bytes().len();
+            let end = start
+                + self[start..]
+                    .iter()
+                    .take_while(|item| item.in_cami().cami_bytes().len() == width)
+                    .count();
+            if width > 0 {
+                multikey_quicksort(&mut self[start..end], 0, width);
+            }
+            start = end;
+        }
+    }
+}
+
+/// Buckets this short are sorted by insertion sort.
+const INSERTION_SORT_MAX: usize = 12;
+
+#[inline]
+fn byte_at<T: CamiBytes>(item: &Cami<T>, depth: usize) -> u8 {
+    item.in_cami().cami_bytes()[depth]
+}
+
+/// Sort `bucket` (of strings that are all `width` bytes long, and that all agree on their bytes
+/// before `depth`) by bytes from `depth` on.
+fn multikey_quicksort<T: CamiBytes>(bucket: &mut [Cami<T>], depth: usize, width: usize) {
+    let (mut low, mut high, mut depth) = (0, bucket.len(), depth);
+    loop {
+        if high - low <= INSERTION_SORT_MAX {
+            insertion_sort(&mut bucket[low..high], depth);
+            return;
+        }
+        let (less, greater) = partition(&mut bucket[low..high], depth);
+        let (less, greater) = (low + less, low + greater);
+        // Recurse into the two smaller parts, and loop on the largest, so that the stack depth is
+        // logarithmic in the number of items (plus up to `width` for the equal parts).
+        let mut parts = [
+            (low, less, depth),
+            (greater, high, depth),
+            (less, greater, depth + 1),
+        ];
+        if depth + 1 == width {
+            // The equal part is fully sorted already.
+            parts[2] = (less, less, depth);
+        }
+        parts.sort_unstable_by_key(|&(low, high, _)| high - low);
+        for &(low, high, depth) in &parts[..2] {
+            if high - low > 1 {
+                multikey_quicksort(&mut bucket[low..high], depth, width);
+            }
+        }
+        low = parts[2].0;
+        high = parts[2].1;
+        depth = parts[2].2;
+        if high - low <= 1 {
+            return;
+        }
+    }
+}
+
+/// Three-way partition of `bucket` (at least 3 items) by the byte at `depth`, around a median of
+/// three pivot. Return `(less, greater)`: `bucket[..less]` have a smaller byte,
+/// `bucket[less..greater]` the same byte as the pivot, and `bucket[greater..]` a greater byte.
+fn partition<T: CamiBytes>(bucket: &mut [Cami<T>], depth: usize) -> (usize, usize) {
+    let pivot = {
+        let first = byte_at(&bucket[0], depth);
+        let middle = byte_at(&bucket[bucket.len() / 2], depth);
+        let last = byte_at(&bucket[bucket.len() - 1], depth);
+        first.max(middle).min(first.min(middle).max(last))
+    };
+    let (mut less, mut index, mut greater) = (0, 0, bucket.len());
+    while index < greater {
+        let byte = byte_at(&bucket[index], depth);
+        if byte < pivot {
+            bucket.swap(less, index);
+            less += 1;
+            index += 1;
+        } else if byte > pivot {
+            greater -= 1;
+            bucket.swap(index, greater);
+        } else {
+            index += 1;
+        }
+    }
+    (less, greater)
+}
+
+/// Insertion sort by bytes from `depth` on (the same length in all items).
+fn insertion_sort<T: CamiBytes>(bucket: &mut [Cami<T>], depth: usize) {
+    for index in 1..bucket.len() {
+        let mut current = index;
+        while current > 0
+            && bucket[current].in_cami().cami_bytes()[depth..]
+                < bucket[current - 1].in_cami().cami_bytes()[depth..]
+        {
+            bucket.swap(current, current - 1);
+            current -= 1;
+        }
+    }
+}
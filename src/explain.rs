@@ -0,0 +1,158 @@
+use crate::cami::cmp_cami_tiers;
+use crate::{Cami, CamiOrd};
+use core::cmp::Ordering;
+use core::fmt;
+#[cfg(feature = "debug")]
+use core::fmt::Debug;
+
+/// Which tier decided the result of [Ord::cmp] for [Cami].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CmpDecidedBy {
+    /// [CamiOrd::cmp_local] returned non-[Ordering::Equal].
+    Local,
+    /// [CamiOrd::cmp_local] was [Ordering::Equal] (or not applicable), and
+    /// [CamiOrd::cmp_non_local] returned non-[Ordering::Equal].
+    NonLocal,
+    /// All applicable tiers returned [Ordering::Equal].
+    Tie,
+}
+
+/// Result of [explain_cmp]: what each tier of [CamiOrd] returned, and which tier decided.
+///
+/// Its [fmt::Debug] shows the tiers only. Under `debug` feature (for `T: Debug`) its [fmt::Debug]
+/// includes [fmt::Debug] renderings of both values, too, and it's also [fmt::Display].
+pub struct CmpExplanation<'a, T: CamiOrd> {
+    left: &'a Cami<T>,
+    right: &'a Cami<T>,
+    local: Option<Ordering>,
+    non_local: Option<Ordering>,
+    decided_by: CmpDecidedBy,
+}
+
+impl<'a, T: CamiOrd> CmpExplanation<'a, T> {
+    #[must_use]
+    #[inline]
+    pub fn left(&self) -> &'a Cami<T> {
+        self.left
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn right(&self) -> &'a Cami<T> {
+        self.right
+    }
+
+    /// Result of [CamiOrd::cmp_local], or [None] if `T` has no local part (as per
+    /// [crate::CamiPartialEq::LOCALITY]).
+    #[must_use]
+    #[inline]
+    pub fn local(&self) -> Option<Ordering> {
+        self.local
+    }
+
+    /// Result of [CamiOrd::cmp_non_local], or [None] if it was not called - either because `T`
+    /// has no non-local part, or because the local part already decided.
+    #[must_use]
+    #[inline]
+    pub fn non_local(&self) -> Option<Ordering> {
+        self.non_local
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn decided_by(&self) -> CmpDecidedBy {
+        self.decided_by
+    }
+
+    /// The overall result. The same as `left.cmp(right)`.
+    #[must_use]
+    #[inline]
+    pub fn ordering(&self) -> Ordering {
+        match self.decided_by {
+            CmpDecidedBy::Local => self.local.unwrap_or(Ordering::Equal),
+            CmpDecidedBy::NonLocal => self.non_local.unwrap_or(Ordering::Equal),
+            CmpDecidedBy::Tie => Ordering::Equal,
+        }
+    }
+}
+
+/// Explain the result of [Ord::cmp] for [Cami]: which tier ([CamiOrd::cmp_local],
+/// [CamiOrd::cmp_non_local]) decided, or whether it was a full tie.
+///
+/// It uses the same [crate::CamiPartialEq::LOCALITY] branches as `impl Ord for Cami<T>`, so it calls each
+/// tier only when [Ord::cmp] would call it, too.
+#[must_use]
+pub fn explain_cmp<'a, T: CamiOrd>(left: &'a Cami<T>, right: &'a Cami<T>) -> CmpExplanation<'a, T> {
+    let (local, non_local) = cmp_cami_tiers(left.in_cami(), right.in_cami());
+    let decided_by = match (local, non_local) {
+        (_, Some(Ordering::Equal)) | (Some(Ordering::Equal), None) => CmpDecidedBy::Tie,
+        (_, Some(_)) => CmpDecidedBy::NonLocal,
+        _ => CmpDecidedBy::Local,
+    };
+    CmpExplanation {
+        left,
+        right,
+        local,
+        non_local,
+        decided_by,
+    }
+}
+
+impl<'a, T: CamiOrd> Clone for CmpExplanation<'a, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<'a, T: CamiOrd> Copy for CmpExplanation<'a, T> {}
+
+/// Without the values (so that it's available for any `T`). Under `debug` feature it shows the
+/// values, too.
+#[cfg(not(feature = "debug"))]
+impl<'a, T: CamiOrd> fmt::Debug for CmpExplanation<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CmpExplanation")
+            .field("local", &self.local)
+            .field("non_local", &self.non_local)
+            .field("decided_by", &self.decided_by)
+            .finish()
+    }
+}
+
+#[cfg(feature = "debug")]
+impl<'a, T: CamiOrd + Debug> fmt::Debug for CmpExplanation<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CmpExplanation")
+            .field("left", self.left.in_cami())
+            .field("right", self.right.in_cami())
+            .field("local", &self.local)
+            .field("non_local", &self.non_local)
+            .field("decided_by", &self.decided_by)
+            .finish()
+    }
+}
+
+#[cfg(feature = "debug")]
+impl<'a, T: CamiOrd + Debug> fmt::Display for CmpExplanation<'a, T> {
+    /// For example: `"b" Less "aa": decided by cmp_local (Less)`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:?} {:?} {:?}: ",
+            self.left.in_cami(),
+            self.ordering(),
+            self.right.in_cami()
+        )?;
+        match self.decided_by {
+            CmpDecidedBy::Local => write!(f, "decided by cmp_local ({:?})", self.ordering()),
+            CmpDecidedBy::NonLocal if self.local.is_some() => write!(
+                f,
+                "cmp_local tied, decided by cmp_non_local ({:?})",
+                self.ordering()
+            ),
+            CmpDecidedBy::NonLocal => {
+                write!(f, "decided by cmp_non_local ({:?})", self.ordering())
+            }
+            CmpDecidedBy::Tie => f.write_str("full tie"),
+        }
+    }
+}
@@ -12,9 +12,14 @@ const NOT_SUPPORTED: () = {
     panic!("NOT_SUPPORTED")
 };*/
 
-pub use cami::{Cami, IntoCami, IntoCamiClone, IntoCamiCopy, IntoRefCami, IntoSliceCami};
+pub use cami::{
+    Cami, CamiCopy, FromArrayCami, FromSliceCami, IntoArrayCami, IntoCami, IntoCamiClone,
+    IntoCamiCopy, IntoRefCami, IntoSliceCami,
+};
 pub use cami_helpers::Locality;
-pub use traits::{CamiOrd, CamiPartialEq, CamiPartialOrd};
+pub use compare::{by_key, cmp, eq, lt, partial_cmp};
+pub use explain::{explain_cmp, CmpDecidedBy, CmpExplanation};
+pub use traits::{CamiLocalKey, CamiOrd, CamiPartialEq, CamiPartialOrd};
 
 pub mod prelude;
 mod traits;
@@ -22,8 +27,13 @@ mod traits;
 #[cfg(feature = "alloc")]
 pub mod alloc;
 mod cami;
+mod compare;
+mod explain;
+mod prefetch;
 
 pub mod core;
+#[cfg(feature = "serde")]
+pub mod serde;
 #[cfg(feature = "std")]
 pub mod std;
 
@@ -0,0 +1,16 @@
+//! Software prefetch hints. No-op on architectures where we don't have a stable intrinsic.
+#![allow(unsafe_code)]
+
+/// Hint the CPU to fetch the cache line containing `ptr` (for reading). `ptr` does NOT need to be
+/// valid (or in bounds) - so callers can use [pointer::wrapping_add] beyond the end of a slice.
+#[inline(always)]
+pub(crate) fn prefetch_read<T>(ptr: *const T) {
+    #[cfg(target_arch = "x86_64")]
+    // SAFETY: A prefetch is only a hint. It doesn't dereference `ptr`, and it never faults - not
+    // even for invalid addresses.
+    unsafe {
+        core::arch::x86_64::_mm_prefetch(ptr as *const i8, core::arch::x86_64::_MM_HINT_T0)
+    }
+    #[cfg(not(target_arch = "x86_64"))]
+    let _ = ptr;
+}
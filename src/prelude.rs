@@ -1,6 +1,7 @@
 pub use crate::{
-    Cami, CamiOrd, CamiPartialEq, CamiPartialOrd, IntoCami, IntoCamiClone, IntoCamiCopy,
-    IntoRefCami, IntoSliceCami, Locality,
+    Cami, CamiCopy, CamiLocalKey, CamiOrd, CamiPartialEq, CamiPartialOrd, FromArrayCami,
+    FromSliceCami, IntoArrayCami, IntoCami, IntoCamiClone, IntoCamiCopy, IntoRefCami,
+    IntoSliceCami, Locality,
 };
 
 #[cfg(feature = "alloc")]
@@ -12,4 +13,8 @@ pub use a_prelude::*;
 mod c_prelude;
 pub use c_prelude::*;
 
-// @TODO same two `pub use` for core & std
+#[cfg(feature = "std")]
+mod s_prelude;
+
+#[cfg(feature = "std")]
+pub use s_prelude::*;
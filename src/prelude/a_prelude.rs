@@ -1,2 +1,18 @@
+pub use crate::alloc::argsort::{
+    apply_permutation, apply_permutation_u32, argsort_cami, argsort_cami_u32,
+};
+pub use crate::alloc::boxed::*;
+pub use crate::alloc::btree_map::CamiBTreeMap;
+pub use crate::alloc::cami_str::CamiStr;
+pub use crate::alloc::eytzinger::CamiEytzinger;
+pub use crate::alloc::interner::{CamiInterner, FrozenCamiInterner, Symbol};
+pub use crate::alloc::kmerge::{kmerge_cami, kmerge_cami_stable, KMergeCami};
+pub use crate::alloc::rc::*;
+pub use crate::alloc::select::top_k_cami;
+pub use crate::alloc::set_ops::*;
+pub use crate::alloc::static_tree::CamiStaticTree;
 pub use crate::alloc::string::*;
+pub use crate::alloc::string_table::CamiStringTable;
+#[cfg(target_has_atomic = "ptr")]
+pub use crate::alloc::sync::*;
 pub use crate::alloc::vec::*;
@@ -0,0 +1,2 @@
+pub use crate::std::hash_map::CamiHashMap;
+pub use crate::std::hash_set::CamiHashSet;
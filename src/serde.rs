@@ -0,0 +1,142 @@
+//! [serde](https://serde.rs) adapters for collections of [Cami], for use with `#[serde(with =
+//! "...")]`. Items are serialized exactly like the wrapped `T` (not as a newtype), and they are
+//! deserialized straight into `Vec<Cami<T>>` - with no intermediate `Vec<T>`.
+//!
+//! ```ignore
+//! #[derive(Serialize, Deserialize)]
+//! struct Table {
+//!     #[serde(with = "cami::serde::vec")]
+//!     any_order: Vec<Cami<String>>,
+//!     #[serde(with = "cami::serde::sorted_vec")]
+//!     sorted: Vec<Cami<String>>,
+//! }
+//! ```
+use crate::{Cami, CamiOrd, CamiPartialEq};
+use ::serde::de::{Deserialize, Deserializer, Error, SeqAccess, Visitor};
+use ::serde::ser::{Serialize, Serializer};
+use core::fmt;
+use core::marker::PhantomData;
+use rust_alloc::vec::Vec;
+
+/// Upper limit for pre-allocation based on [SeqAccess::size_hint], so that malicious input can't
+/// make us allocate a lot up front.
+const MAX_PREALLOCATE: usize = 4096;
+
+struct VecCamiVisitor<T>(PhantomData<T>);
+
+impl<'de, T: Deserialize<'de> + CamiPartialEq> Visitor<'de> for VecCamiVisitor<T> {
+    type Value = Vec<Cami<T>>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a sequence")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut items = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(MAX_PREALLOCATE));
+        while let Some(item) = seq.next_element::<T>()? {
+            items.push(Cami::new(item));
+        }
+        Ok(items)
+    }
+}
+
+fn serialize_items<T, S>(items: &[Cami<T>], serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Serialize + CamiPartialEq,
+    S: Serializer,
+{
+    serializer.collect_seq(items.iter().map(Cami::in_cami))
+}
+
+fn deserialize_items<'de, T, D>(deserializer: D) -> Result<Vec<Cami<T>>, D::Error>
+where
+    T: Deserialize<'de> + CamiPartialEq,
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_seq(VecCamiVisitor(PhantomData))
+}
+
+/// Index of the first item that is less (in [Cami] order) than its predecessor, if any.
+fn first_unsorted<T: CamiOrd>(items: &[Cami<T>]) -> Option<usize> {
+    items
+        .windows(2)
+        .position(|pair| pair[0] > pair[1])
+        .map(|index| index + 1)
+}
+
+/// `Vec<Cami<T>>` (de)serialized exactly like `Vec<T>`, in any order.
+pub mod vec {
+    use super::*;
+
+    pub fn serialize<T, S>(items: &[Cami<T>], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Serialize + CamiPartialEq,
+        S: Serializer,
+    {
+        serialize_items(items, serializer)
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Vec<Cami<T>>, D::Error>
+    where
+        T: Deserialize<'de> + CamiPartialEq,
+        D: Deserializer<'de>,
+    {
+        deserialize_items(deserializer)
+    }
+}
+
+/// `Vec<Cami<T>>` (de)serialized exactly like `Vec<T>`, but sorted in [Cami] order (duplicates
+/// allowed). Deserialization rejects any input that is not sorted.
+pub mod sorted_vec {
+    use super::*;
+
+    /// In debug builds this asserts that `items` are sorted, so that they can be deserialized
+    /// back.
+    pub fn serialize<T, S>(items: &[Cami<T>], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Serialize + CamiOrd,
+        S: Serializer,
+    {
+        debug_assert!(first_unsorted(items).is_none());
+        serialize_items(items, serializer)
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Vec<Cami<T>>, D::Error>
+    where
+        T: Deserialize<'de> + CamiOrd,
+        D: Deserializer<'de>,
+    {
+        let items = deserialize_items(deserializer)?;
+        match first_unsorted(&items) {
+            None => Ok(items),
+            Some(index) => Err(D::Error::custom(format_args!(
+                "item at index {} is not sorted in Cami order",
+                index
+            ))),
+        }
+    }
+}
+
+/// `Vec<Cami<T>>` (de)serialized exactly like `Vec<T>`. Deserialization sorts the input in [Cami]
+/// order (stable), rather than rejecting it like [sorted_vec] does.
+pub mod sorting_vec {
+    use super::*;
+
+    pub fn serialize<T, S>(items: &[Cami<T>], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Serialize + CamiOrd,
+        S: Serializer,
+    {
+        serialize_items(items, serializer)
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Vec<Cami<T>>, D::Error>
+    where
+        T: Deserialize<'de> + CamiOrd,
+        D: Deserializer<'de>,
+    {
+        let mut items = deserialize_items(deserializer)?;
+        items.sort();
+        Ok(items)
+    }
+}
@@ -1 +1,4 @@
 pub use crate::alloc::*;
+
+pub mod hash_map;
+pub mod hash_set;
@@ -0,0 +1,807 @@
+//! Hash map with [Cami] keys, using open addressing, where each slot of the table holds (some of)
+//! the hash bits and the local key (as per [CamiLocalKey]) of its entry.
+//!
+//! A std `HashMap<Cami<K>, V>` calls [PartialEq::eq] on every candidate in a probe sequence whose
+//! hash (bits) match. Here a candidate whose hash bits or local key differ from the needle's is
+//! rejected straight from the table - without dereferencing the stored key. Only if both match,
+//! [CamiPartialEq::eq_non_local] gets called (if applicable, as per [CamiPartialEq::LOCALITY]).
+//!
+//! [CamiHashMap::stats] shows how many such dereferences were avoided.
+use crate::{Cami, CamiLocalKey, CamiPartialEq};
+use ::std::borrow::Borrow;
+use ::std::collections::hash_map::RandomState;
+use ::std::hash::{BuildHasher, Hash, Hasher};
+use ::std::iter::FusedIterator;
+use ::std::mem;
+use ::std::slice;
+use ::std::sync::atomic::{AtomicUsize, Ordering as AtomicOrdering};
+use ::std::vec;
+
+/// Minimum number of slots (once allocated). A power of two.
+const MIN_SLOTS: usize = 8;
+
+#[derive(Clone, Copy)]
+enum Slot<L> {
+    Empty,
+    /// A removed entry (a tombstone). Probing continues past it.
+    Deleted,
+    Full {
+        /// Index into `entries`.
+        index: usize,
+        /// Top 8 bits of the hash. (The low bits determine the position.) Like the 7-bit tags of
+        /// SwissTable (std `HashMap`), it's small - so tags of different keys match now and then.
+        /// That's where the local key saves a dereference.
+        tag: u8,
+        local: L,
+    },
+}
+
+#[derive(Clone)]
+struct Bucket<K: CamiPartialEq, V> {
+    hash: u64,
+    key: Cami<K>,
+    value: V,
+}
+
+#[inline]
+fn tag_of(hash: u64) -> u8 {
+    (hash >> 56) as u8
+}
+
+/// Counters of [CamiHashMap] (or [crate::std::hash_set::CamiHashSet]) lookups, since creation or
+/// since the last [CamiHashMap::reset_stats].
+///
+/// They're cheap (no atomic read-modify-write), so under concurrent lookups (through shared
+/// references from several threads) some increments may get lost.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CamiHashStats {
+    /// Occupied slots visited while probing.
+    pub probed: usize,
+    /// Occupied slots rejected because their hash bits differed - without dereferencing the key.
+    pub rejected_by_hash: usize,
+    /// Occupied slots rejected because their local keys differed (even though their hash bits
+    /// matched) - without dereferencing the key.
+    pub rejected_by_local: usize,
+    /// Calls to [CamiPartialEq::eq_non_local] - that is, dereferences of stored keys.
+    pub non_local_comparisons: usize,
+}
+
+impl CamiHashStats {
+    /// Dereferences of stored keys avoided, compared to a table that checks only the (same) hash
+    /// tags.
+    #[must_use]
+    #[inline]
+    pub fn dereferences_avoided(&self) -> usize {
+        self.rejected_by_local
+    }
+}
+
+#[derive(Default)]
+struct Counter(AtomicUsize);
+
+impl Counter {
+    /// Not atomic as a whole: Relaxed load & store, so that it's as cheap as a non-atomic
+    /// increment.
+    #[inline]
+    fn increment(&self) {
+        self.0.store(
+            self.0.load(AtomicOrdering::Relaxed) + 1,
+            AtomicOrdering::Relaxed,
+        );
+    }
+
+    #[inline]
+    fn get(&self) -> usize {
+        self.0.load(AtomicOrdering::Relaxed)
+    }
+
+    #[inline]
+    fn reset(&mut self) {
+        *self.0.get_mut() = 0;
+    }
+}
+
+#[derive(Default)]
+struct Counters {
+    probed: Counter,
+    rejected_by_hash: Counter,
+    rejected_by_local: Counter,
+    non_local_comparisons: Counter,
+}
+
+/// Hash map with [Cami] keys. Its API follows [::std::collections::HashMap] (for `insert`, `get`,
+/// `remove`, `entry`, `iter`...). Iteration order is the insertion order, as long as there are no
+/// removals. (Removal moves the last entry into the place of the removed one.)
+///
+/// Lookups accept any (possibly unsized) `Q` where `K: Borrow<Q>` (so that `K` and `Q` hash the
+/// same) and `K: CamiPartialEq<Q>`, and where `Q` has the same [CamiLocalKey::LocalKey] as `K`.
+/// For example, look up `CamiHashMap<String, V>` by `&str`:
+///
+/// `map.get("abc")`
+///
+/// Keys are hashed as `K` (not as [Cami]), so this doesn't need the `hash` feature.
+pub struct CamiHashMap<K: CamiPartialEq + CamiLocalKey, V, S = RandomState> {
+    /// Length is 0, or a power of two.
+    slots: Vec<Slot<K::LocalKey>>,
+    entries: Vec<Bucket<K, V>>,
+    /// Number of [Slot::Deleted].
+    deleted: usize,
+    hash_builder: S,
+    counters: Counters,
+}
+
+impl<K: CamiPartialEq + CamiLocalKey + Hash, V> CamiHashMap<K, V, RandomState> {
+    #[must_use]
+    #[inline]
+    pub fn new() -> Self {
+        Self::with_hasher(RandomState::new())
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity_and_hasher(capacity, RandomState::new())
+    }
+}
+
+impl<K: CamiPartialEq + CamiLocalKey + Hash, V, S: BuildHasher> CamiHashMap<K, V, S> {
+    #[must_use]
+    #[inline]
+    pub fn with_hasher(hash_builder: S) -> Self {
+        Self {
+            slots: Vec::new(),
+            entries: Vec::new(),
+            deleted: 0,
+            hash_builder,
+            counters: Counters::default(),
+        }
+    }
+
+    #[must_use]
+    pub fn with_capacity_and_hasher(capacity: usize, hash_builder: S) -> Self {
+        let mut map = Self::with_hasher(hash_builder);
+        map.reserve(capacity);
+        map
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Number of entries the map can hold without reallocating its table.
+    #[must_use]
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        Self::max_used(self.slots.len())
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn hasher(&self) -> &S {
+        &self.hash_builder
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.slots.iter_mut().for_each(|slot| *slot = Slot::Empty);
+        self.deleted = 0;
+    }
+
+    /// Maximum number of full and deleted slots: 7/8 of `slots`.
+    #[inline]
+    fn max_used(slots: usize) -> usize {
+        slots - slots / 8
+    }
+
+    pub fn reserve(&mut self, additional: usize) {
+        let needed = self.entries.len() + additional;
+        if needed + self.deleted > Self::max_used(self.slots.len()) {
+            let mut slots = MIN_SLOTS.max(self.slots.len());
+            while Self::max_used(slots) < needed {
+                slots *= 2;
+            }
+            self.rehash(slots);
+        }
+        self.entries.reserve(additional);
+    }
+
+    /// Rebuild the table with `slots` slots (a power of two), dropping tombstones. It reuses the
+    /// hash bits & local keys from the old table - it doesn't touch the keys.
+    fn rehash(&mut self, slots: usize) {
+        let old = mem::replace(&mut self.slots, vec![Slot::Empty; slots]);
+        self.deleted = 0;
+        let mask = slots - 1;
+        for slot in old {
+            if let Slot::Full { index, .. } = slot {
+                let mut position = self.entries[index].hash as usize & mask;
+                while let Slot::Full { .. } = self.slots[position] {
+                    position = (position + 1) & mask;
+                }
+                self.slots[position] = slot;
+            }
+        }
+    }
+
+    #[inline]
+    fn hash<Q: Hash + ?Sized>(&self, key: &Q) -> u64 {
+        let mut hasher = self.hash_builder.build_hasher();
+        key.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Position (in `slots`) of the entry with `key`, if any.
+    fn find<Q>(&self, hash: u64, local: &K::LocalKey, key: &Q) -> Option<usize>
+    where
+        Q: ?Sized,
+        K: CamiPartialEq<Q>,
+    {
+        if self.slots.is_empty() {
+            return None;
+        }
+        let mask = self.slots.len() - 1;
+        let tag = tag_of(hash);
+        let mut position = hash as usize & mask;
+        loop {
+            match &self.slots[position] {
+                Slot::Empty => return None,
+                Slot::Deleted => {}
+                Slot::Full {
+                    index,
+                    tag: slot_tag,
+                    local: slot_local,
+                } => {
+                    self.counters.probed.increment();
+                    if *slot_tag != tag {
+                        self.counters.rejected_by_hash.increment();
+                    } else if slot_local != local {
+                        self.counters.rejected_by_local.increment();
+                    } else if !<Cami<K>>::LOCALITY.has_non_local() {
+                        return Some(position);
+                    } else {
+                        self.counters.non_local_comparisons.increment();
+                        if self.entries[*index].key.in_cami().eq_non_local(key) {
+                            return Some(position);
+                        }
+                    }
+                }
+            }
+            position = (position + 1) & mask;
+        }
+    }
+
+    /// Index (in `entries`) of the entry with `key`, if any.
+    #[inline]
+    fn find_index<Q>(&self, key: &Q) -> Option<usize>
+    where
+        Q: Hash + CamiLocalKey<LocalKey = K::LocalKey> + ?Sized,
+        K: Borrow<Q> + CamiPartialEq<Q>,
+    {
+        let position = self.find(self.hash(key), &key.local_key(), key)?;
+        match self.slots[position] {
+            Slot::Full { index, .. } => Some(index),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Add an entry for a key that is NOT in the map yet. Return its index (in `entries`).
+    fn insert_new(&mut self, hash: u64, local: K::LocalKey, key: Cami<K>, value: V) -> usize {
+        self.reserve(1);
+        let mask = self.slots.len() - 1;
+        let mut position = hash as usize & mask;
+        while let Slot::Full { .. } = self.slots[position] {
+            position = (position + 1) & mask;
+        }
+        if let Slot::Deleted = self.slots[position] {
+            self.deleted -= 1;
+        }
+        let index = self.entries.len();
+        self.slots[position] = Slot::Full {
+            index,
+            tag: tag_of(hash),
+            local,
+        };
+        self.entries.push(Bucket { hash, key, value });
+        index
+    }
+
+    /// Remove the entry at `position` (in `slots`), moving the last entry into its place (in
+    /// `entries`).
+    fn remove_at(&mut self, position: usize) -> (Cami<K>, V) {
+        let index = match mem::replace(&mut self.slots[position], Slot::Deleted) {
+            Slot::Full { index, .. } => index,
+            _ => unreachable!(),
+        };
+        self.deleted += 1;
+        let last = self.entries.len() - 1;
+        if index != last {
+            // Find the slot of the last entry by its index - without comparing keys.
+            let mask = self.slots.len() - 1;
+            let mut position = self.entries[last].hash as usize & mask;
+            loop {
+                if let Slot::Full {
+                    index: slot_index, ..
+                } = &mut self.slots[position]
+                {
+                    if *slot_index == last {
+                        *slot_index = index;
+                        break;
+                    }
+                }
+                position = (position + 1) & mask;
+            }
+        }
+        let bucket = self.entries.swap_remove(index);
+        (bucket.key, bucket.value)
+    }
+
+    #[must_use]
+    pub fn get_key_value<Q>(&self, key: &Q) -> Option<(&Cami<K>, &V)>
+    where
+        Q: Hash + CamiLocalKey<LocalKey = K::LocalKey> + ?Sized,
+        K: Borrow<Q> + CamiPartialEq<Q>,
+    {
+        self.find_index(key).map(|index| {
+            let bucket = &self.entries[index];
+            (&bucket.key, &bucket.value)
+        })
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        Q: Hash + CamiLocalKey<LocalKey = K::LocalKey> + ?Sized,
+        K: Borrow<Q> + CamiPartialEq<Q>,
+    {
+        self.get_key_value(key).map(|(_, value)| value)
+    }
+
+    #[must_use]
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        Q: Hash + CamiLocalKey<LocalKey = K::LocalKey> + ?Sized,
+        K: Borrow<Q> + CamiPartialEq<Q>,
+    {
+        let index = self.find_index(key)?;
+        Some(&mut self.entries[index].value)
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        Q: Hash + CamiLocalKey<LocalKey = K::LocalKey> + ?Sized,
+        K: Borrow<Q> + CamiPartialEq<Q>,
+    {
+        self.find_index(key).is_some()
+    }
+
+    /// Like [::std::collections::HashMap::insert]: If the map already had this key, keep the
+    /// existing key, replace the value and return the old value.
+    pub fn insert(&mut self, key: Cami<K>, value: V) -> Option<V> {
+        match self.entry(key) {
+            Entry::Occupied(mut entry) => Some(entry.insert(value)),
+            Entry::Vacant(entry) => {
+                entry.insert(value);
+                None
+            }
+        }
+    }
+
+    /// Insert, or replace both the key and the value. Return the old key and value (if any).
+    pub(crate) fn replace_key(&mut self, key: Cami<K>, value: V) -> Option<(Cami<K>, V)> {
+        let hash = self.hash(key.in_cami());
+        let local = key.local_key();
+        match self.find(hash, &local, key.in_cami()) {
+            Some(position) => {
+                let index = match self.slots[position] {
+                    Slot::Full { index, .. } => index,
+                    _ => unreachable!(),
+                };
+                // Equal keys have the same hash and local key, so the slot stays as it is.
+                let bucket = &mut self.entries[index];
+                Some((
+                    mem::replace(&mut bucket.key, key),
+                    mem::replace(&mut bucket.value, value),
+                ))
+            }
+            None => {
+                self.insert_new(hash, local, key, value);
+                None
+            }
+        }
+    }
+
+    pub fn remove_entry<Q>(&mut self, key: &Q) -> Option<(Cami<K>, V)>
+    where
+        Q: Hash + CamiLocalKey<LocalKey = K::LocalKey> + ?Sized,
+        K: Borrow<Q> + CamiPartialEq<Q>,
+    {
+        let position = self.find(self.hash(key), &key.local_key(), key)?;
+        Some(self.remove_at(position))
+    }
+
+    #[inline]
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        Q: Hash + CamiLocalKey<LocalKey = K::LocalKey> + ?Sized,
+        K: Borrow<Q> + CamiPartialEq<Q>,
+    {
+        self.remove_entry(key).map(|(_, value)| value)
+    }
+
+    /// Like [::std::collections::HashMap::entry].
+    pub fn entry(&mut self, key: Cami<K>) -> Entry<'_, K, V, S> {
+        let hash = self.hash(key.in_cami());
+        let local = key.local_key();
+        match self.find(hash, &local, key.in_cami()) {
+            Some(position) => Entry::Occupied(OccupiedEntry {
+                map: self,
+                position,
+            }),
+            None => Entry::Vacant(VacantEntry {
+                map: self,
+                hash,
+                local,
+                key,
+            }),
+        }
+    }
+
+    /// Keep only entries for which `f` returns `true`.
+    pub fn retain<F: FnMut(&Cami<K>, &mut V) -> bool>(&mut self, mut f: F) {
+        let mut position = 0;
+        while position < self.slots.len() {
+            if let Slot::Full { index, .. } = self.slots[position] {
+                let bucket = &mut self.entries[index];
+                if !f(&bucket.key, &mut bucket.value) {
+                    self.remove_at(position);
+                }
+            }
+            position += 1;
+        }
+    }
+
+    /// Counters of lookups so far. See [CamiHashStats].
+    #[must_use]
+    pub fn stats(&self) -> CamiHashStats {
+        CamiHashStats {
+            probed: self.counters.probed.get(),
+            rejected_by_hash: self.counters.rejected_by_hash.get(),
+            rejected_by_local: self.counters.rejected_by_local.get(),
+            non_local_comparisons: self.counters.non_local_comparisons.get(),
+        }
+    }
+
+    pub fn reset_stats(&mut self) {
+        self.counters.probed.reset();
+        self.counters.rejected_by_hash.reset();
+        self.counters.rejected_by_local.reset();
+        self.counters.non_local_comparisons.reset();
+    }
+}
+
+impl<K: CamiPartialEq + CamiLocalKey, V, S> CamiHashMap<K, V, S> {
+    #[must_use]
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter {
+            entries: self.entries.iter(),
+        }
+    }
+
+    /// Iterate with mutable values. (Keys stay immutable.)
+    #[must_use]
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+        IterMut {
+            entries: self.entries.iter_mut(),
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn keys(&self) -> impl ExactSizeIterator<Item = &Cami<K>> + '_ {
+        self.entries.iter().map(|bucket| &bucket.key)
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn values(&self) -> impl ExactSizeIterator<Item = &V> + '_ {
+        self.entries.iter().map(|bucket| &bucket.value)
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn values_mut(&mut self) -> impl ExactSizeIterator<Item = &mut V> + '_ {
+        self.entries.iter_mut().map(|bucket| &mut bucket.value)
+    }
+}
+
+impl<K: CamiPartialEq + CamiLocalKey + Hash, V, S: BuildHasher + Default> Default
+    for CamiHashMap<K, V, S>
+{
+    #[inline]
+    fn default() -> Self {
+        Self::with_hasher(S::default())
+    }
+}
+
+impl<K: CamiPartialEq + CamiLocalKey + Clone, V: Clone, S: Clone> Clone for CamiHashMap<K, V, S> {
+    /// The clone's [CamiHashMap::stats] start from zero.
+    fn clone(&self) -> Self {
+        Self {
+            slots: self.slots.clone(),
+            entries: self.entries.clone(),
+            deleted: self.deleted,
+            hash_builder: self.hash_builder.clone(),
+            counters: Counters::default(),
+        }
+    }
+}
+
+impl<K: CamiPartialEq + CamiLocalKey + Hash, V, S: BuildHasher> Extend<(Cami<K>, V)>
+    for CamiHashMap<K, V, S>
+{
+    fn extend<I: IntoIterator<Item = (Cami<K>, V)>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+impl<K: CamiPartialEq + CamiLocalKey + Hash, V, S: BuildHasher + Default> FromIterator<(Cami<K>, V)>
+    for CamiHashMap<K, V, S>
+{
+    fn from_iter<I: IntoIterator<Item = (Cami<K>, V)>>(iter: I) -> Self {
+        let mut map = Self::default();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<'a, K: CamiPartialEq + CamiLocalKey, V, S> IntoIterator for &'a CamiHashMap<K, V, S> {
+    type Item = (&'a Cami<K>, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+    #[inline]
+    fn into_iter(self) -> Iter<'a, K, V> {
+        self.iter()
+    }
+}
+
+impl<'a, K: CamiPartialEq + CamiLocalKey, V, S> IntoIterator for &'a mut CamiHashMap<K, V, S> {
+    type Item = (&'a Cami<K>, &'a mut V);
+    type IntoIter = IterMut<'a, K, V>;
+    #[inline]
+    fn into_iter(self) -> IterMut<'a, K, V> {
+        self.iter_mut()
+    }
+}
+
+impl<K: CamiPartialEq + CamiLocalKey, V, S> IntoIterator for CamiHashMap<K, V, S> {
+    type Item = (Cami<K>, V);
+    type IntoIter = IntoIter<K, V>;
+    #[inline]
+    fn into_iter(self) -> IntoIter<K, V> {
+        IntoIter {
+            entries: self.entries.into_iter(),
+        }
+    }
+}
+
+/// Iterator over [CamiHashMap].
+pub struct Iter<'a, K: CamiPartialEq, V> {
+    entries: slice::Iter<'a, Bucket<K, V>>,
+}
+
+impl<'a, K: CamiPartialEq, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a Cami<K>, &'a V);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.entries
+            .next()
+            .map(|bucket| (&bucket.key, &bucket.value))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.entries.size_hint()
+    }
+}
+
+impl<'a, K: CamiPartialEq, V> ExactSizeIterator for Iter<'a, K, V> {}
+impl<'a, K: CamiPartialEq, V> FusedIterator for Iter<'a, K, V> {}
+
+impl<'a, K: CamiPartialEq, V> Clone for Iter<'a, K, V> {
+    fn clone(&self) -> Self {
+        Self {
+            entries: self.entries.clone(),
+        }
+    }
+}
+
+/// Iterator over [CamiHashMap], with mutable values.
+pub struct IterMut<'a, K: CamiPartialEq, V> {
+    entries: slice::IterMut<'a, Bucket<K, V>>,
+}
+
+impl<'a, K: CamiPartialEq, V> Iterator for IterMut<'a, K, V> {
+    type Item = (&'a Cami<K>, &'a mut V);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.entries
+            .next()
+            .map(|bucket| (&bucket.key, &mut bucket.value))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.entries.size_hint()
+    }
+}
+
+impl<'a, K: CamiPartialEq, V> ExactSizeIterator for IterMut<'a, K, V> {}
+impl<'a, K: CamiPartialEq, V> FusedIterator for IterMut<'a, K, V> {}
+
+/// Owning iterator over [CamiHashMap].
+pub struct IntoIter<K: CamiPartialEq, V> {
+    entries: vec::IntoIter<Bucket<K, V>>,
+}
+
+impl<K: CamiPartialEq, V> Iterator for IntoIter<K, V> {
+    type Item = (Cami<K>, V);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.entries.next().map(|bucket| (bucket.key, bucket.value))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.entries.size_hint()
+    }
+}
+
+impl<K: CamiPartialEq, V> ExactSizeIterator for IntoIter<K, V> {}
+impl<K: CamiPartialEq, V> FusedIterator for IntoIter<K, V> {}
+
+/// Like [::std::collections::hash_map::Entry].
+pub enum Entry<'a, K: CamiPartialEq + CamiLocalKey, V, S> {
+    Occupied(OccupiedEntry<'a, K, V, S>),
+    Vacant(VacantEntry<'a, K, V, S>),
+}
+
+impl<'a, K: CamiPartialEq + CamiLocalKey + Hash, V, S: BuildHasher> Entry<'a, K, V, S> {
+    #[must_use]
+    #[inline]
+    pub fn key(&self) -> &Cami<K> {
+        match self {
+            Entry::Occupied(entry) => entry.key(),
+            Entry::Vacant(entry) => entry.key(),
+        }
+    }
+
+    #[inline]
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        self.or_insert_with(|| default)
+    }
+
+    pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default()),
+        }
+    }
+
+    #[inline]
+    pub fn or_default(self) -> &'a mut V
+    where
+        V: Default,
+    {
+        self.or_insert_with(V::default)
+    }
+
+    #[must_use]
+    pub fn and_modify<F: FnOnce(&mut V)>(mut self, f: F) -> Self {
+        if let Entry::Occupied(entry) = &mut self {
+            f(entry.get_mut());
+        }
+        self
+    }
+}
+
+pub struct OccupiedEntry<'a, K: CamiPartialEq + CamiLocalKey, V, S> {
+    map: &'a mut CamiHashMap<K, V, S>,
+    /// Position in `slots`.
+    position: usize,
+}
+
+impl<'a, K: CamiPartialEq + CamiLocalKey + Hash, V, S: BuildHasher> OccupiedEntry<'a, K, V, S> {
+    #[inline]
+    fn index(&self) -> usize {
+        match self.map.slots[self.position] {
+            Slot::Full { index, .. } => index,
+            _ => unreachable!(),
+        }
+    }
+
+    /// The key stored in the map (NOT the one passed to [CamiHashMap::entry]).
+    #[must_use]
+    #[inline]
+    pub fn key(&self) -> &Cami<K> {
+        &self.map.entries[self.index()].key
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn get(&self) -> &V {
+        &self.map.entries[self.index()].value
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut V {
+        let index = self.index();
+        &mut self.map.entries[index].value
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn into_mut(self) -> &'a mut V {
+        let index = self.index();
+        &mut self.map.entries[index].value
+    }
+
+    #[inline]
+    pub fn insert(&mut self, value: V) -> V {
+        mem::replace(self.get_mut(), value)
+    }
+
+    #[inline]
+    pub fn remove_entry(self) -> (Cami<K>, V) {
+        self.map.remove_at(self.position)
+    }
+
+    #[inline]
+    pub fn remove(self) -> V {
+        self.remove_entry().1
+    }
+}
+
+pub struct VacantEntry<'a, K: CamiPartialEq + CamiLocalKey, V, S> {
+    map: &'a mut CamiHashMap<K, V, S>,
+    hash: u64,
+    local: K::LocalKey,
+    key: Cami<K>,
+}
+
+impl<'a, K: CamiPartialEq + CamiLocalKey + Hash, V, S: BuildHasher> VacantEntry<'a, K, V, S> {
+    #[must_use]
+    #[inline]
+    pub fn key(&self) -> &Cami<K> {
+        &self.key
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn into_key(self) -> Cami<K> {
+        self.key
+    }
+
+    pub fn insert(self, value: V) -> &'a mut V {
+        let index = self.map.insert_new(self.hash, self.local, self.key, value);
+        &mut self.map.entries[index].value
+    }
+}
@@ -0,0 +1,274 @@
+//! Hash set of [Cami] items. See [crate::std::hash_map] for how slots are tagged with local keys.
+use crate::std::hash_map::{self, CamiHashMap, CamiHashStats};
+use crate::{Cami, CamiLocalKey, CamiPartialEq};
+use ::std::borrow::Borrow;
+use ::std::collections::hash_map::RandomState;
+use ::std::hash::{BuildHasher, Hash};
+use ::std::iter::FusedIterator;
+
+/// Hash set of [Cami] items. Its API follows [::std::collections::HashSet]. Lookups accept the
+/// same (possibly unsized) needles as [CamiHashMap].
+pub struct CamiHashSet<T: CamiPartialEq + CamiLocalKey, S = RandomState> {
+    map: CamiHashMap<T, (), S>,
+}
+
+impl<T: CamiPartialEq + CamiLocalKey + Hash> CamiHashSet<T, RandomState> {
+    #[must_use]
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            map: CamiHashMap::new(),
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            map: CamiHashMap::with_capacity(capacity),
+        }
+    }
+}
+
+impl<T: CamiPartialEq + CamiLocalKey + Hash, S: BuildHasher> CamiHashSet<T, S> {
+    #[must_use]
+    #[inline]
+    pub fn with_hasher(hash_builder: S) -> Self {
+        Self {
+            map: CamiHashMap::with_hasher(hash_builder),
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn with_capacity_and_hasher(capacity: usize, hash_builder: S) -> Self {
+        Self {
+            map: CamiHashMap::with_capacity_and_hasher(capacity, hash_builder),
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.map.capacity()
+    }
+
+    #[inline]
+    pub fn reserve(&mut self, additional: usize) {
+        self.map.reserve(additional);
+    }
+
+    #[inline]
+    pub fn clear(&mut self) {
+        self.map.clear();
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn contains<Q>(&self, value: &Q) -> bool
+    where
+        Q: Hash + CamiLocalKey<LocalKey = T::LocalKey> + ?Sized,
+        T: Borrow<Q> + CamiPartialEq<Q>,
+    {
+        self.map.contains_key(value)
+    }
+
+    /// The stored item equal to `value`, if any.
+    #[must_use]
+    #[inline]
+    pub fn get<Q>(&self, value: &Q) -> Option<&Cami<T>>
+    where
+        Q: Hash + CamiLocalKey<LocalKey = T::LocalKey> + ?Sized,
+        T: Borrow<Q> + CamiPartialEq<Q>,
+    {
+        self.map.get_key_value(value).map(|(item, _)| item)
+    }
+
+    /// Return whether `value` was newly inserted. If the set already had an equal item, keep the
+    /// existing one.
+    #[inline]
+    pub fn insert(&mut self, value: Cami<T>) -> bool {
+        match self.map.entry(value) {
+            hash_map::Entry::Occupied(_) => false,
+            hash_map::Entry::Vacant(entry) => {
+                entry.insert(());
+                true
+            }
+        }
+    }
+
+    /// Insert `value`, replacing (and returning) an equal item, if any.
+    #[inline]
+    pub fn replace(&mut self, value: Cami<T>) -> Option<Cami<T>> {
+        self.map.replace_key(value, ()).map(|(item, ())| item)
+    }
+
+    #[inline]
+    pub fn remove<Q>(&mut self, value: &Q) -> bool
+    where
+        Q: Hash + CamiLocalKey<LocalKey = T::LocalKey> + ?Sized,
+        T: Borrow<Q> + CamiPartialEq<Q>,
+    {
+        self.map.remove(value).is_some()
+    }
+
+    /// Remove and return the stored item equal to `value`, if any.
+    #[inline]
+    pub fn take<Q>(&mut self, value: &Q) -> Option<Cami<T>>
+    where
+        Q: Hash + CamiLocalKey<LocalKey = T::LocalKey> + ?Sized,
+        T: Borrow<Q> + CamiPartialEq<Q>,
+    {
+        self.map.remove_entry(value).map(|(item, ())| item)
+    }
+
+    #[inline]
+    pub fn retain<F: FnMut(&Cami<T>) -> bool>(&mut self, mut f: F) {
+        self.map.retain(|item, ()| f(item));
+    }
+
+    /// Counters of lookups so far. See [CamiHashStats].
+    #[must_use]
+    #[inline]
+    pub fn stats(&self) -> CamiHashStats {
+        self.map.stats()
+    }
+
+    #[inline]
+    pub fn reset_stats(&mut self) {
+        self.map.reset_stats();
+    }
+}
+
+impl<T: CamiPartialEq + CamiLocalKey, S> CamiHashSet<T, S> {
+    #[must_use]
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            inner: self.map.iter(),
+        }
+    }
+}
+
+impl<T: CamiPartialEq + CamiLocalKey + Hash, S: BuildHasher + Default> Default
+    for CamiHashSet<T, S>
+{
+    #[inline]
+    fn default() -> Self {
+        Self {
+            map: CamiHashMap::default(),
+        }
+    }
+}
+
+impl<T: CamiPartialEq + CamiLocalKey + Clone, S: Clone> Clone for CamiHashSet<T, S> {
+    fn clone(&self) -> Self {
+        Self {
+            map: self.map.clone(),
+        }
+    }
+}
+
+impl<T: CamiPartialEq + CamiLocalKey + Hash, S: BuildHasher> Extend<Cami<T>> for CamiHashSet<T, S> {
+    fn extend<I: IntoIterator<Item = Cami<T>>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+        for item in iter {
+            self.insert(item);
+        }
+    }
+}
+
+impl<T: CamiPartialEq + CamiLocalKey + Hash, S: BuildHasher + Default> FromIterator<Cami<T>>
+    for CamiHashSet<T, S>
+{
+    fn from_iter<I: IntoIterator<Item = Cami<T>>>(iter: I) -> Self {
+        let mut set = Self::default();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<'a, T: CamiPartialEq + CamiLocalKey, S> IntoIterator for &'a CamiHashSet<T, S> {
+    type Item = &'a Cami<T>;
+    type IntoIter = Iter<'a, T>;
+    #[inline]
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<T: CamiPartialEq + CamiLocalKey, S> IntoIterator for CamiHashSet<T, S> {
+    type Item = Cami<T>;
+    type IntoIter = IntoIter<T>;
+    #[inline]
+    fn into_iter(self) -> IntoIter<T> {
+        IntoIter {
+            inner: self.map.into_iter(),
+        }
+    }
+}
+
+/// Iterator over [CamiHashSet].
+pub struct Iter<'a, T: CamiPartialEq> {
+    inner: hash_map::Iter<'a, T, ()>,
+}
+
+impl<'a, T: CamiPartialEq> Iterator for Iter<'a, T> {
+    type Item = &'a Cami<T>;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a Cami<T>> {
+        self.inner.next().map(|(item, ())| item)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a, T: CamiPartialEq> ExactSizeIterator for Iter<'a, T> {}
+impl<'a, T: CamiPartialEq> FusedIterator for Iter<'a, T> {}
+
+impl<'a, T: CamiPartialEq> Clone for Iter<'a, T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+/// Owning iterator over [CamiHashSet].
+pub struct IntoIter<T: CamiPartialEq> {
+    inner: hash_map::IntoIter<T, ()>,
+}
+
+impl<T: CamiPartialEq> Iterator for IntoIter<T> {
+    type Item = Cami<T>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Cami<T>> {
+        self.inner.next().map(|(item, ())| item)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<T: CamiPartialEq> ExactSizeIterator for IntoIter<T> {}
+impl<T: CamiPartialEq> FusedIterator for IntoIter<T> {}
@@ -113,3 +113,19 @@ pub trait CamiOrd: Eq + CamiPartialOrd {
     #[must_use]
     fn cmp_non_local(&self, other: &Self) -> Ordering;
 }
+
+/// The local part (as per [CamiOrd::cmp_local]) as a standalone, dense key. That allows
+/// containers and algorithms to store, cache or compare local parts without touching (the rest
+/// of) the items - for example, lengths of strings.
+///
+/// It must be consistent with [CamiOrd::cmp_local] (where `Self` implements [CamiOrd]): For any `a`
+/// and `b`, `a.cmp_local(&b) == a.local_key().cmp(&b.local_key())`.
+///
+/// Unsized types like `str` and `[T]` implement this, too, so that needles don't need to be
+/// wrapped in references or owned.
+pub trait CamiLocalKey {
+    type LocalKey: Ord + Copy;
+
+    #[must_use]
+    fn local_key(&self) -> Self::LocalKey;
+}
@@ -0,0 +1,93 @@
+use cami::prelude::*;
+
+/// [U8Cami] and [F32Cami] are [Copy] (as [u8] and [F32Total] are [CamiCopy]). bytemuck's `Pod`
+/// requires that.
+#[test]
+fn numeric_cami_are_copy() {
+    fn assert_copy<T: Copy>() {}
+    assert_copy::<U8Cami>();
+    assert_copy::<F32Cami>();
+}
+
+#[cfg(feature = "adapt-bytemuck")]
+mod bytemuck_adapter {
+    use bytemuck::{TransparentWrapper, Zeroable};
+    use cami::prelude::*;
+
+    #[test]
+    fn cast_slice_of_bytes() {
+        let bytes = [3u8, 1, 255, 0, 1];
+        let wrapped: &[Cami<u8>] = bytemuck::cast_slice(&bytes);
+        let mut sorted = wrapped.to_vec();
+        sorted.sort();
+        let sorted: &[u8] = bytemuck::cast_slice(&sorted);
+        assert_eq!(sorted, [0, 1, 1, 3, 255]);
+    }
+
+    #[test]
+    fn cast_slice_of_floats() {
+        let floats = [1.5f32, -0.0, 0.0, f32::NEG_INFINITY];
+        let wrapped: &[F32Cami] = bytemuck::cast_slice(&floats);
+        let mut sorted = wrapped.to_vec();
+        sorted.sort();
+        let bits: Vec<u32> = sorted.iter().map(|f| f.into_f32().to_bits()).collect();
+        let expected: Vec<u32> = [f32::NEG_INFINITY, -0.0, 0.0, 1.5]
+            .iter()
+            .map(|f| f.to_bits())
+            .collect();
+        assert_eq!(bits, expected);
+    }
+
+    #[test]
+    fn transparent_wrapper() {
+        let bytes = [2u8, 1];
+        let wrapped = <Cami<u8> as TransparentWrapper<u8>>::wrap_slice(&bytes[..]);
+        assert!(wrapped[1] < wrapped[0]);
+        assert_eq!(
+            <Cami<u8> as TransparentWrapper<u8>>::peel_slice(wrapped),
+            [2, 1]
+        );
+
+        let mut floats = [2.0f32, -1.0];
+        let wrapped: &mut [F32Total] = F32Total::wrap_slice_mut(&mut floats[..]);
+        wrapped.swap(0, 1);
+        assert_eq!(floats, [-1.0, 2.0]);
+    }
+
+    #[test]
+    fn zeroed() {
+        assert!(<Cami<u8> as Zeroable>::zeroed() == Cami::new(0));
+        assert_eq!(<F32Cami as Zeroable>::zeroed().into_f32().to_bits(), 0);
+    }
+}
+
+#[cfg(feature = "adapt-zerocopy")]
+mod zerocopy_adapter {
+    use cami::prelude::*;
+    use zerocopy::{FromBytes, IntoBytes};
+
+    #[test]
+    fn bytes_round_trip() {
+        let bytes = [7u8, 0, 9];
+        let wrapped = <[Cami<u8>]>::ref_from_bytes(&bytes).unwrap();
+        assert_eq!(wrapped.len(), 3);
+        assert!(wrapped[1] < wrapped[0]);
+        assert_eq!(wrapped.as_bytes(), bytes);
+    }
+
+    #[test]
+    fn floats_round_trip() {
+        for value in [0.0f32, -0.0, 1.5, f32::NAN, f32::INFINITY] {
+            let wrapped = Cami::new(F32Total::new(value));
+            let bytes = wrapped.as_bytes();
+            assert_eq!(bytes, value.to_bits().to_ne_bytes());
+            let read = F32Cami::read_from_bytes(bytes).unwrap();
+            assert_eq!(read.into_f32().to_bits(), value.to_bits());
+        }
+    }
+
+    #[test]
+    fn wrong_length_is_rejected() {
+        assert!(F32Cami::read_from_bytes(&[0u8; 3]).is_err());
+    }
+}
@@ -0,0 +1,143 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+use cami::prelude::*;
+use common::{random_string, Rng};
+
+/// Strings of lengths 0..=3 over "ab": many equal strings, and many equal lengths (local keys)
+/// with different contents (non-local parts).
+fn strings(rng: &mut Rng) -> Vec<String> {
+    let len = rng.below(60);
+    (0..len)
+        .map(|_| random_string(rng, 4, &['a', 'b']))
+        .collect()
+}
+
+/// The oracle: a stable sort of the indices.
+fn expected(strings: &[String]) -> Vec<usize> {
+    let mut indices: Vec<usize> = (0..strings.len()).collect();
+    indices.sort_by(|&this, &other| cami::cmp(&strings[this], &strings[other]));
+    indices
+}
+
+#[test]
+fn against_stable_sort() {
+    let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+    for _ in 0..500 {
+        let strings = strings(&mut rng);
+        let expected = expected(&strings);
+        assert_eq!(argsort_cami(&strings), expected);
+        let permutation = argsort_cami_u32(&strings);
+        assert!(permutation
+            .iter()
+            .map(|&index| index as usize)
+            .eq(expected.iter().copied()));
+
+        let mut sorted = strings.clone();
+        apply_permutation(&mut sorted, &expected);
+        let mut sorted_u32 = strings.clone();
+        apply_permutation_u32(&mut sorted_u32, &permutation);
+        assert_eq!(sorted, sorted_u32);
+        let mut oracle = strings.clone();
+        oracle.sort_by(cami::cmp);
+        assert_eq!(sorted, oracle);
+    }
+}
+
+#[test]
+fn equal_local_keys() {
+    // The same lengths, different contents: the non-local parts decide. Equal strings keep their
+    // order.
+    let strings: Vec<String> = ["bb", "ab", "b", "ba", "ab", "a", "aa", "b"]
+        .iter()
+        .map(|s| (*s).to_owned())
+        .collect();
+    assert_eq!(argsort_cami(&strings), [5, 2, 7, 6, 1, 4, 3, 0]);
+    assert_eq!(argsort_cami_u32(&strings), [5, 2, 7, 6, 1, 4, 3, 0]);
+
+    // Pure local items.
+    assert_eq!(argsort_cami(&[3u8, 1, 3, 0, 1]), [3, 1, 4, 0, 2]);
+    assert!(argsort_cami::<u8>(&[]).is_empty());
+}
+
+#[test]
+fn parallel_columns() {
+    let names: Vec<String> = ["carol", "al", "bob", "al", "eve"]
+        .iter()
+        .map(|s| (*s).to_owned())
+        .collect();
+    let mut ages = vec![30, 40, 50, 20, 60];
+    let mut ids = vec!['c', 'a', 'b', 'd', 'e'];
+    let rows: Vec<(String, u32, char)> = names
+        .iter()
+        .cloned()
+        .zip(ages.iter().copied())
+        .zip(ids.iter().copied())
+        .map(|((name, age), id)| (name, age, id))
+        .collect();
+
+    let permutation = argsort_cami(&names);
+    let mut sorted_names = names;
+    apply_permutation(&mut sorted_names, &permutation);
+    apply_permutation(&mut ages, &permutation);
+    apply_permutation(&mut ids, &permutation);
+    assert_eq!(sorted_names, ["al", "al", "bob", "eve", "carol"]);
+    assert_eq!(ages, [40, 20, 50, 60, 30]);
+    assert_eq!(ids, ['a', 'd', 'b', 'e', 'c']);
+    // Each row stays together.
+    for ((name, age), id) in sorted_names.iter().zip(&ages).zip(&ids) {
+        assert!(rows.contains(&(name.clone(), *age, *id)));
+    }
+
+    let permutation = argsort_cami_u32(&sorted_names);
+    apply_permutation_u32(&mut ages, &permutation);
+    assert_eq!(ages, [40, 20, 50, 60, 30]);
+}
+
+#[test]
+#[should_panic(expected = "the permutation must be as long as the slice")]
+fn too_short() {
+    apply_permutation(&mut [1, 2, 3], &[0, 1]);
+}
+
+#[test]
+#[should_panic(expected = "the permutation must be as long as the slice")]
+fn too_short_u32() {
+    apply_permutation_u32(&mut [1, 2, 3], &[0, 1]);
+}
+
+#[test]
+#[should_panic(expected = "not a permutation: index 1 is out of bounds or repeated")]
+fn repeated() {
+    apply_permutation(&mut [1, 2, 3], &[1, 0, 1]);
+}
+
+#[test]
+#[should_panic(expected = "not a permutation: index 1 is out of bounds or repeated")]
+fn repeated_u32() {
+    apply_permutation_u32(&mut [1, 2, 3], &[1, 0, 1]);
+}
+
+#[test]
+#[should_panic(expected = "not a permutation: index 3 is out of bounds or repeated")]
+fn out_of_bounds() {
+    apply_permutation(&mut [1, 2, 3], &[0, 3, 1]);
+}
+
+#[test]
+#[should_panic(expected = "not a permutation: index 3 is out of bounds or repeated")]
+fn out_of_bounds_u32() {
+    apply_permutation_u32(&mut [1, 2, 3], &[0, 3, 1]);
+}
+
+#[test]
+fn invalid_permutation_leaves_the_slice() {
+    // The permutation is checked before any item is moved.
+    let mut slice = vec![1, 2, 3];
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        apply_permutation(&mut slice, &[2, 0, 2]);
+    }));
+    assert!(result.is_err());
+    assert_eq!(slice, [1, 2, 3]);
+}
@@ -0,0 +1,238 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+use cami::prelude::*;
+use common::{random_string, Rng};
+use core::ops::Bound;
+use std::collections::{BTreeMap, VecDeque};
+
+/// Short strings over a small alphabet: many keys share their length (local key), and there are
+/// only 364 distinct keys, so removals hit existing keys often.
+fn random_key(rng: &mut Rng) -> String {
+    random_string(rng, 6, &['a', 'b', 'c'])
+}
+
+fn random_bound<T>(rng: &mut Rng, value: T) -> Bound<T> {
+    match rng.below(3) {
+        0 => Bound::Included(value),
+        1 => Bound::Excluded(value),
+        _ => Bound::Unbounded,
+    }
+}
+
+fn assert_same<K: CamiOrd + CamiLocalKey, V: PartialEq + core::fmt::Debug>(
+    map: &CamiBTreeMap<K, V>,
+    oracle: &BTreeMap<Cami<K>, V>,
+) {
+    assert_eq!(map.len(), oracle.len());
+    assert_eq!(map.iter().count(), oracle.len());
+    for (index, ((key, value), (expected_key, expected_value))) in
+        map.iter().zip(oracle.iter()).enumerate()
+    {
+        assert!(key == expected_key, "key at {}", index);
+        assert_eq!(value, expected_value, "value at {}", index);
+    }
+    assert!(map.first_key_value().map(|(key, _)| key) == oracle.keys().next());
+    assert!(map.last_key_value().map(|(key, _)| key) == oracle.keys().next_back());
+}
+
+/// Grow the map, then shrink it (so that nodes get split, and later rotated and merged), with
+/// random inserts and removes throughout.
+fn run_strings(seed: u64) {
+    let mut rng = Rng(seed);
+    let mut map = CamiBTreeMap::<String, u64>::new();
+    let mut oracle = BTreeMap::<Cami<String>, u64>::new();
+    for step in 0..6000u64 {
+        let insert_per_mille = if step < 3000 { 800 } else { 200 };
+        let key = random_key(&mut rng);
+        if rng.below(1000) < insert_per_mille {
+            assert_eq!(
+                map.insert(Cami::new(key.clone()), step),
+                oracle.insert(Cami::new(key), step)
+            );
+        } else {
+            // Look up (and remove) by `&str`.
+            assert_eq!(map.get(key.as_str()), oracle.get(&Cami::new(key.clone())));
+            assert_eq!(
+                map.remove(key.as_str()),
+                oracle.remove(&Cami::new(key.clone()))
+            );
+            assert!(!map.contains_key(key.as_str()));
+        }
+        if step % 97 == 0 {
+            assert_same(&map, &oracle);
+        }
+    }
+    assert_same(&map, &oracle);
+    // Remove the rest.
+    let mut keys: VecDeque<String> = oracle.keys().map(|key| key.in_cami().clone()).collect();
+    for index in 0.. {
+        // Alternate the front, the back and the middle.
+        let key = match index % 3 {
+            0 => keys.pop_front(),
+            1 => keys.pop_back(),
+            _ => keys.remove(keys.len() / 2),
+        };
+        let key = match key {
+            Some(key) => key,
+            None => break,
+        };
+        assert_eq!(
+            map.remove(key.as_str()),
+            oracle.remove(&Cami::new(key.clone()))
+        );
+    }
+    assert_same(&map, &oracle);
+    assert!(map.is_empty());
+}
+
+#[test]
+fn strings_against_std() {
+    for seed in [0x2545_f491_4f6c_dd1d, 0x9e37_79b9_7f4a_7c15, 1] {
+        run_strings(seed);
+    }
+}
+
+#[test]
+fn integers_against_std() {
+    let mut rng = Rng(0x1234_5678_9abc_def1);
+    let mut map = CamiBTreeMap::<u32, u32>::new();
+    let mut oracle = BTreeMap::<Cami<u32>, u32>::new();
+    for round in 0..4 {
+        for _ in 0..3000 {
+            let key = rng.below(2000) as u32;
+            assert_eq!(
+                map.insert(Cami::new(key), round),
+                oracle.insert(Cami::new(key), round)
+            );
+        }
+        assert_same(&map, &oracle);
+        for _ in 0..2500 {
+            let key = rng.below(2000) as u32;
+            assert_eq!(map.get(&key), oracle.get(&Cami::new(key)));
+            assert_eq!(map.remove(&key), oracle.remove(&Cami::new(key)));
+        }
+        assert_same(&map, &oracle);
+    }
+}
+
+#[test]
+fn ranges_against_std() {
+    let mut rng = Rng(0x0bad_5eed_0bad_5eed);
+    let mut map = CamiBTreeMap::<String, usize>::new();
+    let mut oracle = BTreeMap::<Cami<String>, usize>::new();
+    for index in 0..250 {
+        let key = random_key(&mut rng);
+        map.insert(Cami::new(key.clone()), index);
+        oracle.insert(Cami::new(key), index);
+    }
+    for _ in 0..3000 {
+        let (mut start, mut end) = (random_key(&mut rng), random_key(&mut rng));
+        if Cami::new(start.as_str()) > Cami::new(end.as_str()) {
+            core::mem::swap(&mut start, &mut end);
+        }
+        let (start_bound, mut end_bound) = (
+            random_bound(&mut rng, start.as_str()),
+            random_bound(&mut rng, end.as_str()),
+        );
+        if start == end {
+            if let (Bound::Excluded(_), Bound::Excluded(end)) = (start_bound, end_bound) {
+                end_bound = Bound::Included(end);
+            }
+        }
+        let range: Vec<(&Cami<String>, &usize)> =
+            map.range::<str, _>((start_bound, end_bound)).collect();
+        let to_cami = |bound: Bound<&str>| bound.map(|key| Cami::new(key.to_owned()));
+        let expected: Vec<(&Cami<String>, &usize)> = oracle
+            .range((to_cami(start_bound), to_cami(end_bound)))
+            .collect();
+        assert!(range == expected, "{:?} {:?}", start_bound, end_bound);
+    }
+}
+
+/// Keys {1, 2, 7}: both bounds of `6..=5` fall between keys.
+#[test]
+#[should_panic(expected = "range start is greater than range end")]
+fn inverted_range_panics_between_keys() {
+    let map: CamiBTreeMap<u32, ()> = [1, 2, 7].iter().map(|&key| (Cami::new(key), ())).collect();
+    let (start, end) = (6, 5);
+    let _ = map.range(start..=end);
+}
+
+#[test]
+#[should_panic(expected = "range start and end are equal and excluded")]
+fn excluded_equal_range_panics() {
+    let map: CamiBTreeMap<u32, ()> = [1, 2, 7].iter().map(|&key| (Cami::new(key), ())).collect();
+    let _ = map.range((Bound::Excluded(2), Bound::Excluded(2)));
+}
+
+#[test]
+fn empty_ranges() {
+    let map: CamiBTreeMap<u32, ()> = (0..100).map(|key| (Cami::new(key), ())).collect();
+    assert_eq!(map.range(5..5).count(), 0);
+    assert_eq!(
+        map.range((Bound::Excluded(5), Bound::Included(5))).count(),
+        0
+    );
+    assert_eq!(map.range(200..).count(), 0);
+    assert_eq!(CamiBTreeMap::<u32, ()>::new().range(..).count(), 0);
+}
+
+#[test]
+fn entry() {
+    let mut map = CamiBTreeMap::<String, usize>::new();
+    let mut oracle = BTreeMap::<Cami<String>, usize>::new();
+    let mut rng = Rng(77);
+    for _ in 0..3000 {
+        let key = random_key(&mut rng);
+        match rng.below(3) {
+            0 => {
+                *map.entry(Cami::new(key.clone())).or_insert(0) += 1;
+                *oracle.entry(Cami::new(key)).or_insert(0) += 1;
+            }
+            1 => {
+                map.entry(Cami::new(key.clone()))
+                    .and_modify(|value| *value *= 2)
+                    .or_default();
+                oracle
+                    .entry(Cami::new(key))
+                    .and_modify(|value| *value *= 2)
+                    .or_default();
+            }
+            _ => {
+                if let cami::alloc::btree_map::Entry::Occupied(entry) =
+                    map.entry(Cami::new(key.clone()))
+                {
+                    assert!(entry.key().in_cami() == &key);
+                    assert_eq!(entry.remove(), oracle.remove(&Cami::new(key)).unwrap());
+                } else {
+                    assert!(!oracle.contains_key(&Cami::new(key)));
+                }
+            }
+        }
+    }
+    assert_same(&map, &oracle);
+}
+
+#[test]
+fn clone_and_iterators() {
+    let map: CamiBTreeMap<u32, u32> = (0..500).map(|key| (Cami::new(key), key * 2)).collect();
+    let clone = map.clone();
+    assert!(clone.keys().eq(map.keys()));
+    assert!(clone.values().copied().eq((0..500).map(|key| key * 2)));
+
+    let mut iter = map.range(10..20);
+    let cloned = iter.clone();
+    assert_eq!(iter.by_ref().count(), 10);
+    assert!(iter.next().is_none());
+    assert_eq!(cloned.count(), 10);
+}
+
+#[test]
+fn iter_is_send_and_sync() {
+    fn assert_send_sync<T: Send + Sync>(_: T) {}
+    let map: CamiBTreeMap<String, u32> = CamiBTreeMap::new();
+    assert_send_sync(map.iter());
+    assert_send_sync(&map);
+}
@@ -0,0 +1,160 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+use cami::prelude::*;
+use common::{random_string, Rng};
+use core::cmp::Ordering;
+
+#[derive(Clone, Debug)]
+struct Record {
+    name: String,
+    id: u32,
+}
+
+/// Records with names of lengths 0..=3 over "ab" (many equal names, and many equal lengths), with
+/// ids in their original order.
+fn records(rng: &mut Rng) -> Vec<Record> {
+    let len = rng.below(50) as u32;
+    (0..len)
+        .map(|id| Record {
+            name: random_string(rng, 4, &['a', 'b']),
+            id,
+        })
+        .collect()
+}
+
+fn ids(records: &[Record]) -> Vec<u32> {
+    records.iter().map(|record| record.id).collect()
+}
+
+fn names(records: &[Record]) -> Vec<&str> {
+    records.iter().map(|record| record.name.as_str()).collect()
+}
+
+#[test]
+fn sort_by_cami_key_is_stable() {
+    let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+    for _ in 0..500 {
+        let records = records(&mut rng);
+        // The oracle: a stable sort with a comparator.
+        let mut expected = records.clone();
+        expected.sort_by(cami::by_key(|record: &Record| record.name.as_str()));
+
+        let mut sorted = records.clone();
+        sorted.sort_by_cami_key(|record| record.name.as_str());
+        assert_eq!(ids(&sorted), ids(&expected));
+
+        let mut sorted = records.clone();
+        sorted.sort_by_cami_key(|record| &record.name);
+        assert_eq!(ids(&sorted), ids(&expected));
+
+        let mut sorted = records.clone();
+        sorted.sort_by_cami_key_uncached(|record| record.name.as_str());
+        assert_eq!(ids(&sorted), ids(&expected));
+
+        // Unstable: the same names, and a permutation of the records.
+        let mut sorted = records.clone();
+        sorted.sort_unstable_by_cami_key(|record| record.name.as_str());
+        assert_eq!(names(&sorted), names(&expected));
+        let mut sorted_ids = ids(&sorted);
+        sorted_ids.sort_unstable();
+        assert!(sorted_ids.into_iter().eq(0..records.len() as u32));
+    }
+}
+
+#[test]
+fn equal_keys_keep_their_order() {
+    let mut records: Vec<Record> = ["b", "a", "b", "a", "b"]
+        .iter()
+        .zip(0..)
+        .map(|(name, id)| Record {
+            name: (*name).to_owned(),
+            id,
+        })
+        .collect();
+    records.sort_by_cami_key(|record| record.name.as_str());
+    assert_eq!(ids(&records), [1, 3, 0, 2, 4]);
+}
+
+#[test]
+fn binary_search_with_str_needle() {
+    let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+    let needles = [
+        "", "a", "b", "aa", "ab", "ba", "bb", "aab", "bbb", "abab", "c", "zz",
+    ];
+    for _ in 0..200 {
+        let mut records = records(&mut rng);
+        records.sort_by_cami_key(|record| record.name.as_str());
+        for needle in needles {
+            let found = records.binary_search_by_cami_key(needle, |record| &record.name);
+            let found_str =
+                records.binary_search_by_cami_key(needle, |record| record.name.as_str());
+            // Where the needle would go, in Cami order (shorter first).
+            let position = records.partition_point(|record| {
+                (record.name.len(), record.name.as_str()) < (needle.len(), needle)
+            });
+            if records
+                .get(position)
+                .map_or(false, |record| record.name == needle)
+            {
+                let index = found.unwrap();
+                assert_eq!(records[index].name, needle);
+                assert_eq!(records[found_str.unwrap()].name, needle);
+            } else {
+                assert_eq!(found, Err(position), "{:?}", needle);
+                assert_eq!(found_str, Err(position), "{:?}", needle);
+            }
+        }
+    }
+}
+
+/// A key with no [CamiLocalKey] (a hash, say, with no cheap part to cache).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Opaque(u64);
+
+impl CamiPartialEq for Opaque {
+    const LOCALITY: Locality = Locality::PureNonLocal;
+    fn eq_local(&self, _: &Self) -> bool {
+        true
+    }
+    fn eq_non_local(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+impl CamiPartialOrd for Opaque {
+    fn partial_cmp_local(&self, _: &Self) -> Option<Ordering> {
+        Some(Ordering::Equal)
+    }
+    fn partial_cmp_non_local(&self, other: &Self) -> Option<Ordering> {
+        Some(self.0.cmp(&other.0))
+    }
+}
+impl CamiOrd for Opaque {
+    fn cmp_local(&self, _: &Self) -> Ordering {
+        Ordering::Equal
+    }
+    fn cmp_non_local(&self, other: &Self) -> Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+#[test]
+fn keys_with_no_local_key() {
+    let mut pairs: Vec<(Opaque, u32)> = [3, 1, 2, 1, 0]
+        .iter()
+        .zip(0..)
+        .map(|(&key, id)| (Opaque(key), id))
+        .collect();
+    pairs.sort_by_cami_key_uncached(|pair| &pair.0);
+    let ids: Vec<u32> = pairs.iter().map(|pair| pair.1).collect();
+    assert_eq!(ids, [4, 1, 3, 2, 0]);
+    assert_eq!(
+        pairs.binary_search_by_cami_key(&Opaque(2), |pair| &pair.0),
+        Ok(3)
+    );
+    assert_eq!(
+        pairs.binary_search_by_cami_key(&Opaque(5), |pair| &pair.0),
+        Err(5)
+    );
+}
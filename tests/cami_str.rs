@@ -0,0 +1,180 @@
+#![cfg(feature = "alloc")]
+
+use cami::prelude::*;
+use core::cmp::Ordering;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashSet;
+use std::hash::{Hash, Hasher};
+
+/// Counts allocations and live bytes per thread, so that tests running in parallel don't disturb
+/// each other.
+struct CountingAlloc;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+    static LIVE_BYTES: Cell<isize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        let _ = LIVE_BYTES.try_with(|live| live.set(live.get() + layout.size() as isize));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        let _ = LIVE_BYTES.try_with(|live| live.set(live.get() - layout.size() as isize));
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+/// Number of allocations made by `f`, and the net bytes it left allocated.
+fn allocations<R>(f: impl FnOnce() -> R) -> (usize, isize) {
+    let (allocations, live) = (ALLOCATIONS.with(Cell::get), LIVE_BYTES.with(Cell::get));
+    drop(f());
+    (
+        ALLOCATIONS.with(Cell::get) - allocations,
+        LIVE_BYTES.with(Cell::get) - live,
+    )
+}
+
+/// Lengths around the prefix (4 bytes) and the inline capacity (12 bytes), and long ones.
+const LENGTHS: [usize; 14] = [0, 1, 3, 4, 5, 11, 12, 13, 15, 16, 17, 100, 4096, 100_000];
+
+fn sample(len: usize, seed: u8) -> String {
+    (0..len)
+        .map(|i| char::from(b'a' + (i as u8).wrapping_mul(7).wrapping_add(seed) % 26))
+        .collect()
+}
+
+#[test]
+fn round_trip_at_boundaries() {
+    for len in LENGTHS.iter().copied().chain(0..=20) {
+        let string = sample(len, 0);
+        for cami_str in [
+            CamiStr::new(&string),
+            CamiStr::from(string.as_str()),
+            CamiStr::from(&string),
+            CamiStr::from(string.clone()),
+        ] {
+            assert_eq!(cami_str.as_str(), string, "{}", len);
+            assert_eq!(cami_str.as_bytes(), string.as_bytes(), "{}", len);
+            assert_eq!(cami_str.len(), len);
+            assert_eq!(cami_str.is_inline(), len <= 12, "{}", len);
+            assert_eq!(String::from(cami_str), string);
+        }
+    }
+    assert_eq!(CamiStr::default().as_str(), "");
+    assert!(CamiStr::default().is_inline());
+}
+
+#[test]
+fn multi_byte_utf8() {
+    for string in [
+        "é",
+        "ab\u{10348}",
+        "жжжжжж",
+        "ab\u{10348}ab\u{10348}ab\u{10348}",
+    ] {
+        let cami_str = CamiStr::new(string);
+        assert_eq!(&*cami_str, string);
+        assert_eq!(cami_str.chars().count(), string.chars().count());
+    }
+}
+
+#[test]
+fn inline_strings_do_not_allocate() {
+    for len in 0..=12 {
+        let string = sample(len, 1);
+        let (count, live) = allocations(|| {
+            let cami_str = CamiStr::new(&string);
+            let clone = cami_str.clone();
+            drop(cami_str);
+            assert_eq!(clone.as_str(), string);
+            clone
+        });
+        assert_eq!((count, live), (0, 0), "{}", len);
+    }
+}
+
+#[test]
+fn clone_and_drop_of_heap_strings() {
+    for len in [13, 16, 17, 100, 100_000] {
+        let string = sample(len, 2);
+        let (count, live) = allocations(|| {
+            let cami_str = CamiStr::new(&string);
+            let clone = cami_str.clone();
+            // The clone owns its own bytes.
+            assert_ne!(clone.as_ptr(), cami_str.as_ptr());
+            drop(cami_str);
+            assert_eq!(clone.as_str(), string);
+            clone
+        });
+        // One for the original, and one for the clone. All freed.
+        assert_eq!((count, live), (2, 0), "{}", len);
+
+        // `From<String>` reuses the allocation (of a string with no spare capacity).
+        let owned = string.clone().into_boxed_str().into_string();
+        let (count, live) = allocations(|| CamiStr::from(owned));
+        assert_eq!((count, live), (0, -(len as isize)), "{}", len);
+    }
+}
+
+fn hash_of<T: Hash + ?Sized>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[test]
+fn borrow_and_hash_agree_with_str() {
+    let strings: Vec<String> = LENGTHS.iter().map(|&len| sample(len, 3)).collect();
+    for string in &strings {
+        assert_eq!(hash_of(&CamiStr::new(string)), hash_of(string.as_str()));
+    }
+    let set: HashSet<CamiStr> = strings.iter().map(|string| CamiStr::new(string)).collect();
+    for string in &strings {
+        assert!(set.contains(string.as_str()));
+    }
+    assert!(!set.contains("not there"));
+}
+
+#[test]
+fn order_agrees_with_cami_string_and_str() {
+    // Same lengths with different prefixes, same prefixes with different rests, zero bytes...
+    let mut strings: Vec<String> = vec![
+        String::new(),
+        "\0".into(),
+        "\0\0\0\0\0".into(),
+        "abcd".into(),
+        "abce".into(),
+        "abcdabcdabcdX".into(),
+        "abcdabcdabcdY".into(),
+        "abceabcdabcdX".into(),
+        "b".into(),
+    ];
+    for &len in &LENGTHS[..12] {
+        for seed in 0..3 {
+            strings.push(sample(len, seed));
+        }
+    }
+    for left in &strings {
+        for right in &strings {
+            let (cami_left, cami_right) = (CamiStr::new(left), CamiStr::new(right));
+            let expected = Cami::new(left.clone()).cmp(&Cami::new(right.clone()));
+            let cami_order = Cami::new(CamiStr::new(left)).cmp(&Cami::new(CamiStr::new(right)));
+            assert_eq!(cami_order, expected, "{:?} {:?}", left, right);
+            assert_eq!(cami_left.cmp(&cami_right), left.cmp(right));
+            assert_eq!(cami_left == cami_right, left == right);
+            assert_eq!(
+                Cami::new(cami_left) == Cami::new(cami_right),
+                expected == Ordering::Equal
+            );
+        }
+    }
+}
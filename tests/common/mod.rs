@@ -0,0 +1,31 @@
+//! Shared by the integration tests (`mod common;`). Each test uses only some of it.
+#![allow(dead_code)]
+
+/// xorshift, so that the tests are deterministic. The seed must not be zero.
+pub struct Rng(pub u64);
+
+impl Rng {
+    pub fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// In `0..bound`.
+    pub fn below(&mut self, bound: u64) -> u64 {
+        self.next() % bound
+    }
+
+    /// One of `items`, which must not be empty.
+    pub fn pick<T: Copy>(&mut self, items: &[T]) -> T {
+        items[self.below(items.len() as u64) as usize]
+    }
+}
+
+/// A string shorter than `len_bound`, over `alphabet`. Short strings over a small alphabet have
+/// many equal lengths (local keys), and many equal strings.
+pub fn random_string(rng: &mut Rng, len_bound: u64, alphabet: &[char]) -> String {
+    let len = rng.below(len_bound);
+    (0..len).map(|_| rng.pick(alphabet)).collect()
+}
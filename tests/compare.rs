@@ -0,0 +1,134 @@
+#![cfg(feature = "alloc")]
+
+use cami::prelude::*;
+use core::cmp::Ordering;
+
+const WORDS: [&str; 8] = ["", "a", "b", "aa", "ab", "ba", "abc", "b\u{e9}"];
+
+#[test]
+fn free_functions_agree_with_cami() {
+    for left in WORDS {
+        for right in WORDS {
+            let expected = Cami::new(left).cmp(&Cami::new(right));
+            let (owned_left, owned_right) = (left.to_owned(), right.to_owned());
+
+            assert_eq!(cami::cmp(left, right), expected);
+            assert_eq!(cami::cmp(&owned_left, &owned_right), expected);
+            assert_eq!(cami::partial_cmp(left, right), Some(expected));
+            assert_eq!(cami::eq(left, right), expected == Ordering::Equal);
+            assert_eq!(cami::lt(left, right), expected == Ordering::Less);
+            // Different types on each side.
+            assert_eq!(cami::partial_cmp(&owned_left, right), Some(expected));
+            assert_eq!(cami::eq(&owned_left, right), expected == Ordering::Equal);
+            assert_eq!(cami::lt(&owned_left, right), expected == Ordering::Less);
+        }
+    }
+    // Shorter first, unlike `Ord for str`.
+    assert_eq!(cami::cmp("b", "aa"), Ordering::Less);
+    assert!(cami::lt("b", "aa"));
+}
+
+/// A float, where NaN is incomparable. The local part is the sign, the non-local part the value.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Float(f32);
+
+impl CamiPartialEq for Float {
+    const LOCALITY: Locality = Locality::Both;
+    fn eq_local(&self, other: &Self) -> bool {
+        self.0.is_sign_negative() == other.0.is_sign_negative()
+    }
+    fn eq_non_local(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+impl CamiPartialOrd for Float {
+    fn partial_cmp_local(&self, other: &Self) -> Option<Ordering> {
+        Some(other.0.is_sign_negative().cmp(&self.0.is_sign_negative()))
+    }
+    fn partial_cmp_non_local(&self, other: &Self) -> Option<Ordering> {
+        self.0.partial_cmp(&other.0)
+    }
+}
+
+#[test]
+fn partial_comparison() {
+    let (negative, one, two, nan) = (Float(-1.0), Float(1.0), Float(2.0), Float(f32::NAN));
+    assert_eq!(cami::partial_cmp(&one, &two), Some(Ordering::Less));
+    assert_eq!(cami::partial_cmp(&two, &negative), Some(Ordering::Greater));
+    assert!(cami::lt(&negative, &one));
+    assert!(cami::eq(&one, &one));
+    // The local parts decide, so the NaN isn't compared.
+    assert_eq!(
+        cami::partial_cmp(&Float(-f32::NAN), &one),
+        Some(Ordering::Less)
+    );
+    // Equal local parts, incomparable non-local parts.
+    assert_eq!(cami::partial_cmp(&nan, &one), None);
+    assert!(!cami::lt(&nan, &one) && !cami::lt(&one, &nan));
+    assert!(!cami::eq(&nan, &nan));
+}
+
+#[derive(Clone, Debug)]
+struct Record {
+    name: String,
+    id: u32,
+}
+
+fn records() -> Vec<Record> {
+    ["bob", "al", "eve", "al", "carol", "bob"]
+        .iter()
+        .zip(0..)
+        .map(|(name, id)| Record {
+            name: (*name).to_owned(),
+            id,
+        })
+        .collect()
+}
+
+fn ids(records: &[Record]) -> Vec<u32> {
+    records.iter().map(|record| record.id).collect()
+}
+
+/// Stable, by length first.
+const SORTED_IDS: [u32; 6] = [1, 3, 0, 5, 2, 4];
+
+#[test]
+fn by_key() {
+    let mut records = records();
+    records.sort_by(cami::by_key(|record: &Record| record.name.as_str()));
+    assert_eq!(ids(&records), SORTED_IDS);
+
+    // A `&String` key compares as a `String` (in Cami order), not by `Ord for String`.
+    let mut records = self::records();
+    records.sort_by(cami::by_key(|record: &Record| &record.name));
+    assert_eq!(ids(&records), SORTED_IDS);
+
+    // Or by turbofish, rather than on the closure parameter.
+    let mut records = self::records();
+    records.sort_by(cami::by_key::<Record, _, _>(|record| record.name.as_str()));
+    assert_eq!(ids(&records), SORTED_IDS);
+
+    let by_name = cami::by_key(|record: &Record| record.name.as_str());
+    assert_eq!(by_name(&records[0], &records[1]), Ordering::Equal);
+    assert_eq!(by_name(&records[1], &records[2]), Ordering::Less);
+    assert_eq!(
+        records.iter().max_by(|a, b| by_name(a, b)).unwrap().name,
+        "carol"
+    );
+}
+
+#[test]
+fn slice_methods_need_no_annotation() {
+    let mut records = records();
+    records.sort_by_cami_key_uncached(|record| record.name.as_str());
+    assert_eq!(ids(&records), SORTED_IDS);
+
+    let mut records = self::records();
+    records.sort_by_cami_key_uncached(|record| &record.name);
+    assert_eq!(ids(&records), SORTED_IDS);
+
+    let mut records = self::records();
+    records.sort_unstable_by_cami_key_uncached(|record| record.name.as_str());
+    let names: Vec<&str> = records.iter().map(|record| record.name.as_str()).collect();
+    assert_eq!(names, ["al", "al", "bob", "bob", "eve", "carol"]);
+}
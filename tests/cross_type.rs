@@ -0,0 +1,135 @@
+#![cfg(feature = "alloc")]
+
+use cami::prelude::*;
+use core::cmp::Ordering;
+
+const WORDS: [&str; 8] = ["", "a", "b", "aa", "ab", "ba", "abc", "b\u{e9}"];
+
+#[test]
+fn string_str_and_boxed_str() {
+    for left in WORDS {
+        for right in WORDS {
+            let expected = Cami::new(left).cmp(&Cami::new(right));
+            let owned = Cami::new(left.to_owned());
+            let boxed = Cami::new(Box::<str>::from(left));
+            let borrowed = Cami::new(right);
+
+            assert_eq!(owned == borrowed, expected == Ordering::Equal);
+            assert_eq!(borrowed == owned, expected == Ordering::Equal);
+            assert_eq!(
+                boxed == Cami::new(right.to_owned()),
+                expected == Ordering::Equal
+            );
+            assert_eq!(owned.partial_cmp(&borrowed), Some(expected));
+            assert_eq!(
+                owned.partial_cmp(&Cami::new(Box::<str>::from(right))),
+                Some(expected)
+            );
+            assert_eq!(
+                boxed.partial_cmp(&Cami::new(right.to_owned())),
+                Some(expected)
+            );
+            assert_eq!(
+                borrowed.partial_cmp(&Cami::new(left.to_owned())),
+                Some(expected.reverse())
+            );
+            assert_eq!(owned < borrowed, expected == Ordering::Less);
+            assert_eq!(owned <= borrowed, expected != Ordering::Greater);
+            assert_eq!(owned > borrowed, expected == Ordering::Greater);
+            assert_eq!(owned >= borrowed, expected != Ordering::Less);
+        }
+    }
+}
+
+#[test]
+fn vec_and_slice() {
+    let samples: [&[u8]; 6] = [&[], &[0], &[1], &[0, 0], &[0, 1], &[1, 0]];
+    for left in samples {
+        for right in samples {
+            let expected = Cami::new(left).cmp(&Cami::new(right));
+            let owned = Cami::new(left.to_vec());
+            let borrowed = Cami::new(right);
+            assert_eq!(owned == borrowed, expected == Ordering::Equal);
+            assert_eq!(
+                borrowed == Cami::new(left.to_vec()),
+                expected == Ordering::Equal
+            );
+            assert_eq!(owned.partial_cmp(&borrowed), Some(expected));
+            assert_eq!(owned < borrowed, expected == Ordering::Less);
+        }
+    }
+}
+
+fn sorted_names() -> Vec<Cami<String>> {
+    let mut names: Vec<Cami<String>> = WORDS
+        .iter()
+        .map(|&word| Cami::new(word.to_owned()))
+        .collect();
+    names.sort();
+    names
+}
+
+#[test]
+fn search_strings_with_str_needles() {
+    let names = sorted_names();
+    for (index, name) in names.iter().enumerate() {
+        let needle: &str = name.in_cami();
+        assert_eq!(names.binary_search_cami(needle), Ok(index));
+        assert_eq!(names.binary_search_cami(&needle), Ok(index));
+        assert!(names.contains_cami(needle));
+        assert_eq!(names.position_cami(needle), Some(index));
+    }
+    // Missing needles: their insertion points are the same as for owned needles.
+    for missing in ["c", "bb", "zz", "abcd", "\u{e9}"] {
+        let expected = names.binary_search(&Cami::new(missing.to_owned()));
+        assert!(expected.is_err());
+        assert_eq!(names.binary_search_cami(missing), expected);
+        assert!(!names.contains_cami(missing));
+        assert_eq!(names.position_cami(missing), None);
+    }
+}
+
+#[test]
+fn search_vecs_with_slice_needles() {
+    let mut rows: Vec<Cami<Vec<u8>>> = vec![
+        Cami::new(vec![2]),
+        Cami::new(vec![]),
+        Cami::new(vec![1, 0]),
+        Cami::new(vec![0, 1, 2]),
+    ];
+    rows.sort();
+    let needle: &[u8] = &[1, 0];
+    assert_eq!(rows.binary_search_cami(needle), Ok(2));
+    assert_eq!(rows.position_cami(needle), Some(2));
+    assert!(!rows.contains_cami(&[0, 1][..]));
+    assert_eq!(rows.binary_search_cami(&[0, 1][..]), Err(2));
+}
+
+/// Patterns that inferred types with the (earlier) same-type-only impls still do, with the
+/// impls generic over the right hand side.
+#[test]
+fn type_inference_of_existing_patterns() {
+    let byte = Cami::new(1u8);
+    assert!(byte == Cami::new(1));
+    assert!(byte < Cami::new(2));
+
+    let name = Cami::new(String::from("a"));
+    assert!(name == Cami::new("a".to_owned()));
+    assert!(name == Cami::new(String::new()) || name != Cami::new(format!("{}", 'b')));
+
+    let bytes = Cami::new(vec![1u8]);
+    assert!(bytes == Cami::new(vec![1]));
+
+    let names = sorted_names();
+    assert!(names.contains(&Cami::new("ab".to_owned())));
+    assert!(names
+        .iter()
+        .any(|name| *name == Cami::new(String::from("b"))));
+    assert!(names.iter().max().is_some());
+    assert_eq!(names.iter().position(|name| name == &names[2]), Some(2));
+
+    let mut words: Vec<_> = WORDS.iter().copied().map(Cami::new).collect();
+    words.sort();
+    words.dedup();
+    assert_eq!(words.len(), WORDS.len());
+}
@@ -0,0 +1,128 @@
+use cami::prelude::*;
+use cami::{explain_cmp, CmpDecidedBy, Locality};
+use core::cmp::Ordering;
+
+/// A type with a non-local part only. Intentionally NOT [Debug].
+#[derive(PartialEq, Eq)]
+struct NonLocalOnly(u32);
+
+impl CamiPartialEq for NonLocalOnly {
+    const LOCALITY: Locality = Locality::PureNonLocal;
+    fn eq_local(&self, _other: &Self) -> bool {
+        unreachable!()
+    }
+    fn eq_non_local(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+impl CamiPartialOrd for NonLocalOnly {
+    fn partial_cmp_local(&self, _other: &Self) -> Option<Ordering> {
+        unreachable!()
+    }
+    fn partial_cmp_non_local(&self, other: &Self) -> Option<Ordering> {
+        self.0.partial_cmp(&other.0)
+    }
+}
+impl CamiOrd for NonLocalOnly {
+    fn cmp_local(&self, _other: &Self) -> Ordering {
+        unreachable!()
+    }
+    fn cmp_non_local(&self, other: &Self) -> Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+#[test]
+fn both_localities() {
+    let (b, aa, ab) = (Cami::new("b"), Cami::new("aa"), Cami::new("ab"));
+
+    let explanation = explain_cmp(&b, &aa);
+    assert_eq!(explanation.decided_by(), CmpDecidedBy::Local);
+    assert_eq!(explanation.local(), Some(Ordering::Less));
+    assert_eq!(explanation.non_local(), None);
+    assert_eq!(explanation.ordering(), Ordering::Less);
+
+    let explanation = explain_cmp(&ab, &aa);
+    assert_eq!(explanation.decided_by(), CmpDecidedBy::NonLocal);
+    assert_eq!(explanation.local(), Some(Ordering::Equal));
+    assert_eq!(explanation.non_local(), Some(Ordering::Greater));
+    assert_eq!(explanation.ordering(), Ordering::Greater);
+
+    let explanation = explain_cmp(&aa, &aa);
+    assert_eq!(explanation.decided_by(), CmpDecidedBy::Tie);
+    assert_eq!(explanation.local(), Some(Ordering::Equal));
+    assert_eq!(explanation.non_local(), Some(Ordering::Equal));
+    assert_eq!(explanation.ordering(), Ordering::Equal);
+}
+
+#[test]
+fn pure_local() {
+    let (one, two) = (Cami::new(1u8), Cami::new(2u8));
+
+    let explanation = explain_cmp(&two, &one);
+    assert_eq!(explanation.decided_by(), CmpDecidedBy::Local);
+    assert_eq!(explanation.local(), Some(Ordering::Greater));
+    assert_eq!(explanation.non_local(), None);
+
+    let explanation = explain_cmp(&one, &one);
+    assert_eq!(explanation.decided_by(), CmpDecidedBy::Tie);
+    assert_eq!(explanation.local(), Some(Ordering::Equal));
+    assert_eq!(explanation.non_local(), None);
+    assert_eq!(explanation.ordering(), Ordering::Equal);
+}
+
+#[test]
+fn pure_non_local() {
+    let (one, two) = (Cami::new(NonLocalOnly(1)), Cami::new(NonLocalOnly(2)));
+
+    let explanation = explain_cmp(&one, &two);
+    assert_eq!(explanation.decided_by(), CmpDecidedBy::NonLocal);
+    assert_eq!(explanation.local(), None);
+    assert_eq!(explanation.non_local(), Some(Ordering::Less));
+    assert_eq!(explanation.ordering(), Ordering::Less);
+
+    let explanation = explain_cmp(&two, &two);
+    assert_eq!(explanation.decided_by(), CmpDecidedBy::Tie);
+    assert_eq!(explanation.local(), None);
+    assert_eq!(explanation.non_local(), Some(Ordering::Equal));
+}
+
+#[test]
+fn ordering_agrees_with_cmp() {
+    let words = ["", "a", "b", "aa", "ab", "ba", "abc"];
+    for left in words {
+        for right in words {
+            let (left, right) = (Cami::new(left), Cami::new(right));
+            assert_eq!(explain_cmp(&left, &right).ordering(), left.cmp(&right));
+        }
+    }
+}
+
+/// Without `debug` feature, Debug (with no values) is available for any `T`.
+#[cfg(not(feature = "debug"))]
+#[test]
+fn debug_without_values() {
+    let (one, two) = (Cami::new(NonLocalOnly(1)), Cami::new(NonLocalOnly(2)));
+    let rendered = format!("{:?}", explain_cmp(&one, &two));
+    assert!(rendered.contains("NonLocal"));
+}
+
+#[cfg(feature = "debug")]
+#[test]
+fn debug_with_values() {
+    let (b, aa) = (Cami::new("b"), Cami::new("aa"));
+    let rendered = format!("{:?}", explain_cmp(&b, &aa));
+    assert!(rendered.contains(r#"left: "b""#), "{}", rendered);
+    assert!(rendered.contains(r#"right: "aa""#), "{}", rendered);
+    assert!(rendered.contains("decided_by: Local"), "{}", rendered);
+}
+
+#[cfg(feature = "debug")]
+#[test]
+fn display_with_values() {
+    let (b, aa) = (Cami::new("b"), Cami::new("aa"));
+    assert_eq!(
+        explain_cmp(&b, &aa).to_string(),
+        r#""b" Less "aa": decided by cmp_local (Less)"#
+    );
+}
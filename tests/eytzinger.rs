@@ -0,0 +1,65 @@
+#![cfg(feature = "alloc")]
+
+use cami::prelude::*;
+
+/// Check every needle (present or not) against [slice::partition_point] over the sorted items.
+fn check<T: CamiOrd + Clone>(items: Vec<Cami<T>>, needles: &[Cami<T>]) {
+    let mut sorted = items.clone();
+    sorted.sort();
+    let eytzinger = CamiEytzinger::from_vec(items);
+    assert_eq!(eytzinger.len(), sorted.len());
+    assert!(eytzinger.iter().eq(sorted.iter()));
+    assert_eq!(eytzinger.iter().len(), sorted.len());
+
+    for needle in needles {
+        let position = sorted.partition_point(|item| item < needle);
+        let expected = sorted.get(position);
+        let found = eytzinger.lower_bound(needle);
+        assert_eq!(found.is_some(), expected.is_some());
+        if let (Some(found), Some(expected)) = (found, expected) {
+            assert!(found == expected);
+        }
+        if let Some(index) = eytzinger.lower_bound_index(needle) {
+            assert!(std::ptr::eq(
+                &eytzinger.as_eytzinger_slice()[index],
+                found.unwrap()
+            ));
+        }
+        let present = expected == Some(needle);
+        assert_eq!(eytzinger.contains(needle), present);
+        assert_eq!(eytzinger.get(needle).is_some(), present);
+    }
+    assert!(eytzinger.into_sorted_vec() == sorted);
+}
+
+#[test]
+fn every_length_and_needle() {
+    for len in 0..=70u32 {
+        // Odd needles fall between the (even) items.
+        let items: Vec<Cami<u32>> = (0..len).rev().map(|i| Cami::new(2 * i)).collect();
+        let needles: Vec<Cami<u32>> = (0..=2 * len + 1).map(Cami::new).collect();
+        check(items, &needles);
+    }
+}
+
+#[test]
+fn every_length_with_duplicates() {
+    for len in 0..=70u32 {
+        let items: Vec<Cami<u32>> = (0..len).map(|i| Cami::new(i / 3 * 2)).collect();
+        let needles: Vec<Cami<u32>> = (0..=len + 1).map(Cami::new).collect();
+        check(items, &needles);
+    }
+}
+
+#[test]
+fn strings_in_cami_order() {
+    let words: Vec<String> = (0..70u32)
+        .map(|i| "ab".repeat((i % 4) as usize) + &char::from(b'a' + (i % 7) as u8).to_string())
+        .collect();
+    for len in 0..=words.len() {
+        let items: Vec<Cami<&str>> = words[..len].iter().map(|w| Cami::new(w.as_str())).collect();
+        let mut needles: Vec<Cami<&str>> = words.iter().map(|w| Cami::new(w.as_str())).collect();
+        needles.extend(["", "z", "zz", "abababz"].iter().copied().map(Cami::new));
+        check(items, &needles);
+    }
+}
@@ -0,0 +1,164 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+use cami::prelude::*;
+use common::{random_string, Rng};
+use core::cmp::Ordering;
+
+/// Sorted (in [Cami] order) strings of lengths 0..=3 over "ab", with many duplicates.
+fn sorted_strings(rng: &mut Rng) -> Vec<Cami<String>> {
+    let len = rng.below(60);
+    let mut strings: Vec<Cami<String>> = (0..len)
+        .map(|_| Cami::new(random_string(rng, 4, &['a', 'b'])))
+        .collect();
+    strings.sort();
+    strings
+}
+
+/// Expected runs (as strings), where `key` is the same within a run.
+fn runs<K: PartialEq>(slice: &[Cami<String>], key: impl Fn(&str) -> K) -> Vec<Vec<&str>> {
+    let mut runs: Vec<Vec<&str>> = Vec::new();
+    for item in slice {
+        let item = item.in_cami().as_str();
+        match runs.last_mut() {
+            Some(run) if key(run[0]) == key(item) => run.push(item),
+            _ => runs.push(vec![item]),
+        }
+    }
+    runs
+}
+
+fn strs(run: &[Cami<String>]) -> Vec<&str> {
+    run.iter().map(|item| item.in_cami().as_str()).collect()
+}
+
+/// Alternate [Iterator::next] and [DoubleEndedIterator::next_back], and put the runs in order.
+fn from_both_ends<'a>(
+    mut groups: impl DoubleEndedIterator<Item = &'a [Cami<String>]>,
+) -> Vec<Vec<&'a str>> {
+    let (mut front, mut back) = (Vec::new(), Vec::new());
+    while let Some(run) = groups.next() {
+        front.push(strs(run));
+        match groups.next_back() {
+            Some(run) => back.push(strs(run)),
+            None => break,
+        }
+    }
+    assert!(groups.next().is_none() && groups.next_back().is_none());
+    front.extend(back.into_iter().rev());
+    front
+}
+
+#[test]
+fn forward_and_backward() {
+    let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+    for _ in 0..300 {
+        let strings = sorted_strings(&mut rng);
+        let by_local = runs(&strings, str::len);
+        let by_cami = runs(&strings, str::to_owned);
+
+        let forward: Vec<Vec<&str>> = strings.group_by_local().map(strs).collect();
+        assert_eq!(forward, by_local);
+        let mut backward: Vec<Vec<&str>> = strings.group_by_local().rev().map(strs).collect();
+        backward.reverse();
+        assert_eq!(backward, by_local);
+        assert_eq!(from_both_ends(strings.group_by_local()), by_local);
+
+        let forward: Vec<Vec<&str>> = strings.group_by_cami().map(strs).collect();
+        assert_eq!(forward, by_cami);
+        let mut backward: Vec<Vec<&str>> = strings.group_by_cami().rev().map(strs).collect();
+        backward.reverse();
+        assert_eq!(backward, by_cami);
+        assert_eq!(from_both_ends(strings.group_by_cami()), by_cami);
+
+        // Splitting runs of equal local parts further gives the same runs.
+        let nested: Vec<Vec<&str>> = strings
+            .group_by_local()
+            .flat_map(|run| run.group_by_cami().map(strs))
+            .collect();
+        assert_eq!(nested, by_cami);
+
+        let count = strings.group_by_cami().count();
+        let (lower, upper) = strings.group_by_cami().size_hint();
+        assert!(lower <= count && count <= upper.unwrap());
+
+        let mut deduped = strings.clone();
+        deduped.dedup_cami();
+        assert_eq!(
+            strs(&deduped),
+            by_cami.iter().map(|run| run[0]).collect::<Vec<_>>()
+        );
+        let mut deduped = strings.clone();
+        deduped.dedup_by_local();
+        assert_eq!(
+            strs(&deduped),
+            by_local.iter().map(|run| run[0]).collect::<Vec<_>>()
+        );
+    }
+}
+
+#[test]
+fn empty() {
+    let empty: &[Cami<String>] = &[];
+    assert!(empty.group_by_local().next().is_none());
+    assert!(empty.group_by_cami().next_back().is_none());
+    assert_eq!(empty.group_by_cami().size_hint(), (0, Some(0)));
+}
+
+/// No local part. Its `eq_local` panics, so that the tests show it's not called.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct NoLocal(u8);
+
+impl CamiPartialEq for NoLocal {
+    const LOCALITY: Locality = Locality::PureNonLocal;
+    fn eq_local(&self, _: &Self) -> bool {
+        unreachable!("eq_local of a type with no local part")
+    }
+    fn eq_non_local(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+impl CamiPartialOrd for NoLocal {
+    fn partial_cmp_local(&self, _: &Self) -> Option<Ordering> {
+        unreachable!("partial_cmp_local of a type with no local part")
+    }
+    fn partial_cmp_non_local(&self, other: &Self) -> Option<Ordering> {
+        Some(self.0.cmp(&other.0))
+    }
+}
+
+fn no_locals(items: &[u8]) -> Vec<Cami<NoLocal>> {
+    items.iter().map(|&item| Cami::new(NoLocal(item))).collect()
+}
+
+fn unwrapped(items: &[Cami<NoLocal>]) -> Vec<u8> {
+    items.iter().map(|item| item.in_cami().0).collect()
+}
+
+#[test]
+fn no_local_part() {
+    let items = no_locals(&[1, 1, 2, 3, 3, 3]);
+    // All local parts are "equal", so the whole slice is one run.
+    let runs: Vec<Vec<u8>> = items.group_by_local().map(unwrapped).collect();
+    assert_eq!(runs, [[1, 1, 2, 3, 3, 3]]);
+    let runs: Vec<Vec<u8>> = items.group_by_local().rev().map(unwrapped).collect();
+    assert_eq!(runs, [[1, 1, 2, 3, 3, 3]]);
+
+    let runs: Vec<Vec<u8>> = items.group_by_cami().map(unwrapped).collect();
+    assert_eq!(runs, [vec![1, 1], vec![2], vec![3, 3, 3]]);
+    let runs: Vec<Vec<u8>> = items.group_by_cami().rev().map(unwrapped).collect();
+    assert_eq!(runs, [vec![3, 3, 3], vec![2], vec![1, 1]]);
+
+    let mut deduped = items.clone();
+    deduped.dedup_cami();
+    assert_eq!(unwrapped(&deduped), [1, 2, 3]);
+    // One run: only the first item stays.
+    let mut deduped = items;
+    deduped.dedup_by_local();
+    assert_eq!(unwrapped(&deduped), [1]);
+
+    let mut empty = no_locals(&[]);
+    empty.dedup_by_local();
+    assert!(empty.is_empty());
+}
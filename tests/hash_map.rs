@@ -0,0 +1,282 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use cami::prelude::*;
+use cami::std::hash_map::{CamiHashStats, Entry};
+use common::{random_string, Rng};
+use std::collections::HashMap;
+use std::hash::{BuildHasher, Hasher};
+
+/// Hashes to the first byte written, in the top 8 bits. So the hash bits (tag) of a `String` are
+/// its first byte, and every probe sequence starts at slot 0. That makes the stats predictable.
+#[derive(Clone, Default)]
+struct FirstByte;
+
+struct FirstByteHasher(Option<u8>);
+
+impl Hasher for FirstByteHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        if self.0.is_none() {
+            self.0 = bytes.first().copied();
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        u64::from(self.0.unwrap_or(0)) << 56
+    }
+}
+
+impl BuildHasher for FirstByte {
+    type Hasher = FirstByteHasher;
+    fn build_hasher(&self) -> FirstByteHasher {
+        FirstByteHasher(None)
+    }
+}
+
+fn assert_same(map: &CamiHashMap<String, u64>, oracle: &HashMap<String, u64>) {
+    assert_eq!(map.len(), oracle.len());
+    assert_eq!(map.iter().count(), oracle.len());
+    for (key, value) in map {
+        assert_eq!(oracle.get(key.in_cami()), Some(value));
+    }
+}
+
+#[test]
+fn against_std() {
+    let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+    let mut map = CamiHashMap::<String, u64>::new();
+    let mut oracle = HashMap::<String, u64>::new();
+    for step in 0..20_000 {
+        let key = random_string(&mut rng, 5, &['a', 'b', 'c', 'd']);
+        match rng.below(4) {
+            0 | 1 => assert_eq!(
+                map.insert(Cami::new(key.clone()), step),
+                oracle.insert(key, step)
+            ),
+            2 => assert_eq!(map.remove(key.as_str()), oracle.remove(&key)),
+            _ => {
+                assert_eq!(map.get(key.as_str()), oracle.get(&key));
+                assert_eq!(map.contains_key(key.as_str()), oracle.contains_key(&key));
+            }
+        }
+        if step % 1000 == 0 {
+            assert_same(&map, &oracle);
+        }
+    }
+    assert_same(&map, &oracle);
+}
+
+#[test]
+fn str_lookups_on_string_keys() {
+    let mut map: CamiHashMap<String, u32> = ["", "a", "ab", "ba", "abc"]
+        .iter()
+        .enumerate()
+        .map(|(index, key)| (Cami::new((*key).to_owned()), index as u32))
+        .collect();
+    assert_eq!(map.get(""), Some(&0));
+    assert_eq!(map.get("ba"), Some(&3));
+    assert_eq!(map.get("bb"), None);
+    assert!(map.get_key_value("abc").unwrap().0.in_cami() == "abc");
+    *map.get_mut("ab").unwrap() += 10;
+    assert_eq!(map.get("ab"), Some(&12));
+    assert!(map.contains_key("a"));
+    assert_eq!(
+        map.remove_entry("a")
+            .map(|(key, value)| (key.from_cami(), value)),
+        Some(("a".to_owned(), 1))
+    );
+    assert!(!map.contains_key("a"));
+    assert_eq!(map.remove("a"), None);
+    assert_eq!(map.len(), 4);
+}
+
+#[test]
+fn insertion_order_without_removals() {
+    let keys = ["d", "a", "c", "b", "aa"];
+    let map: CamiHashMap<&str, usize> = keys
+        .iter()
+        .enumerate()
+        .map(|(index, key)| (Cami::new(*key), index))
+        .collect();
+    assert!(map
+        .keys()
+        .map(|key| *key.in_cami())
+        .eq(keys.iter().copied()));
+    assert!(map.values().copied().eq(0..keys.len()));
+}
+
+#[test]
+fn tombstones_are_reused() {
+    let mut map = CamiHashMap::<String, (), FirstByte>::with_hasher(FirstByte);
+    // All in the same probe sequence: "a" in slot 0, "b" in slot 1.
+    map.insert(Cami::new("a".to_owned()), ());
+    map.insert(Cami::new("b".to_owned()), ());
+    map.remove("a");
+    // "c" takes the tombstone in slot 0.
+    map.insert(Cami::new("c".to_owned()), ());
+    map.reset_stats();
+    assert!(map.contains_key("b"));
+    // Slot 0 was occupied (by "c"), rather than a tombstone (which isn't counted).
+    assert_eq!(map.stats().probed, 2);
+    assert_eq!(map.stats().rejected_by_hash, 1);
+}
+
+#[test]
+fn rehash_after_many_removes() {
+    let mut map = CamiHashMap::<u32, u32>::with_capacity(100);
+    let capacity = map.capacity();
+    assert!(capacity >= 100);
+    for key in 0..50 {
+        map.insert(Cami::new(key), key);
+    }
+    // Tombstones pile up, until a rehash (of the same size) drops them.
+    for key in 50..10_000 {
+        map.insert(Cami::new(key), key);
+        assert_eq!(map.remove(&(key - 50)), Some(key - 50));
+    }
+    assert_eq!(map.capacity(), capacity);
+    assert_eq!(map.len(), 50);
+    for key in 0..10_000 {
+        assert_eq!(map.get(&key), Some(&key).filter(|_| key >= 9950));
+    }
+}
+
+#[test]
+fn retain() {
+    let mut map: CamiHashMap<u32, u32> = (0..1000).map(|key| (Cami::new(key), key * 3)).collect();
+    let mut oracle: HashMap<u32, u32> = (0..1000).map(|key| (key, key * 3)).collect();
+    map.retain(|key, value| {
+        *value += 1;
+        key.in_cami() % 3 != 0
+    });
+    oracle.retain(|key, value| {
+        *value += 1;
+        key % 3 != 0
+    });
+    assert_eq!(map.len(), oracle.len());
+    for (key, value) in &map {
+        assert_eq!(oracle.get(key.in_cami()), Some(value));
+    }
+    for key in 0..1000 {
+        assert_eq!(map.get(&key), oracle.get(&key));
+    }
+    map.retain(|_, _| false);
+    assert!(map.is_empty());
+    assert_eq!(map.get(&1), None);
+}
+
+#[test]
+fn entry_api() {
+    let mut map = CamiHashMap::<String, u32>::new();
+    for word in ["ab", "b", "ab", "cd", "ab", "b"] {
+        *map.entry(Cami::new(word.to_owned())).or_insert(0) += 1;
+    }
+    assert_eq!(map.get("ab"), Some(&3));
+    assert_eq!(map.get("b"), Some(&2));
+    assert_eq!(map.get("cd"), Some(&1));
+
+    map.entry(Cami::new("cd".to_owned()))
+        .and_modify(|count| *count *= 10)
+        .or_default();
+    map.entry(Cami::new("ef".to_owned()))
+        .and_modify(|count| *count *= 10)
+        .or_default();
+    assert_eq!(map.get("cd"), Some(&10));
+    assert_eq!(map.get("ef"), Some(&0));
+
+    match map.entry(Cami::new("b".to_owned())) {
+        Entry::Occupied(mut entry) => {
+            assert!(entry.key().in_cami() == "b");
+            assert_eq!(entry.insert(7), 2);
+            assert_eq!(*entry.get(), 7);
+            assert_eq!(entry.remove(), 7);
+        }
+        Entry::Vacant(_) => panic!("expected an occupied entry"),
+    }
+    match map.entry(Cami::new("b".to_owned())) {
+        Entry::Vacant(entry) => {
+            assert!(entry.key().in_cami() == "b");
+            *entry.insert(5) += 1;
+        }
+        Entry::Occupied(_) => panic!("expected a vacant entry"),
+    }
+    assert_eq!(map.get("b"), Some(&6));
+    assert_eq!(map.len(), 4);
+}
+
+/// Keys "a", "ab", "b": tags 'a', 'a', 'b'; local keys (lengths) 1, 2, 1; slots 0, 1, 2.
+fn stats_map() -> CamiHashMap<String, (), FirstByte> {
+    let mut map = CamiHashMap::with_hasher(FirstByte);
+    for key in ["a", "ab", "b"] {
+        map.insert(Cami::new(key.to_owned()), ());
+    }
+    map.reset_stats();
+    map
+}
+
+#[test]
+fn stats() {
+    let mut map = stats_map();
+    assert_eq!(map.stats(), CamiHashStats::default());
+
+    // "b": two different tags, and then a match.
+    assert!(map.contains_key("b"));
+    assert_eq!(
+        map.stats(),
+        CamiHashStats {
+            probed: 3,
+            rejected_by_hash: 2,
+            rejected_by_local: 0,
+            non_local_comparisons: 1,
+        }
+    );
+    assert_eq!(map.stats().dereferences_avoided(), 0);
+
+    // "ax": "a" has the same tag but a different length (no dereference), "ab" has the same tag
+    // and length (a dereference, no match), "b" has a different tag. Then an empty slot.
+    map.reset_stats();
+    assert!(!map.contains_key("ax"));
+    assert_eq!(
+        map.stats(),
+        CamiHashStats {
+            probed: 3,
+            rejected_by_hash: 1,
+            rejected_by_local: 1,
+            non_local_comparisons: 1,
+        }
+    );
+    assert_eq!(map.stats().dereferences_avoided(), 1);
+
+    // "abc": same tag as "a" and "ab", but a different length from both.
+    map.reset_stats();
+    assert!(!map.contains_key("abc"));
+    assert_eq!(map.stats().rejected_by_local, 2);
+    assert_eq!(map.stats().non_local_comparisons, 0);
+    assert_eq!(map.stats().dereferences_avoided(), 2);
+
+    // Counters accumulate until reset, and clones start from zero.
+    assert!(map.contains_key("b"));
+    assert_eq!(map.stats().probed, 6);
+    assert_eq!(map.clone().stats(), CamiHashStats::default());
+}
+
+#[test]
+fn stats_of_pure_local_keys() {
+    // `u8` has no non-local part: a matching local key is a match, with no dereference.
+    let mut map = CamiHashMap::<u8, (), FirstByte>::with_hasher(FirstByte);
+    for key in [1, 2, 3] {
+        map.insert(Cami::new(key), ());
+    }
+    map.reset_stats();
+    assert!(map.contains_key(&3));
+    assert_eq!(
+        map.stats(),
+        CamiHashStats {
+            probed: 3,
+            rejected_by_hash: 2,
+            rejected_by_local: 0,
+            non_local_comparisons: 0,
+        }
+    );
+}
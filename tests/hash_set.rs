@@ -0,0 +1,113 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use cami::prelude::*;
+use cami::std::hash_map::CamiHashStats;
+use common::{random_string, Rng};
+use std::collections::HashSet;
+use std::hash::{BuildHasher, Hasher};
+
+/// Hashes to the first byte written, in the top 8 bits (like in `tests/hash_map.rs`).
+#[derive(Clone, Default)]
+struct FirstByte;
+
+struct FirstByteHasher(Option<u8>);
+
+impl Hasher for FirstByteHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        if self.0.is_none() {
+            self.0 = bytes.first().copied();
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        u64::from(self.0.unwrap_or(0)) << 56
+    }
+}
+
+impl BuildHasher for FirstByte {
+    type Hasher = FirstByteHasher;
+    fn build_hasher(&self) -> FirstByteHasher {
+        FirstByteHasher(None)
+    }
+}
+
+#[test]
+fn against_std() {
+    let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+    let mut set = CamiHashSet::<String>::new();
+    let mut oracle = HashSet::<String>::new();
+    for _ in 0..20_000 {
+        let item = random_string(&mut rng, 5, &['a', 'b', 'c']);
+        match rng.below(3) {
+            0 => assert_eq!(set.insert(Cami::new(item.clone())), oracle.insert(item)),
+            1 => assert_eq!(set.remove(item.as_str()), oracle.remove(&item)),
+            _ => assert_eq!(set.contains(item.as_str()), oracle.contains(&item)),
+        }
+    }
+    assert_eq!(set.len(), oracle.len());
+    assert!(set.iter().all(|item| oracle.contains(item.in_cami())));
+}
+
+#[test]
+fn str_lookups_on_string_items() {
+    let mut set: CamiHashSet<String> = ["a", "bb", "ab"]
+        .iter()
+        .map(|item| Cami::new((*item).to_owned()))
+        .collect();
+    assert!(set.contains("ab"));
+    assert!(!set.contains("ba"));
+    assert!(set.get("bb").unwrap().in_cami() == "bb");
+    assert!(!set.insert(Cami::new("a".to_owned())));
+    assert_eq!(set.len(), 3);
+
+    assert!(set.remove("a"));
+    assert!(!set.remove("a"));
+    assert!(set.take("bb").unwrap().in_cami() == "bb");
+    assert!(set.take("bb").is_none());
+    assert_eq!(set.len(), 1);
+}
+
+#[test]
+fn replace() {
+    let mut set = CamiHashSet::<String>::new();
+    assert!(set.replace(Cami::new("ab".to_owned())).is_none());
+    let old = set.replace(Cami::new("ab".to_owned())).unwrap();
+    assert!(old.in_cami() == "ab");
+    assert_eq!(set.len(), 1);
+}
+
+#[test]
+fn retain() {
+    let mut set: CamiHashSet<u32> = (0..500).map(Cami::new).collect();
+    set.retain(|item| item.in_cami() % 5 == 0);
+    assert_eq!(set.len(), 100);
+    for item in 0..500 {
+        assert_eq!(set.contains(&item), item % 5 == 0);
+    }
+}
+
+#[test]
+fn stats() {
+    let mut set = CamiHashSet::<String, FirstByte>::with_hasher(FirstByte);
+    // Tags 'a', 'a', 'b'; lengths 1, 2, 1; all in one probe sequence.
+    for item in ["a", "ab", "b"] {
+        set.insert(Cami::new(item.to_owned()));
+    }
+    set.reset_stats();
+    // "abc": the same tag as "a" and "ab", but a different length. "b" has a different tag.
+    assert!(!set.contains("abc"));
+    assert_eq!(
+        set.stats(),
+        CamiHashStats {
+            probed: 3,
+            rejected_by_hash: 1,
+            rejected_by_local: 2,
+            non_local_comparisons: 0,
+        }
+    );
+    assert_eq!(set.stats().dereferences_avoided(), 2);
+    set.reset_stats();
+    assert_eq!(set.stats(), CamiHashStats::default());
+}
@@ -0,0 +1,147 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+use cami::alloc::interner::{CamiInterner, Symbol};
+use cami::prelude::*;
+use common::{random_string, Rng};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::collections::HashMap;
+
+/// Counts allocations per thread, so that tests running in parallel don't disturb each other.
+struct CountingAlloc;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+/// Short strings (with many duplicates and many equal lengths), plus a few longer ones.
+fn words() -> Vec<String> {
+    let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+    let alphabet = ['a', 'b', 'c', 'd', 'e', 'f'];
+    (0..5000)
+        .map(|_| {
+            if rng.below(50) == 0 {
+                (0..20).map(|_| rng.pick(&alphabet)).collect()
+            } else {
+                random_string(&mut rng, 4, &alphabet)
+            }
+        })
+        .collect()
+}
+
+#[test]
+fn symbols_are_stable_while_growing() {
+    let words = words();
+    let mut interner = CamiInterner::new();
+    let mut issued: HashMap<&str, Symbol> = HashMap::new();
+    for word in &words {
+        // Insertion order: a new string gets the next symbol.
+        let next = Symbol::from_u32(issued.len() as u32);
+        let expected = *issued.entry(word.as_str()).or_insert(next);
+        let symbol = interner.get_or_intern(word);
+        assert_eq!(symbol, expected, "{:?}", word);
+    }
+    assert_eq!(interner.len(), issued.len());
+    // The table grew many times. All symbols still resolve, and are found again.
+    for (word, &symbol) in &issued {
+        assert_eq!(interner.get(word), Some(symbol));
+        assert_eq!(interner.get_or_intern(word), symbol);
+        assert_eq!(interner.resolve(symbol), Some(*word));
+    }
+    assert_eq!(interner.len(), issued.len());
+    assert_eq!(interner.get("not interned"), None);
+    assert_eq!(
+        interner.resolve(Symbol::from_u32(interner.len() as u32)),
+        None
+    );
+    let in_order: Vec<&str> = interner.iter().map(|(_, s)| s).collect();
+    let mut expected: Vec<(&str, Symbol)> =
+        issued.iter().map(|(&s, &symbol)| (s, symbol)).collect();
+    expected.sort_by_key(|&(_, symbol)| symbol);
+    assert!(in_order
+        .iter()
+        .copied()
+        .eq(expected.iter().map(|&(s, _)| s)));
+}
+
+#[test]
+fn empty() {
+    let mut interner = CamiInterner::new();
+    assert_eq!(interner.get(""), None);
+    assert!(interner.is_empty());
+    let symbol = interner.get_or_intern("");
+    assert_eq!(interner.resolve(symbol), Some(""));
+    assert_eq!(interner.get(""), Some(symbol));
+}
+
+/// Looking up an interned string doesn't grow the table, even when it's as full as it gets.
+#[test]
+fn hits_do_not_grow() {
+    for count in 1..100 {
+        let strings: Vec<String> = (0..count).map(|i| i.to_string()).collect();
+        let mut interner: CamiInterner = strings.iter().map(String::as_str).collect();
+        let before = ALLOCATIONS.with(Cell::get);
+        for s in &strings {
+            interner.get_or_intern(s);
+        }
+        assert_eq!(ALLOCATIONS.with(Cell::get), before, "{}", count);
+        assert_eq!(interner.len(), count);
+    }
+}
+
+#[test]
+fn freeze_remaps_issued_symbols() {
+    let words = words();
+    let mut interner = CamiInterner::new();
+    let issued: Vec<(Symbol, &str)> = words
+        .iter()
+        .map(|word| (interner.get_or_intern(word), word.as_str()))
+        .collect();
+    let len = interner.len();
+    let frozen = interner.freeze();
+    assert_eq!(frozen.len(), len);
+
+    for &(old, word) in &issued {
+        let new = frozen.remap(old).unwrap();
+        assert_eq!(frozen.resolve(new), Some(word));
+        assert_eq!(frozen.get(word), Some(new));
+    }
+    assert_eq!(frozen.remap(Symbol::from_u32(len as u32)), None);
+
+    // New symbols are in Cami order of their strings.
+    let strings: Vec<&str> = frozen.iter().map(|(_, s)| s).collect();
+    assert!(strings
+        .windows(2)
+        .all(|pair| Cami::new(pair[0]) < Cami::new(pair[1])));
+    for (index, (symbol, _)) in frozen.iter().enumerate() {
+        assert_eq!(symbol.to_u32() as usize, index);
+    }
+
+    // The original insertion order, with new symbols.
+    let mut first_seen: Vec<&str> = Vec::new();
+    for &(_, word) in &issued {
+        if !first_seen.contains(&word) {
+            first_seen.push(word);
+        }
+    }
+    assert!(frozen
+        .iter_insertion_order()
+        .map(|(_, s)| s)
+        .eq(first_seen.iter().copied()));
+}
@@ -0,0 +1,157 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+use cami::prelude::*;
+use common::Rng;
+use core::cmp::Ordering;
+
+/// Ordered by `len` (the local part), then by `byte` (the non-local part). `tag` is NOT a part of
+/// the order (nor of equality), so that we can tell equal items apart.
+#[derive(Clone, Copy, Debug)]
+struct Item {
+    len: u8,
+    byte: u8,
+    tag: u32,
+}
+
+impl PartialEq for Item {
+    fn eq(&self, other: &Self) -> bool {
+        (self.len, self.byte) == (other.len, other.byte)
+    }
+}
+impl Eq for Item {}
+
+impl CamiPartialEq for Item {
+    const LOCALITY: Locality = Locality::Both;
+    fn eq_local(&self, other: &Self) -> bool {
+        self.len == other.len
+    }
+    fn eq_non_local(&self, other: &Self) -> bool {
+        self.byte == other.byte
+    }
+}
+impl CamiPartialOrd for Item {
+    fn partial_cmp_local(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp_local(other))
+    }
+    fn partial_cmp_non_local(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp_non_local(other))
+    }
+}
+impl CamiOrd for Item {
+    fn cmp_local(&self, other: &Self) -> Ordering {
+        self.len.cmp(&other.len)
+    }
+    fn cmp_non_local(&self, other: &Self) -> Ordering {
+        self.byte.cmp(&other.byte)
+    }
+}
+
+/// Items (len, byte), tagged by their index.
+fn items(keys: &[(u8, u8)]) -> Vec<Item> {
+    keys.iter()
+        .zip(0..)
+        .map(|(&(len, byte), tag)| Item { len, byte, tag })
+        .collect()
+}
+
+fn tag(item: Option<Item>) -> Option<u32> {
+    item.map(|item| item.tag)
+}
+
+#[test]
+fn first_minimum_and_last_maximum() {
+    // Minimum (0, 1) at 1 and 4; maximum (2, 0) at 0, 3 and 5. (1, 2) is in between: a greater
+    // non-local part, but a lesser local part.
+    let items = items(&[(2, 0), (0, 1), (1, 2), (2, 0), (0, 1), (2, 0), (0, 2)]);
+    assert_eq!(tag(items.iter().copied().min_cami()), Some(1));
+    assert_eq!(tag(items.iter().copied().max_cami()), Some(5));
+    assert_eq!(
+        items
+            .iter()
+            .copied()
+            .minmax_cami()
+            .into_option()
+            .map(|(min, max)| (min.tag, max.tag)),
+        Some((1, 5))
+    );
+    assert_eq!(
+        tag(items.iter().copied().min_by_cami_key(|item| item.len)),
+        Some(1)
+    );
+    assert_eq!(
+        tag(items.iter().copied().max_by_cami_key(|item| item.len)),
+        Some(5)
+    );
+
+    // All equal.
+    let equal = self::items(&[(1, 1), (1, 1), (1, 1)]);
+    assert_eq!(tag(equal.iter().copied().min_cami()), Some(0));
+    assert_eq!(tag(equal.iter().copied().max_cami()), Some(2));
+    match equal.iter().copied().minmax_cami() {
+        MinMaxCami::MinMax(min, max) => assert_eq!((min.tag, max.tag), (0, 2)),
+        other => panic!("{:?}", other),
+    }
+    // Two equal items: the first one is the minimum, the second one the maximum.
+    match equal[..2].iter().copied().minmax_cami() {
+        MinMaxCami::MinMax(min, max) => assert_eq!((min.tag, max.tag), (0, 1)),
+        other => panic!("{:?}", other),
+    }
+}
+
+#[test]
+fn minmax_against_min_and_max() {
+    let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+    for len in 0..200 {
+        let keys: Vec<(u8, u8)> = (0..len % 20)
+            .map(|_| (rng.below(3) as u8, rng.below(3) as u8))
+            .collect();
+        let items = items(&keys);
+        let (min, max) = (
+            items.iter().copied().min_cami(),
+            items.iter().copied().max_cami(),
+        );
+        match items.iter().copied().minmax_cami() {
+            MinMaxCami::NoElements => assert!(min.is_none() && max.is_none()),
+            MinMaxCami::OneElement(item) => {
+                assert_eq!(items.len(), 1);
+                assert_eq!((Some(item.tag), Some(item.tag)), (tag(min), tag(max)));
+            }
+            MinMaxCami::MinMax(found_min, found_max) => {
+                assert_eq!(
+                    (Some(found_min.tag), Some(found_max.tag)),
+                    (tag(min), tag(max))
+                );
+            }
+        }
+        // The same as a stable sort's first and last items.
+        let sorted = items.iter().copied().sorted_cami();
+        assert_eq!(tag(min), tag(sorted.first().copied()));
+        assert_eq!(tag(max), tag(sorted.last().copied()));
+    }
+}
+
+#[test]
+fn is_sorted_cami() {
+    assert!(core::iter::empty::<u8>().is_sorted_cami());
+    assert!([5u8].iter().copied().is_sorted_cami());
+    assert!(["", "b", "b", "ab", "ba"].iter().copied().is_sorted_cami());
+    // Lexicographically sorted, but not in Cami order.
+    assert!(!["ab", "b"].iter().copied().is_sorted_cami());
+
+    // It stops at the first item out of order, and the rest can be resumed.
+    let mut iter = [1u8, 3, 2, 4, 6, 5, 7].iter().copied();
+    assert!(!iter.is_sorted_cami());
+    assert_eq!(iter.next(), Some(4));
+    assert!(!iter.is_sorted_cami());
+    assert_eq!(iter.next(), Some(7));
+    assert!(iter.is_sorted_cami());
+}
+
+#[test]
+fn position_cami() {
+    let words = ["abc", "b", "ab", "b"];
+    assert_eq!(words.iter().copied().position_cami("b"), Some(1));
+    assert_eq!(words.iter().copied().position_cami("c"), None);
+}
@@ -0,0 +1,123 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+use cami::prelude::*;
+use common::Rng;
+use core::cmp::Ordering;
+
+/// Byte strings around the 8 byte prefix boundary, including zero bytes (which pad the prefix).
+fn samples() -> Vec<Vec<u8>> {
+    let mut samples: Vec<Vec<u8>> = vec![
+        vec![],
+        vec![0],
+        vec![0, 0],
+        vec![1],
+        vec![1, 0],
+        vec![255],
+        b"a".to_vec(),
+        b"a\0".to_vec(),
+        b"ab".to_vec(),
+        b"abcdefg".to_vec(),
+        b"abcdefg\0".to_vec(),
+        b"abcdefgh".to_vec(),
+        b"abcdefgh\0".to_vec(),
+        b"abcdefghi".to_vec(),
+        b"abcdefgha".to_vec(),
+        b"abcdefgh\0\0".to_vec(),
+        b"abcdefhh".to_vec(),
+        b"b".to_vec(),
+    ];
+    let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+    for _ in 0..500 {
+        let len = rng.below(13);
+        samples.push((0..len).map(|_| rng.pick(&[0, 1, b'a', 255])).collect());
+    }
+    samples
+}
+
+fn ascii_samples() -> Vec<String> {
+    samples()
+        .into_iter()
+        .map(|bytes| {
+            bytes
+                .into_iter()
+                .map(|b| char::from(b'a' + b % 3))
+                .collect()
+        })
+        .collect()
+}
+
+#[test]
+fn cmp_equals_std_for_byte_vecs() {
+    let samples = samples();
+    for a in &samples {
+        for b in &samples {
+            let (la, lb) = (LexCami::new_lex(a.clone()), LexCami::new_lex(b.clone()));
+            assert_eq!(la.cmp(&lb), a.cmp(b), "{:?} vs {:?}", a, b);
+            assert_eq!(la == lb, a == b, "{:?} vs {:?}", a, b);
+        }
+    }
+}
+
+#[test]
+fn cmp_equals_std_for_byte_slices() {
+    let samples = samples();
+    for a in &samples {
+        for b in &samples {
+            let (la, lb) = (LexCami::new_lex(&a[..]), LexCami::new_lex(&b[..]));
+            assert_eq!(la.cmp(&lb), a.cmp(b), "{:?} vs {:?}", a, b);
+            assert_eq!(la.partial_cmp(&lb), Some(a.cmp(b)));
+        }
+    }
+}
+
+#[test]
+fn cmp_equals_std_for_strings() {
+    let samples = ascii_samples();
+    for a in &samples {
+        for b in &samples {
+            assert_eq!(
+                LexCami::new_lex(a.clone()).cmp(&LexCami::new_lex(b.clone())),
+                a.cmp(b)
+            );
+            assert_eq!(
+                LexCami::new_lex(a.as_str()).cmp(&LexCami::new_lex(b.as_str())),
+                a.cmp(b)
+            );
+        }
+    }
+}
+
+#[test]
+fn sort_equals_std_sort() {
+    let strings = ascii_samples();
+    let mut expected: Vec<&str> = strings.iter().map(String::as_str).collect();
+    let mut sorted: Vec<LexCami<&str>> = expected.iter().copied().map(LexCami::new_lex).collect();
+    expected.sort();
+    sorted.sort();
+    let sorted: Vec<&str> = sorted.into_iter().map(LexCami::into_lex_inner).collect();
+    assert_eq!(sorted, expected);
+
+    let mut expected = samples();
+    let mut sorted: Vec<LexCami<Vec<u8>>> =
+        expected.iter().cloned().map(LexCami::new_lex).collect();
+    expected.sort_unstable();
+    sorted.sort_unstable();
+    let sorted: Vec<Vec<u8>> = sorted.into_iter().map(LexCami::into_lex_inner).collect();
+    assert_eq!(sorted, expected);
+}
+
+#[test]
+fn prefix_decides_most_comparisons() {
+    let (a, b) = (LexCami::new_lex("apple pie"), LexCami::new_lex("banana"));
+    let explanation = cami::explain_cmp(&a, &b);
+    assert_eq!(explanation.ordering(), Ordering::Less);
+    assert_eq!(explanation.decided_by(), cami::CmpDecidedBy::Local);
+
+    let (a, b) = (LexCami::new_lex("abcdefgh1"), LexCami::new_lex("abcdefgh2"));
+    assert_eq!(
+        cami::explain_cmp(&a, &b).decided_by(),
+        cami::CmpDecidedBy::NonLocal
+    );
+}
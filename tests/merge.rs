@@ -0,0 +1,224 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+use cami::core::{kmerge_cami_in_place, merge_cami, merge_cami_in_place, merge_cami_slices};
+use cami::prelude::*;
+use cami::Locality;
+use common::Rng;
+use core::cmp::Ordering;
+
+/// Ordered by `len` (the local part), then by `byte` (the non-local part). `tag` is NOT a part of
+/// the order (nor of equality), so that stability is observable.
+#[derive(Clone, Copy, Debug)]
+struct Item {
+    len: u8,
+    byte: u8,
+    tag: u32,
+}
+
+impl PartialEq for Item {
+    fn eq(&self, other: &Self) -> bool {
+        (self.len, self.byte) == (other.len, other.byte)
+    }
+}
+impl Eq for Item {}
+
+impl CamiPartialEq for Item {
+    const LOCALITY: Locality = Locality::Both;
+    fn eq_local(&self, other: &Self) -> bool {
+        self.len == other.len
+    }
+    fn eq_non_local(&self, other: &Self) -> bool {
+        self.byte == other.byte
+    }
+}
+impl CamiPartialOrd for Item {
+    fn partial_cmp_local(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp_local(other))
+    }
+    fn partial_cmp_non_local(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp_non_local(other))
+    }
+}
+impl CamiOrd for Item {
+    fn cmp_local(&self, other: &Self) -> Ordering {
+        self.len.cmp(&other.len)
+    }
+    fn cmp_non_local(&self, other: &Self) -> Ordering {
+        self.byte.cmp(&other.byte)
+    }
+}
+
+/// The oracle: [Cami] order, local part first.
+fn cmp_cami<T: CamiOrd>(left: &T, right: &T) -> Ordering {
+    left.cmp_local(right)
+        .then_with(|| left.cmp_non_local(right))
+}
+
+impl Rng {
+    /// A sorted run of `len` items, with many ties (both local and full), tagged from `first_tag`.
+    fn run(&mut self, len: usize, first_tag: u32) -> Vec<Item> {
+        let mut run: Vec<Item> = (0..len as u32)
+            .map(|index| Item {
+                len: self.below(3) as u8,
+                byte: self.below(3) as u8,
+                tag: first_tag + index,
+            })
+            .collect();
+        run.sort_by(cmp_cami);
+        run
+    }
+
+    /// Usually short, sometimes empty, sometimes long.
+    fn run_len(&mut self) -> usize {
+        match self.below(8) {
+            0 => 0,
+            1 => 1,
+            2 => 100 + self.below(200) as usize,
+            _ => self.below(20) as usize,
+        }
+    }
+}
+
+fn tags(items: &[Item]) -> Vec<u32> {
+    items.iter().map(|item| item.tag).collect()
+}
+
+/// Stable merge of `runs` (in this order) as per the oracle.
+fn expected(runs: &[Vec<Item>]) -> Vec<Item> {
+    let mut all: Vec<Item> = runs.concat();
+    all.sort_by(cmp_cami);
+    all
+}
+
+#[test]
+fn two_way_merges() {
+    let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+    for _ in 0..2000 {
+        let (left_len, right_len) = (rng.run_len(), rng.run_len());
+        let left = rng.run(left_len, 0);
+        let right = rng.run(right_len, 1000);
+        let expected = tags(&expected(&[left.clone(), right.clone()]));
+
+        let merged: Vec<Item> = merge_cami(left.iter().copied(), right.iter().copied()).collect();
+        assert_eq!(tags(&merged), expected);
+
+        let merged: Vec<Item> = merge_cami_slices(&left, &right).copied().collect();
+        assert_eq!(tags(&merged), expected);
+        let merged: Vec<Item> = merge_cami_slices(&left, &right)
+            .galloping()
+            .copied()
+            .collect();
+        assert_eq!(tags(&merged), expected);
+
+        let mut in_place = [left.clone(), right].concat();
+        merge_cami_in_place(&mut in_place, left.len());
+        assert_eq!(tags(&in_place), expected);
+    }
+}
+
+/// Galloping with sides that don't interleave at all, or only at their ends.
+#[test]
+fn galloping_with_long_runs() {
+    let item = |len, byte, tag| Item { len, byte, tag };
+    let low: Vec<Item> = (0..500).map(|tag| item(0, 0, tag)).collect();
+    let high: Vec<Item> = (500..1000).map(|tag| item(2, 2, tag)).collect();
+    let mut middle = vec![item(0, 0, 1000), item(1, 1, 1001)];
+    middle.extend((1002..1100).map(|tag| item(2, 2, tag)));
+    for (left, right) in [
+        (&low, &high),
+        (&high, &low),
+        (&low, &middle),
+        (&middle, &high),
+    ] {
+        let expected = tags(&expected(&[left.clone(), right.clone()]));
+        let merged: Vec<Item> = merge_cami_slices(left, right)
+            .galloping()
+            .copied()
+            .collect();
+        assert_eq!(tags(&merged), expected);
+    }
+}
+
+fn random_runs(rng: &mut Rng) -> Vec<Vec<Item>> {
+    let k = rng.below(9) as u32;
+    (0..k)
+        .map(|run| {
+            let len = rng.run_len();
+            rng.run(len, 1000 * run)
+        })
+        .collect()
+}
+
+#[test]
+fn k_way_merges() {
+    let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+    for _ in 0..1000 {
+        let runs = random_runs(&mut rng);
+        let expected = expected(&runs);
+
+        let stable: Vec<Item> = kmerge_cami_stable(runs.clone()).collect();
+        assert_eq!(tags(&stable), tags(&expected));
+
+        // Unstable: the same keys, and the same items (but maybe in a different order among
+        // fully equal ones).
+        let unstable: Vec<Item> = kmerge_cami(runs.iter().map(|run| run.iter().copied())).collect();
+        assert_eq!(unstable, expected);
+        let (mut unstable_tags, mut expected_tags) = (tags(&unstable), tags(&expected));
+        unstable_tags.sort_unstable();
+        expected_tags.sort_unstable();
+        assert_eq!(unstable_tags, expected_tags);
+
+        let mut in_place = runs.concat();
+        let run_ends: Vec<usize> = runs
+            .iter()
+            .scan(0, |end, run| {
+                *end += run.len();
+                Some(*end)
+            })
+            .collect();
+        kmerge_cami_in_place(&mut in_place, &run_ends);
+        assert_eq!(tags(&in_place), tags(&expected));
+    }
+}
+
+/// Every run has the same (fully equal) items: only stability decides the order.
+#[test]
+fn k_way_stability_with_equal_keys() {
+    let item = |tag| Item {
+        len: 1,
+        byte: 1,
+        tag,
+    };
+    let runs: Vec<Vec<Item>> = vec![
+        vec![item(0), item(1)],
+        vec![],
+        vec![item(2)],
+        vec![item(3), item(4), item(5)],
+        vec![],
+    ];
+    let merged: Vec<Item> = kmerge_cami_stable(runs.clone()).collect();
+    assert_eq!(tags(&merged), [0, 1, 2, 3, 4, 5]);
+
+    let mut in_place = runs.concat();
+    kmerge_cami_in_place(&mut in_place, &[2, 2, 3, 6, 6]);
+    assert_eq!(tags(&in_place), [0, 1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn k_way_edge_cases() {
+    let no_runs: Vec<Vec<u8>> = Vec::new();
+    assert_eq!(kmerge_cami(no_runs.clone()).count(), 0);
+    assert_eq!(kmerge_cami_stable(no_runs).count(), 0);
+    let empty_runs: Vec<Vec<u8>> = vec![vec![], vec![]];
+    assert_eq!(kmerge_cami(empty_runs).count(), 0);
+    kmerge_cami_in_place::<u8>(&mut [], &[]);
+
+    // Pure local items.
+    let runs = vec![vec![1u8, 4, 9], vec![0, 4], vec![255]];
+    let merged: Vec<u8> = kmerge_cami(runs.clone()).collect();
+    assert_eq!(merged, [0, 1, 4, 4, 9, 255]);
+    let merged: Vec<u8> = kmerge_cami_stable(runs).collect();
+    assert_eq!(merged, [0, 1, 4, 4, 9, 255]);
+}
@@ -0,0 +1,80 @@
+//! Regression tests: [PartialOrd::lt], [PartialOrd::le], [PartialOrd::gt] and [PartialOrd::ge] of
+//! [Cami] have to agree with [Ord::cmp] - the non-local parts apply only if the local parts are
+//! equal. And the forwarding `CamiPartialEq for Cami<T>` (used by `Cami<Cami<T>>`) must not negate
+//! the non-local equality.
+use cami::prelude::*;
+use core::cmp::Ordering;
+
+const WORDS: [&str; 9] = ["", "a", "b", "aa", "ab", "ba", "bb", "abc", "b\u{e9}"];
+
+/// Not requiring [Debug], so that it works without `debug` feature. Failures report indices.
+fn assert_operators_agree_with_cmp<T: Ord>(items: &[T]) {
+    for (i, left) in items.iter().enumerate() {
+        for (j, right) in items.iter().enumerate() {
+            let ordering = left.cmp(right);
+            assert_eq!(left < right, ordering == Ordering::Less, "{} < {}", i, j);
+            assert_eq!(
+                left <= right,
+                ordering != Ordering::Greater,
+                "{} <= {}",
+                i,
+                j
+            );
+            assert_eq!(left > right, ordering == Ordering::Greater, "{} > {}", i, j);
+            assert_eq!(left >= right, ordering != Ordering::Less, "{} >= {}", i, j);
+            assert_eq!(left.partial_cmp(right), Some(ordering), "{} {}", i, j);
+            assert_eq!(left == right, ordering == Ordering::Equal, "{} == {}", i, j);
+        }
+    }
+}
+
+#[test]
+fn str_operators_are_local_first() {
+    let (aa, b) = (Cami::new("aa"), Cami::new("b"));
+    // Shorter first, even though "aa" < "b" lexicographically.
+    assert!(b < aa);
+    assert!(b <= aa);
+    assert!(!aa.lt(&b));
+    assert!(!aa.le(&b));
+    assert!(aa > b);
+    assert!(aa >= b);
+
+    let items: Vec<Cami<&str>> = WORDS.iter().copied().map(Cami::new).collect();
+    assert_operators_agree_with_cmp(&items);
+}
+
+#[test]
+fn pure_local_operators() {
+    let items: Vec<Cami<u8>> = [0u8, 1, 2, 255].iter().copied().map(Cami::new).collect();
+    assert_operators_agree_with_cmp(&items);
+}
+
+#[test]
+fn sort_agrees_with_cmp() {
+    // `slice::sort` uses `PartialOrd::lt`, while `sort_by(Ord::cmp)` uses `Ord::cmp`.
+    let mut by_lt: Vec<Cami<&str>> = WORDS.iter().rev().copied().map(Cami::new).collect();
+    let mut by_cmp = by_lt.clone();
+    by_lt.sort();
+    by_cmp.sort_by(Ord::cmp);
+    assert!(by_lt == by_cmp);
+    let sorted: Vec<&str> = by_lt.iter().map(|word| *word.in_cami()).collect();
+    assert_eq!(
+        sorted,
+        ["", "a", "b", "aa", "ab", "ba", "bb", "abc", "b\u{e9}"]
+    );
+}
+
+#[test]
+fn nested_cami() {
+    let items: Vec<Cami<Cami<&str>>> = WORDS
+        .iter()
+        .copied()
+        .map(|word| Cami::new(Cami::new(word)))
+        .collect();
+    for (index, item) in items.iter().enumerate() {
+        assert!(item == &Cami::new(Cami::new(WORDS[index])));
+        assert!(item.eq_non_local(item));
+    }
+    assert!(items[3] != items[4]);
+    assert_operators_agree_with_cmp(&items);
+}
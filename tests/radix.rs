@@ -0,0 +1,198 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+use cami::core::{CamiRadixKey, F32Total};
+use cami::prelude::*;
+use common::Rng;
+use core::fmt::Debug;
+
+impl Rng {
+    /// Random bits, but often small (so that some bytes are the same for all items, and the radix
+    /// sort skips their passes), or one of `extremes`.
+    fn value<T: Copy>(&mut self, extremes: &[T], from_bits: impl Fn(u64) -> T) -> T {
+        let random = self.next();
+        match random % 4 {
+            0 => extremes[(random >> 8) as usize % extremes.len()],
+            1 => from_bits((random >> 8) % 4),
+            _ => from_bits(random >> 8),
+        }
+    }
+}
+
+/// Sort `values` with [RadixSortCami::sort_cami] and [RadixSortCami::radix_sort_cami], and compare
+/// with `sort_unstable` of the unwrapped values.
+fn check<T>(values: &[T])
+where
+    T: CamiRadixKey + Ord + Debug,
+{
+    let mut expected = values.to_vec();
+    expected.sort_unstable();
+    let wrapped: Vec<Cami<T>> = values.iter().copied().map(Cami::new).collect();
+
+    let mut sorted = wrapped.clone();
+    sorted.sort_cami();
+    let sorted: Vec<T> = sorted.into_iter().map(Cami::from_cami).collect();
+    assert_eq!(sorted, expected);
+
+    let mut sorted = wrapped.clone();
+    let mut buffer = wrapped.clone();
+    sorted.radix_sort_cami(&mut buffer);
+    let sorted: Vec<T> = sorted.into_iter().map(Cami::from_cami).collect();
+    assert_eq!(sorted, expected);
+}
+
+fn check_random<T>(extremes: &[T], from_bits: impl Fn(u64) -> T)
+where
+    T: CamiRadixKey + Ord + Debug,
+{
+    let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+    check::<T>(&[]);
+    check(extremes);
+    for len in 1..300 {
+        let values: Vec<T> = (0..len).map(|_| rng.value(extremes, &from_bits)).collect();
+        check(&values);
+    }
+    check(&[extremes[0]; 1000]);
+}
+
+#[test]
+fn unit() {
+    let mut units = [Cami::new(()); 5];
+    units.sort_cami();
+    units.radix_sort_cami(&mut []);
+    assert_eq!(units.len(), 5);
+}
+
+#[test]
+fn bool() {
+    check_random(&[false, true], |bits| bits % 2 == 1);
+}
+
+#[test]
+fn u8() {
+    check_random(&[u8::MIN, u8::MAX, 1, 0x7f, 0x80], |bits| bits as u8);
+}
+
+#[test]
+fn i8() {
+    check_random(&[i8::MIN, i8::MAX, 0, -1, 1], |bits| bits as i8);
+}
+
+#[test]
+fn i16() {
+    check_random(&[i16::MIN, i16::MAX, 0, -1, 1, -256, 255], |bits| {
+        bits as i16
+    });
+}
+
+#[test]
+fn i32() {
+    check_random(&[i32::MIN, i32::MAX, 0, -1, 1, i32::MIN + 1], |bits| {
+        bits as i32
+    });
+}
+
+#[test]
+fn i64() {
+    check_random(&[i64::MIN, i64::MAX, 0, -1, 1, i64::MAX - 1], |bits| {
+        bits as i64
+    });
+}
+
+#[test]
+fn u64() {
+    check_random(&[u64::MIN, u64::MAX, 1, 1 << 63], |bits| bits);
+}
+
+/// Signed zeros, NaNs with different signs and payloads (quiet and signaling), infinities,
+/// subnormals and extremes.
+const SPECIAL_F32_BITS: [u32; 14] = [
+    0x0000_0000, // +0.0
+    0x8000_0000, // -0.0
+    0x7fc0_0000, // NaN
+    0x7fc0_0001, // NaN, another payload
+    0x7f80_0001, // signaling NaN
+    0xffc0_0000, // -NaN
+    0xff80_0001, // signaling -NaN
+    0x7f80_0000, // +inf
+    0xff80_0000, // -inf
+    0x0000_0001, // the smallest positive subnormal
+    0x8000_0001, // the smallest negative subnormal
+    0x7f7f_ffff, // f32::MAX
+    0xff7f_ffff, // f32::MIN
+    0x3f80_0000, // 1.0
+];
+
+fn total_bits(values: impl IntoIterator<Item = f32>) -> Vec<u32> {
+    values.into_iter().map(f32::to_bits).collect()
+}
+
+/// Compare (by bits, so that NaNs and zeros are told apart) with `sort_unstable_by(total_cmp)`.
+fn check_f32(values: &[f32]) {
+    let mut expected = values.to_vec();
+    expected.sort_unstable_by(f32::total_cmp);
+    let wrapped: Vec<Cami<F32Total>> = values.iter().map(|&value| value.into_cami()).collect();
+
+    let mut sorted = wrapped.clone();
+    sorted.sort_cami();
+    assert_eq!(
+        total_bits(sorted.iter().map(|value| value.into_f32())),
+        total_bits(expected.iter().copied())
+    );
+
+    let mut sorted = wrapped.clone();
+    let mut buffer = wrapped.clone();
+    sorted.radix_sort_cami(&mut buffer);
+    assert_eq!(
+        total_bits(sorted.iter().map(|value| value.into_f32())),
+        total_bits(expected.iter().copied())
+    );
+}
+
+#[test]
+fn f32_total() {
+    let specials: Vec<f32> = SPECIAL_F32_BITS.map(f32::from_bits).to_vec();
+    check_f32(&specials);
+    let mut reversed = specials.clone();
+    reversed.reverse();
+    check_f32(&reversed);
+    // -NaN < -inf < ... < -0.0 < +0.0 < ... < +inf < NaN.
+    let mut sorted: Vec<Cami<F32Total>> = specials.iter().map(|&value| value.into_cami()).collect();
+    let mut buffer = sorted.clone();
+    sorted.radix_sort_cami(&mut buffer);
+    let bits = total_bits(sorted.iter().map(|value| value.into_f32()));
+    assert_eq!(bits[..2], [0xffc0_0000, 0xff80_0001]);
+    assert_eq!(bits[2], 0xff80_0000);
+    let zeros = bits.iter().position(|&bits| bits == 0x8000_0000).unwrap();
+    assert_eq!(bits[zeros + 1], 0x0000_0000);
+    assert_eq!(
+        bits[bits.len() - 4..],
+        [0x7f80_0000, 0x7f80_0001, 0x7fc0_0000, 0x7fc0_0001]
+    );
+
+    let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+    for len in 1..300 {
+        let values: Vec<f32> = (0..len)
+            .map(|_| rng.value(&specials, |bits| f32::from_bits(bits as u32)))
+            .collect();
+        check_f32(&values);
+    }
+}
+
+#[test]
+#[should_panic(expected = "the buffer must be at least as long as the slice")]
+fn short_buffer() {
+    let mut values: Vec<Cami<u32>> = (0..10).rev().map(Cami::new).collect();
+    let mut buffer = vec![Cami::new(0); 9];
+    values.radix_sort_cami(&mut buffer);
+}
+
+/// Keys of at most one byte don't use the buffer.
+#[test]
+fn narrow_keys_need_no_buffer() {
+    let mut values: Vec<Cami<i8>> = [3, -1, i8::MIN, 0].map(Cami::new).to_vec();
+    values.radix_sort_cami(&mut []);
+    let values: Vec<i8> = values.into_iter().map(Cami::from_cami).collect();
+    assert_eq!(values, [i8::MIN, -1, 0, 3]);
+}
@@ -0,0 +1,99 @@
+#![cfg(feature = "alloc")]
+
+use cami::prelude::*;
+use core::ops::{Bound, RangeBounds};
+
+fn bounds(key: usize) -> [Bound<usize>; 3] {
+    [Bound::Included(key), Bound::Excluded(key), Bound::Unbounded]
+}
+
+/// Sorted (in [Cami] order) strings of lengths 0..=4, with gaps (no length 2) and duplicates.
+fn sorted_strings() -> Vec<Cami<String>> {
+    let mut strings: Vec<Cami<String>> = ["", "a", "b", "b", "abc", "abc", "cba", "dddd"]
+        .iter()
+        .map(|s| Cami::new((*s).to_owned()))
+        .collect();
+    strings.sort();
+    strings
+}
+
+#[test]
+fn local_range_for_all_bounds() {
+    let strings = sorted_strings();
+    // Keys below, within (including the gap) and above the present lengths.
+    for start in 0..=6 {
+        for end in 0..=6 {
+            for start_bound in bounds(start) {
+                for end_bound in bounds(end) {
+                    let range = (start_bound, end_bound);
+                    let expected: Vec<&str> = strings
+                        .iter()
+                        .map(|item| item.in_cami().as_str())
+                        .filter(|item| range.contains(&item.len()))
+                        .collect();
+                    let found: Vec<&str> = strings
+                        .local_range(range)
+                        .iter()
+                        .map(|item| item.in_cami().as_str())
+                        .collect();
+                    assert_eq!(found, expected, "{:?}", range);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn local_range_with_range_syntax() {
+    let strings = sorted_strings();
+    let lengths = |found: &[Cami<String>]| -> Vec<usize> {
+        found.iter().map(|item| item.in_cami().len()).collect()
+    };
+    assert_eq!(lengths(strings.local_range(1..4)), [1, 1, 1, 3, 3, 3]);
+    assert_eq!(lengths(strings.local_range(2..=3)), [3, 3, 3]);
+    assert_eq!(lengths(strings.local_range(..1)), [0]);
+    assert_eq!(lengths(strings.local_range(4..)), [4]);
+    assert_eq!(lengths(strings.local_range(..)).len(), strings.len());
+    assert!(strings.local_range(2..3).is_empty());
+    assert!(strings.local_range(5..).is_empty());
+
+    // Inverted (and empty) ranges give an empty subslice, rather than panicking.
+    let (start, end) = (3, 1);
+    assert!(strings.local_range(start..end).is_empty());
+    assert!(strings.local_range(start..=end).is_empty());
+    assert!(strings.local_range(start..start).is_empty());
+    assert!(strings
+        .local_range((Bound::Excluded(3), Bound::Included(3)))
+        .is_empty());
+    assert!(strings
+        .local_range((Bound::Excluded(1), Bound::Excluded(3)))
+        .is_empty());
+
+    let empty: &[Cami<String>] = &[];
+    assert!(empty.local_range(..).is_empty());
+    assert!(empty.local_range(start..end).is_empty());
+}
+
+#[test]
+fn local_range_of_pure_local_items() {
+    let bytes: Vec<Cami<u8>> = [0u8, 3, 3, 7, 200, 255]
+        .iter()
+        .copied()
+        .map(Cami::new)
+        .collect();
+    let found = |range: (Bound<u8>, Bound<u8>)| -> Vec<u8> {
+        bytes
+            .local_range(range)
+            .iter()
+            .map(|item| *item.in_cami())
+            .collect()
+    };
+    assert_eq!(found((Bound::Included(3), Bound::Excluded(200))), [3, 3, 7]);
+    assert_eq!(
+        found((Bound::Excluded(3), Bound::Included(255))),
+        [7, 200, 255]
+    );
+    assert_eq!(found((Bound::Unbounded, Bound::Excluded(0))), [0u8; 0]);
+    assert_eq!(found((Bound::Excluded(255), Bound::Unbounded)), [0u8; 0]);
+    assert_eq!(found((Bound::Included(200), Bound::Included(7))), [0u8; 0]);
+}
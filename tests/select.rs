@@ -0,0 +1,163 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+use cami::prelude::*;
+use common::Rng;
+use core::cmp::Ordering;
+
+/// Ordered by `len` (the local part, and the local key), then by `byte` (the non-local part).
+/// `tag` is NOT a part of the order, so that we can check no item is lost or duplicated.
+#[derive(Clone, Copy, Debug, Default)]
+struct Item {
+    len: u8,
+    byte: u8,
+    tag: u32,
+}
+
+impl PartialEq for Item {
+    fn eq(&self, other: &Self) -> bool {
+        (self.len, self.byte) == (other.len, other.byte)
+    }
+}
+impl Eq for Item {}
+
+impl CamiPartialEq for Item {
+    const LOCALITY: Locality = Locality::Both;
+    fn eq_local(&self, other: &Self) -> bool {
+        self.len == other.len
+    }
+    fn eq_non_local(&self, other: &Self) -> bool {
+        self.byte == other.byte
+    }
+}
+impl CamiPartialOrd for Item {
+    fn partial_cmp_local(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp_local(other))
+    }
+    fn partial_cmp_non_local(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp_non_local(other))
+    }
+}
+impl CamiOrd for Item {
+    fn cmp_local(&self, other: &Self) -> Ordering {
+        self.len.cmp(&other.len)
+    }
+    fn cmp_non_local(&self, other: &Self) -> Ordering {
+        self.byte.cmp(&other.byte)
+    }
+}
+impl CamiLocalKey for Item {
+    type LocalKey = u8;
+    fn local_key(&self) -> u8 {
+        self.len
+    }
+}
+
+impl Item {
+    /// The [Cami] order, as a tuple.
+    fn key(&self) -> (u8, u8) {
+        (self.len, self.byte)
+    }
+}
+
+fn keys(items: &[Item]) -> Vec<(u8, u8)> {
+    items.iter().map(Item::key).collect()
+}
+
+fn sorted_tags(items: &[Item]) -> Vec<u32> {
+    let mut tags: Vec<u32> = items.iter().map(|item| item.tag).collect();
+    tags.sort_unstable();
+    tags
+}
+
+/// Items with many local key ties (and many full ties), in random order.
+fn random_items(rng: &mut Rng) -> Vec<Item> {
+    let len = rng.below(40) as u32;
+    (0..len)
+        .map(|tag| Item {
+            len: rng.below(4) as u8,
+            byte: rng.below(3) as u8,
+            tag,
+        })
+        .collect()
+}
+
+#[test]
+fn against_full_sort() {
+    let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+    for _ in 0..500 {
+        let items = random_items(&mut rng);
+        let mut sorted = items.clone();
+        sorted.sort_by_key(Item::key);
+        // k=0, some k, k=len and k>len.
+        for k in (0..=items.len()).chain([items.len() + 1, items.len() + 100]) {
+            let expected = keys(&sorted[..k.min(sorted.len())]);
+
+            let top = top_k_cami(items.iter().copied(), k);
+            assert_eq!(keys(&top), expected, "{}", k);
+            // Each item at most once.
+            let mut tags = sorted_tags(&top);
+            tags.dedup();
+            assert_eq!(tags.len(), top.len());
+
+            let mut buffer = vec![Item::default(); k];
+            let top = top_k_cami_in(items.iter().copied(), &mut buffer);
+            assert_eq!(keys(top), expected, "{}", k);
+
+            let mut partly_sorted = items.clone();
+            partial_sort_cami(&mut partly_sorted, k);
+            assert_eq!(keys(&partly_sorted[..expected.len()]), expected, "{}", k);
+            // A permutation of the input.
+            assert_eq!(sorted_tags(&partly_sorted), sorted_tags(&items));
+        }
+    }
+}
+
+#[test]
+fn select_nth_against_full_sort() {
+    let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+    for _ in 0..500 {
+        let items = random_items(&mut rng);
+        let mut sorted = items.clone();
+        sorted.sort_by_key(Item::key);
+        for (index, expected) in sorted.iter().enumerate() {
+            let mut selected = items.clone();
+            let (lesser, nth, greater) = select_nth_unstable_cami(&mut selected, index);
+            assert_eq!(nth, expected);
+            assert!(lesser.iter().all(|item| item.key() <= nth.key()));
+            assert!(greater.iter().all(|item| item.key() >= nth.key()));
+        }
+    }
+}
+
+/// All items have the same local key, so every candidate (once the heap is full) compares the
+/// non-local parts.
+#[test]
+fn equal_local_keys() {
+    let items: Vec<Item> = (0..50)
+        .map(|tag| Item {
+            len: 1,
+            byte: (tag * 7 % 5) as u8,
+            tag,
+        })
+        .collect();
+    let mut sorted = items.clone();
+    sorted.sort_by_key(|item| item.byte);
+    for k in [0, 1, 9, 10, 11, 50, 51] {
+        let expected = keys(&sorted[..k.min(50)]);
+        assert_eq!(keys(&top_k_cami(items.iter().copied(), k)), expected);
+        let mut partly_sorted = items.clone();
+        partial_sort_cami(&mut partly_sorted, k);
+        assert_eq!(keys(&partly_sorted[..k.min(50)]), expected);
+    }
+}
+
+#[test]
+fn strings() {
+    let words = ["pear", "fig", "apple", "kiwi", "date", "fig", "banana"];
+    let top = top_k_cami(words.iter().map(|word| Cami::new(*word)), 4);
+    let top: Vec<&str> = top.into_iter().map(Cami::from_cami).collect();
+    assert_eq!(top, ["fig", "fig", "date", "kiwi"]);
+    assert!(top_k_cami(words.iter().map(|word| Cami::new(*word)), 0).is_empty());
+}
@@ -0,0 +1,89 @@
+#![cfg(feature = "serde")]
+
+use cami::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+struct Plain {
+    items: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Any {
+    #[serde(with = "cami::serde::vec")]
+    items: Vec<Cami<String>>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Sorted {
+    #[serde(with = "cami::serde::sorted_vec")]
+    items: Vec<Cami<String>>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Sorting {
+    #[serde(with = "cami::serde::sorting_vec")]
+    items: Vec<Cami<String>>,
+}
+
+fn strings(items: &[&str]) -> Vec<String> {
+    items.iter().map(|s| s.to_string()).collect()
+}
+
+fn unwrap(items: Vec<Cami<String>>) -> Vec<String> {
+    Cami::unwrap_vec(items)
+}
+
+#[test]
+fn vec_like_plain() {
+    let plain = Plain {
+        items: strings(&["ccc", "b", "aa"]),
+    };
+    let any = Any {
+        items: Cami::wrap_vec(plain.items.clone()),
+    };
+    let json = serde_json::to_string(&any).unwrap();
+    assert_eq!(json, serde_json::to_string(&plain).unwrap());
+    assert_eq!(
+        bincode::serialize(&any).unwrap(),
+        bincode::serialize(&plain).unwrap()
+    );
+
+    let back: Any = serde_json::from_str(&json).unwrap();
+    assert_eq!(unwrap(back.items), plain.items);
+}
+
+#[test]
+fn sorted_vec_round_trip() {
+    let sorted = Sorted {
+        items: Cami::wrap_vec(strings(&["b", "aa", "ab", "ab", "ccc"])),
+    };
+    let json = serde_json::to_string(&sorted).unwrap();
+    let back: Sorted = serde_json::from_str(&json).unwrap();
+    assert_eq!(unwrap(back.items), strings(&["b", "aa", "ab", "ab", "ccc"]));
+
+    let bytes = bincode::serialize(&sorted).unwrap();
+    let back: Sorted = bincode::deserialize(&bytes).unwrap();
+    assert_eq!(unwrap(back.items), strings(&["b", "aa", "ab", "ab", "ccc"]));
+}
+
+#[test]
+fn sorted_vec_rejects_unsorted() {
+    // Lexicographic, but not Cami order.
+    let json = r#"{"items":["aa","b"]}"#;
+    let err = serde_json::from_str::<Sorted>(json).err().unwrap();
+    assert!(err.to_string().contains("index 1"));
+
+    let bytes = bincode::serialize(&Plain {
+        items: strings(&["aa", "b"]),
+    })
+    .unwrap();
+    assert!(bincode::deserialize::<Sorted>(&bytes).is_err());
+}
+
+#[test]
+fn sorting_vec_sorts() {
+    let json = r#"{"items":["ccc","aa","b"]}"#;
+    let sorting: Sorting = serde_json::from_str(json).unwrap();
+    assert_eq!(unwrap(sorting.items), strings(&["b", "aa", "ccc"]));
+}
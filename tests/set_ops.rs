@@ -0,0 +1,232 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+use cami::core::{
+    difference_cami, intersection_cami, merge_join_by_cami, symmetric_difference_cami, union_cami,
+    EitherOrBoth, SetOpCami,
+};
+use cami::prelude::*;
+use common::Rng;
+use core::cmp::Ordering;
+
+/// Ordered by `len` (the local part), then by `byte` (the non-local part). `tag` is NOT a part of
+/// the order (nor of equality), so that we can tell which side (and which duplicate) an item came
+/// from.
+#[derive(Clone, Copy, Debug)]
+struct Item {
+    len: u8,
+    byte: u8,
+    tag: u32,
+}
+
+impl PartialEq for Item {
+    fn eq(&self, other: &Self) -> bool {
+        (self.len, self.byte) == (other.len, other.byte)
+    }
+}
+impl Eq for Item {}
+
+impl CamiPartialEq for Item {
+    const LOCALITY: Locality = Locality::Both;
+    fn eq_local(&self, other: &Self) -> bool {
+        self.len == other.len
+    }
+    fn eq_non_local(&self, other: &Self) -> bool {
+        self.byte == other.byte
+    }
+}
+impl CamiPartialOrd for Item {
+    fn partial_cmp_local(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp_local(other))
+    }
+    fn partial_cmp_non_local(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp_non_local(other))
+    }
+}
+impl CamiOrd for Item {
+    fn cmp_local(&self, other: &Self) -> Ordering {
+        self.len.cmp(&other.len)
+    }
+    fn cmp_non_local(&self, other: &Self) -> Ordering {
+        self.byte.cmp(&other.byte)
+    }
+}
+
+impl Rng {
+    /// A sorted multiset, with many duplicates (and many equal local parts), tagged from
+    /// `first_tag`.
+    fn multiset(&mut self, first_tag: u32) -> Vec<Item> {
+        let len = match self.below(6) {
+            0 => 0,
+            1 => 200,
+            _ => self.below(30),
+        } as u32;
+        let mut items: Vec<Item> = (0..len)
+            .map(|index| Item {
+                len: self.below(3) as u8,
+                byte: self.below(3) as u8,
+                tag: first_tag + index,
+            })
+            .collect();
+        items.sort_by_key(|item| (item.len, item.byte));
+        items
+    }
+}
+
+/// All keys, in [Cami] order.
+fn keys() -> impl Iterator<Item = (u8, u8)> {
+    (0..3).flat_map(|len| (0..3).map(move |byte| (len, byte)))
+}
+
+fn with_key(items: &[Item], key: (u8, u8)) -> Vec<Item> {
+    items
+        .iter()
+        .copied()
+        .filter(|item| (item.len, item.byte) == key)
+        .collect()
+}
+
+fn tags<'a>(items: impl IntoIterator<Item = &'a Item>) -> Vec<u32> {
+    items.into_iter().map(|item| item.tag).collect()
+}
+
+/// Expected tags of a set operation, one key at a time: `m` items on the left and `n` on the
+/// right are paired one to one (in order), and `per_key` picks from the pairs (left items) and
+/// from the unpaired leftovers of either side.
+fn expected(
+    left: &[Item],
+    right: &[Item],
+    per_key: impl Fn(&[Item], &[Item], &[Item]) -> Vec<Item>,
+) -> Vec<u32> {
+    let mut result = Vec::new();
+    for key in keys() {
+        let (left, right) = (with_key(left, key), with_key(right, key));
+        let paired = left.len().min(right.len());
+        result.extend(per_key(&left[..paired], &left[paired..], &right[paired..]));
+    }
+    tags(&result)
+}
+
+fn check_size_hint<T: CamiOrd>(set_op: SetOpCami<'_, T>) -> usize {
+    let (lower, upper) = set_op.size_hint();
+    let count = set_op.count();
+    assert!(lower <= count && count <= upper.unwrap());
+    count
+}
+
+#[test]
+fn multisets_against_counts() {
+    let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+    for _ in 0..2000 {
+        let (left, right) = (rng.multiset(0), rng.multiset(1000));
+        let (left, right) = (&left[..], &right[..]);
+
+        assert_eq!(
+            tags(intersection_cami(left, right)),
+            expected(left, right, |paired, _, _| paired.to_vec())
+        );
+        assert_eq!(
+            tags(union_cami(left, right)),
+            expected(left, right, |paired, left, right| [paired, left, right]
+                .concat())
+        );
+        assert_eq!(
+            tags(difference_cami(left, right)),
+            expected(left, right, |_, left, _| left.to_vec())
+        );
+        assert_eq!(
+            tags(symmetric_difference_cami(left, right)),
+            expected(left, right, |_, left, right| [left, right].concat())
+        );
+
+        for set_op in [
+            intersection_cami(left, right),
+            union_cami(left, right),
+            difference_cami(left, right),
+            symmetric_difference_cami(left, right),
+        ] {
+            check_size_hint(set_op.clone());
+            // Partly consumed, with a pending run.
+            let mut set_op = set_op;
+            set_op.next();
+            check_size_hint(set_op);
+        }
+    }
+}
+
+#[test]
+fn merge_join_multisets() {
+    let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+    for _ in 0..2000 {
+        let (left, right) = (rng.multiset(0), rng.multiset(1000));
+        let mut expected = Vec::new();
+        for key in keys() {
+            let (left, right) = (with_key(&left, key), with_key(&right, key));
+            let paired = left.len().min(right.len());
+            expected.extend(
+                left.iter()
+                    .zip(&right)
+                    .map(|(left, right)| EitherOrBoth::Both(left.tag, right.tag)),
+            );
+            expected.extend(
+                left[paired..]
+                    .iter()
+                    .map(|left| EitherOrBoth::Left(left.tag)),
+            );
+            expected.extend(
+                right[paired..]
+                    .iter()
+                    .map(|right| EitherOrBoth::Right(right.tag)),
+            );
+        }
+        let joined: Vec<EitherOrBoth<u32>> = merge_join_by_cami(left.clone(), right.clone())
+            .map(|either| match either {
+                EitherOrBoth::Left(left) => EitherOrBoth::Left(left.tag),
+                EitherOrBoth::Right(right) => EitherOrBoth::Right(right.tag),
+                EitherOrBoth::Both(left, right) => EitherOrBoth::Both(left.tag, right.tag),
+            })
+            .collect();
+        assert_eq!(joined, expected);
+
+        // All items, each exactly once.
+        let lefts: Vec<u32> = joined.iter().filter_map(|either| either.left()).collect();
+        let rights: Vec<u32> = joined.iter().filter_map(|either| either.right()).collect();
+        assert_eq!(lefts.len(), left.len());
+        assert_eq!(rights.len(), right.len());
+    }
+}
+
+fn strs<'a>(set_op: SetOpCami<'a, Cami<String>>) -> Vec<&'a str> {
+    set_op.map(|item| item.in_cami().as_str()).collect()
+}
+
+fn sorted(strings: &[&str]) -> Vec<Cami<String>> {
+    let mut sorted: Vec<Cami<String>> =
+        strings.iter().map(|s| Cami::new((*s).to_owned())).collect();
+    sorted.sort();
+    sorted
+}
+
+#[test]
+fn strings() {
+    let left = sorted(&["b", "a", "a", "cc", "ab", "abc"]);
+    let right = sorted(&["a", "c", "ab", "ab", "ba"]);
+    assert_eq!(strs(intersection_cami(&left, &right)), ["a", "ab"]);
+    assert_eq!(
+        strs(union_cami(&left, &right)),
+        ["a", "a", "b", "c", "ab", "ab", "ba", "cc", "abc"]
+    );
+    assert_eq!(
+        strs(difference_cami(&left, &right)),
+        ["a", "b", "cc", "abc"]
+    );
+    assert_eq!(
+        strs(symmetric_difference_cami(&left, &right)),
+        ["a", "b", "c", "ab", "ba", "cc", "abc"]
+    );
+    assert_eq!(strs(union_cami(&[], &right)).len(), right.len());
+    assert!(strs(intersection_cami(&left, &[])).is_empty());
+    assert!(strs(difference_cami(&[], &right)).is_empty());
+    assert_eq!(merge_join_by_cami(&left, &[]).count(), left.len());
+}
@@ -0,0 +1,125 @@
+#![cfg(feature = "alloc")]
+
+use cami::prelude::*;
+use core::ops::Bound;
+
+/// Sizes around the boundaries of one leaf, and of one, two and three levels of internal nodes.
+fn sizes() -> Vec<usize> {
+    let leaf = cami::alloc::static_tree::NODE_KEYS;
+    let fanout = leaf + 1;
+    let mut sizes: Vec<usize> = (0..=2 * leaf + 2).collect();
+    for boundary in [
+        leaf * fanout,
+        leaf * fanout * fanout,
+        leaf * fanout * fanout * fanout,
+    ] {
+        sizes.extend([boundary - 1, boundary, boundary + 1, boundary + leaf]);
+    }
+    sizes
+}
+
+fn bounds<T: Clone>(value: &T) -> [Bound<T>; 3] {
+    [
+        Bound::Included(value.clone()),
+        Bound::Excluded(value.clone()),
+        Bound::Unbounded,
+    ]
+}
+
+fn in_range<T: Ord>(item: &T, range: &(Bound<T>, Bound<T>)) -> bool {
+    (match &range.0 {
+        Bound::Included(start) => item >= start,
+        Bound::Excluded(start) => item > start,
+        Bound::Unbounded => true,
+    }) && (match &range.1 {
+        Bound::Included(end) => item <= end,
+        Bound::Excluded(end) => item < end,
+        Bound::Unbounded => true,
+    })
+}
+
+/// Check searches against the sorted [Vec]. `needles` has to be sorted.
+fn check<T: CamiOrd + CamiLocalKey + Clone>(
+    sorted: Vec<Cami<T>>,
+    needles: &[Cami<T>],
+    ranges: bool,
+) {
+    let tree = CamiStaticTree::from_sorted_vec(sorted.clone());
+    assert_eq!(tree.len(), sorted.len());
+    assert!(tree.as_slice() == &sorted[..]);
+    for needle in needles {
+        let rank = sorted.partition_point(|item| item < needle);
+        assert_eq!(tree.rank(needle), rank);
+        assert!(tree.lower_bound(needle) == sorted.get(rank));
+        let present = sorted.get(rank) == Some(needle);
+        assert_eq!(tree.contains(needle), present);
+        assert_eq!(tree.get(needle).is_some(), present);
+    }
+    if ranges {
+        // Every pair of (sorted) needles, including inverted ranges.
+        for start in needles.iter().step_by(3) {
+            for end in needles.iter().step_by(3) {
+                for start in bounds(start) {
+                    for end in bounds(end) {
+                        let range = (start.clone(), end);
+                        let expected: Vec<&Cami<T>> = sorted
+                            .iter()
+                            .filter(|item| in_range(*item, &range))
+                            .collect();
+                        let found: Vec<&Cami<T>> = tree.range(range).collect();
+                        assert!(found == expected);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn pure_local_keys_with_duplicates() {
+    for len in sizes() {
+        // Each value three times; odd needles fall between.
+        let sorted: Vec<Cami<u32>> = (0..len as u32).map(|i| Cami::new(i / 3 * 2)).collect();
+        let max = sorted.last().map_or(0, |last| *last.in_cami());
+        let needles: Vec<Cami<u32>> = (0..=max + 2).map(Cami::new).collect();
+        check(sorted, &needles, len < 100);
+    }
+}
+
+#[test]
+fn strings_tied_on_local_keys() {
+    for len in sizes() {
+        // Few distinct lengths, so that many items tie on their local keys (lengths).
+        let words: Vec<String> = (0..len)
+            .map(|i| format!("{:0width$}", i % 97, width = 1 + i % 3))
+            .collect();
+        let mut sorted: Vec<Cami<String>> = words.into_iter().map(Cami::new).collect();
+        sorted.sort();
+        let mut needles: Vec<Cami<String>> = (0..100)
+            .flat_map(|i| {
+                (1..=4).map(move |width| Cami::new(format!("{:0width$}", i, width = width)))
+            })
+            .collect();
+        needles.push(Cami::new(String::new()));
+        needles.sort();
+        needles.dedup();
+        check(sorted, &needles, len < 30);
+    }
+}
+
+#[test]
+fn from_iterator_sorts() {
+    let tree: CamiStaticTree<&str> = ["ccc", "b", "aa", "a"]
+        .iter()
+        .copied()
+        .map(Cami::new)
+        .collect();
+    let sorted: Vec<&str> = tree.iter().map(|item| *item.in_cami()).collect();
+    assert_eq!(sorted, ["a", "b", "aa", "ccc"]);
+}
+
+#[test]
+#[should_panic(expected = "sorted")]
+fn unsorted_items_panic() {
+    let _ = CamiStaticTree::from_sorted_vec(vec![Cami::new(2u8), Cami::new(1)]);
+}
@@ -0,0 +1,82 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+use cami::prelude::*;
+use common::Rng;
+
+/// Byte strings of few lengths (so that the equal-length buckets are large), over a small alphabet
+/// (so that they share long prefixes), including duplicates, empty strings and zero bytes.
+fn samples() -> Vec<Vec<u8>> {
+    let mut samples: Vec<Vec<u8>> = vec![vec![], vec![], vec![0], vec![255], vec![0, 0]];
+    let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+    for _ in 0..2000 {
+        let len = rng.below(9);
+        samples.push(
+            (0..len)
+                .map(|_| rng.pick(&[0, 1, b'a', b'b', 255]))
+                .collect(),
+        );
+    }
+    samples
+}
+
+/// Valid UTF-8, with multi-byte characters (whose byte order is what matters).
+fn string_samples() -> Vec<String> {
+    samples()
+        .into_iter()
+        .map(|bytes| {
+            bytes
+                .into_iter()
+                .map(|b| ['a', 'b', 'é', 'ж', '\u{10348}'][b as usize % 5])
+                .collect()
+        })
+        .collect()
+}
+
+#[test]
+fn byte_slices_match_ord() {
+    let samples = samples();
+    let mut sorted: Vec<Cami<&[u8]>> = samples.iter().map(|s| Cami::new(s.as_slice())).collect();
+    let mut expected = sorted.clone();
+    expected.sort();
+    sorted.sort_strings_cami();
+    assert!(sorted == expected);
+}
+
+#[test]
+fn strs_match_ord() {
+    let samples = string_samples();
+    let mut sorted: Vec<Cami<&str>> = samples.iter().map(|s| Cami::new(s.as_str())).collect();
+    let mut expected = sorted.clone();
+    expected.sort();
+    sorted.sort_strings_cami();
+    assert!(sorted == expected);
+}
+
+#[test]
+fn strings_match_ord() {
+    let mut sorted: Vec<Cami<String>> = string_samples().into_iter().map(Cami::new).collect();
+    let mut expected = sorted.clone();
+    expected.sort();
+    sorted.sort_strings_cami();
+    assert!(sorted == expected);
+}
+
+#[test]
+fn short_and_sorted_inputs() {
+    for len in 0..20 {
+        let samples: Vec<String> = string_samples().into_iter().take(len).collect();
+        let mut sorted: Vec<Cami<&str>> = samples.iter().map(|s| Cami::new(s.as_str())).collect();
+        let mut expected = sorted.clone();
+        expected.sort();
+        sorted.sort_strings_cami();
+        assert!(sorted == expected);
+        // Already sorted, and reversed.
+        sorted.sort_strings_cami();
+        assert!(sorted == expected);
+        sorted.reverse();
+        sorted.sort_strings_cami();
+        assert!(sorted == expected);
+    }
+}
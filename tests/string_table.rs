@@ -0,0 +1,165 @@
+#![cfg(feature = "std")]
+
+use cami::alloc::string_table::{CamiStringTableError, CAMI_STRING_TABLE_VERSION};
+use cami::prelude::*;
+use std::path::PathBuf;
+use std::{fs, process};
+
+const WORDS: &[&str] = &[
+    "pear",
+    "fig",
+    "banana",
+    "apple",
+    "kiwi",
+    "",
+    "cherry",
+    "date",
+    "fig",
+    "elderberry",
+    "plum",
+];
+
+/// Unique per test (and per process), so that tests can run in parallel.
+fn temp_file(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("cami-string-table-{}-{}.bin", process::id(), name))
+}
+
+fn round_trip(name: &str, words: &[&str]) -> Vec<u8> {
+    let path = temp_file(name);
+    fs::write(
+        &path,
+        CamiStringTable::build(words.iter().copied()).unwrap(),
+    )
+    .unwrap();
+    let bytes = fs::read(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+    bytes
+}
+
+#[test]
+fn round_trip_through_temp_file() {
+    let bytes = round_trip("basic", WORDS);
+    let table = CamiStringTable::from_bytes(&bytes).unwrap();
+
+    let mut expected: Vec<Cami<&str>> = WORDS.iter().copied().map(Cami::new).collect();
+    expected.sort();
+    expected.dedup();
+    let expected: Vec<&str> = expected.into_iter().map(Cami::from_cami).collect();
+    assert_eq!(table.iter().collect::<Vec<_>>(), expected);
+    assert_eq!(table.len(), WORDS.len() - 1);
+
+    for (index, word) in expected.iter().enumerate() {
+        assert_eq!(table.find(word), Some(index));
+        assert_eq!(table.get(index), Some(*word));
+        assert_eq!(table.len_at(index), word.len());
+    }
+    for missing in ["figs", "aaaa", "zzzzzz", "b", "elderberrx"] {
+        assert!(!table.contains(missing));
+    }
+    assert_eq!(table.get(table.len()), None);
+}
+
+#[test]
+fn empty_round_trip() {
+    let bytes = round_trip("empty", &[]);
+    let table = CamiStringTable::from_bytes(&bytes).unwrap();
+    assert!(table.is_empty());
+    assert_eq!(table.find(""), None);
+}
+
+#[test]
+fn loads_unaligned() {
+    let bytes = CamiStringTable::build(WORDS.iter().copied()).unwrap();
+    let mut shifted = vec![0u8];
+    shifted.extend_from_slice(&bytes);
+    let table = CamiStringTable::from_bytes(&shifted[1..]).unwrap();
+    assert!(table.contains("banana"));
+}
+
+#[test]
+fn rejects_corruption() {
+    let bytes = round_trip("corrupt", WORDS);
+    let load = |bytes: &[u8]| CamiStringTable::from_bytes(bytes).map(|_| ()).unwrap_err();
+
+    assert!(matches!(
+        load(&bytes[..20]),
+        CamiStringTableError::Truncated { .. }
+    ));
+    assert!(matches!(
+        load(&bytes[..bytes.len() - 1]),
+        CamiStringTableError::Truncated { .. }
+    ));
+    let mut longer = bytes.clone();
+    longer.push(0);
+    assert!(matches!(
+        load(&longer),
+        CamiStringTableError::TrailingBytes { .. }
+    ));
+
+    let mut bad = bytes.clone();
+    bad[0] ^= 1;
+    assert_eq!(load(&bad), CamiStringTableError::BadMagic);
+
+    let mut bad = bytes.clone();
+    bad[8..12].copy_from_slice(&(CAMI_STRING_TABLE_VERSION + 1).to_le_bytes());
+    assert_eq!(
+        load(&bad),
+        CamiStringTableError::UnsupportedVersion(CAMI_STRING_TABLE_VERSION + 1)
+    );
+
+    // The first length (of "") is right after the header. Make it reach beyond the blob.
+    let mut bad = bytes.clone();
+    bad[32..36].copy_from_slice(&u32::MAX.to_le_bytes());
+    assert_eq!(load(&bad), CamiStringTableError::OutOfBounds { index: 0 });
+
+    // The first string ("") becomes "f", so the second one ("fig") should start 1 byte later.
+    let mut bad = bytes.clone();
+    bad[32..36].copy_from_slice(&1u32.to_le_bytes());
+    assert_eq!(load(&bad), CamiStringTableError::BadOffset { index: 1 });
+
+    // Swap the blob contents of two strings of the same length: they get out of order.
+    let two = CamiStringTable::build(["ab", "cd"]).unwrap();
+    let mut bad = two.clone();
+    let blob_at = bad.len() - 4;
+    bad[blob_at..].copy_from_slice(b"cdab");
+    assert_eq!(load(&bad), CamiStringTableError::NotSorted { index: 1 });
+
+    let mut bad = bytes.clone();
+    let last = bad.len() - 1;
+    bad[last] = 0xFF;
+    assert!(matches!(
+        load(&bad),
+        CamiStringTableError::InvalidUtf8 { .. }
+    ));
+}
+
+#[test]
+fn find_agrees_with_linear_search() {
+    // All strings over {a, b} of up to 6 bytes. Every third one is left out, so that misses fall
+    // between, before and after present strings of the same length.
+    let mut all: Vec<String> = vec![String::new()];
+    for len in 1..=6 {
+        let previous: Vec<String> = all.iter().filter(|s| s.len() == len - 1).cloned().collect();
+        for prefix in previous {
+            all.push(format!("{}a", prefix));
+            all.push(format!("{}b", prefix));
+        }
+    }
+    let present: Vec<&str> = all
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| index % 3 != 1)
+        .map(|(_, s)| s.as_str())
+        .collect();
+    let bytes = CamiStringTable::build(present.iter().copied()).unwrap();
+    let table = CamiStringTable::from_bytes(&bytes).unwrap();
+    let sorted: Vec<&str> = table.iter().collect();
+    for needle in all.iter().map(String::as_str).chain(["c", "abababa"]) {
+        assert_eq!(
+            table.find(needle),
+            sorted.iter().position(|s| *s == needle),
+            "{:?}",
+            needle
+        );
+    }
+}
@@ -0,0 +1,129 @@
+//! Zero cost conversions. Keep these Miri-clean: `cargo +nightly miri test --test wrap
+//! --features alloc`.
+use cami::prelude::*;
+use core::mem;
+
+#[test]
+fn layout() {
+    assert_eq!(mem::size_of::<Cami<u8>>(), mem::size_of::<u8>());
+    assert_eq!(mem::align_of::<Cami<u8>>(), mem::align_of::<u8>());
+    assert_eq!(mem::size_of::<Cami<&str>>(), mem::size_of::<&str>());
+    assert_eq!(mem::align_of::<Cami<&str>>(), mem::align_of::<&str>());
+    assert_eq!(mem::size_of::<F32Cami>(), mem::size_of::<f32>());
+    assert_eq!(mem::align_of::<F32Cami>(), mem::align_of::<f32>());
+}
+
+#[test]
+fn refs() {
+    let mut value = 5u8;
+    assert_eq!(*Cami::wrap_ref(&value).in_cami(), 5);
+    *Cami::wrap_mut(&mut value).in_cami_mut() = 6;
+    assert_eq!(value, 6);
+    assert_eq!(*value.into_ref_cami().in_cami(), 6);
+}
+
+#[test]
+fn slices() {
+    let mut items = [3u8, 1, 2];
+    Cami::wrap_slice_mut(&mut items).sort();
+    assert_eq!(items, [1, 2, 3]);
+    let wrapped = Cami::wrap_slice(&items);
+    assert_eq!(wrapped.len(), 3);
+    assert_eq!(Cami::unwrap_slice(wrapped), &[1, 2, 3]);
+
+    let strs = ["aa", "b"];
+    let mut wrapped = strs.into_slice_cami().to_vec();
+    wrapped.sort();
+    assert_eq!(Cami::unwrap_slice(&wrapped), &["b", "aa"]);
+
+    let empty: [u8; 0] = [];
+    assert!(Cami::wrap_slice(&empty).is_empty());
+}
+
+#[test]
+fn f32_slices() {
+    let mut floats = [1.5f32, -0.0, 0.0, -1.0];
+    floats.into_slice_mut_cami().sort();
+    assert_eq!(
+        F32Cami::unwrap_f32_slice(floats.into_slice_cami())
+            .iter()
+            .map(|f| f.to_bits())
+            .collect::<Vec<_>>(),
+        [-1.0f32, -0.0, 0.0, 1.5]
+            .iter()
+            .map(|f| f.to_bits())
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(F32Cami::wrap_f32_ref(&floats[3]).into_f32(), 1.5);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn vecs() {
+    let strings = vec![String::from("ccc"), String::from("b"), String::from("aa")];
+    let mut wrapped = Cami::wrap_vec(strings);
+    wrapped.push(Cami::new(String::from("dddd")));
+    wrapped.sort();
+    let strings = Cami::unwrap_vec(wrapped);
+    assert_eq!(strings, ["b", "aa", "ccc", "dddd"]);
+
+    let mut with_capacity = Vec::<String>::with_capacity(10);
+    with_capacity.push(String::from("x"));
+    let wrapped = Cami::wrap_vec(with_capacity);
+    assert_eq!(wrapped.capacity(), 10);
+    drop(wrapped);
+}
+
+#[test]
+fn arrays_and_options() {
+    let wrapped = Cami::wrap_array(["ccc", "b", "aa"]);
+    let mut sorted = wrapped;
+    sorted.sort();
+    assert_eq!(sorted.into_unwrapped_array(), ["b", "aa", "ccc"]);
+    let array = [1u8, 2];
+    assert!(array.into_ref_array_cami()[1] == Cami::new(2u8));
+
+    let value = 7u8;
+    assert!(Cami::wrap_option_ref(Some(&value)) == Some(&Cami::new(7u8)));
+    assert_eq!(
+        Cami::unwrap_option_ref(Cami::wrap_option_ref(Some(&value))),
+        Some(&7)
+    );
+    assert!(Cami::<u8>::wrap_option_ref(None).is_none());
+
+    let mut items = [2u8, 1];
+    let wrapped = Cami::wrap_slice_mut(&mut items);
+    wrapped.sort();
+    assert_eq!(wrapped.as_unwrapped_slice(), &[1, 2]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn boxed_rc_arc() {
+    use std::rc::Rc;
+    use std::sync::Arc;
+
+    let owned = |items: &[&str]| -> Vec<String> { items.iter().map(|s| s.to_string()).collect() };
+
+    let mut boxed = owned(&["ccc", "b", "aa"])
+        .into_boxed_slice()
+        .into_boxed_slice_cami();
+    boxed.sort();
+    assert_eq!(&*boxed.into_unwrapped_boxed_slice(), ["b", "aa", "ccc"]);
+
+    let rc: Rc<[String]> = owned(&["x", "yy"]).into();
+    let rc_clone = Rc::clone(&rc);
+    let wrapped = rc.into_rc_slice_cami();
+    assert_eq!(Rc::strong_count(&wrapped), 2);
+    assert!(wrapped[0] < wrapped[1]);
+    let rc = wrapped.into_unwrapped_rc_slice();
+    assert!(Rc::ptr_eq(&rc, &rc_clone));
+
+    let arc: Arc<[String]> = owned(&["x", "yy"]).into();
+    let wrapped = arc.into_arc_slice_cami();
+    assert_eq!(wrapped.len(), 2);
+    assert_eq!(&*wrapped.into_unwrapped_arc_slice(), ["x", "yy"]);
+
+    let vec = Cami::wrap_vec(owned(&["b", "a"]));
+    assert_eq!(vec.into_unwrapped_vec(), ["b", "a"]);
+}